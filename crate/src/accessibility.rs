@@ -0,0 +1,92 @@
+use crate::i18n::{Language, Messages};
+
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Document, HtmlElement};
+
+pub struct AccessibleView {
+    document: Document,
+    container: HtmlElement,
+    live_region: HtmlElement,
+    button_list: HtmlElement,
+    language: Option<Language>,
+    description: String,
+    button_labels: Vec<String>,
+}
+
+impl AccessibleView {
+    pub fn new(document: &Document) -> Result<AccessibleView, JsValue> {
+        let container = document.create_element("div")?.dyn_into::<HtmlElement>()?;
+        let live_region = document.create_element("div")?.dyn_into::<HtmlElement>()?;
+        let button_list = document.create_element("div")?.dyn_into::<HtmlElement>()?;
+
+        live_region.set_attribute("role", "status")?;
+        live_region.set_attribute("aria-live", "polite")?;
+        button_list.set_attribute("role", "group")?;
+
+        container.append_child(&live_region)?;
+        container.append_child(&button_list)?;
+
+        let style = container.style();
+        style.set_property("position", "absolute")?;
+        style.set_property("width", "1px")?;
+        style.set_property("height", "1px")?;
+        style.set_property("overflow", "hidden")?;
+        style.set_property("clip", "rect(0 0 0 0)")?;
+        style.set_property("white-space", "nowrap")?;
+
+        Ok(AccessibleView {
+            document: document.clone(),
+            container,
+            live_region,
+            button_list,
+            language: None,
+            description: String::new(),
+            button_labels: vec![],
+        })
+    }
+
+    pub fn container(&self) -> &HtmlElement {
+        &self.container
+    }
+
+    pub fn button_list(&self) -> &HtmlElement {
+        &self.button_list
+    }
+
+    pub fn update(
+        &mut self,
+        messages: Messages,
+        description: String,
+        button_labels: Vec<String>,
+    ) -> Result<(), JsValue> {
+        if Some(messages.language) != self.language {
+            self.container
+                .set_attribute("lang", &messages.language.to_string()[..])?;
+            self.button_list.set_attribute(
+                "aria-label",
+                &messages.text("accessibility.available_actions")[..],
+            )?;
+            self.language = Some(messages.language);
+        }
+
+        if description != self.description {
+            self.live_region.set_text_content(Some(&description[..]));
+            self.description = description;
+        }
+
+        if button_labels != self.button_labels {
+            self.button_list.set_text_content(None);
+            for (index, label) in button_labels.iter().enumerate() {
+                let button = self.document.create_element("button")?;
+                button.set_attribute(BUTTON_INDEX_ATTRIBUTE, &index.to_string()[..])?;
+                button.set_text_content(Some(&label[..]));
+                self.button_list.append_child(&button)?;
+            }
+            self.button_labels = button_labels;
+        }
+
+        Ok(())
+    }
+}
+
+const BUTTON_INDEX_ATTRIBUTE: &str = "data-action-index";
@@ -0,0 +1,350 @@
+use nzscq::{
+    choices::{Action, BatchChoice, DequeueChoice},
+    game::BatchChoiceGame,
+    outcomes::Outcome,
+};
+
+use ordered_float::NotNan;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Choice {
+    Dequeue(DequeueChoice),
+    Action(Action),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Evaluation {
+    Best,
+    Better(Choice, NotNan<f64>),
+    TooComplex,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Decision {
+    pub choice_index: usize,
+    pub chosen: Choice,
+    pub evaluation: Evaluation,
+}
+
+impl Decision {
+    pub fn is_mistake(&self) -> bool {
+        matches!(self.evaluation, Evaluation::Better(_, _))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RoundAnalysis {
+    pub number: usize,
+    pub decisions: Vec<Decision>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Analysis {
+    pub rounds: Vec<RoundAnalysis>,
+}
+
+impl Analysis {
+    pub fn from_choices(choices: &[BatchChoice]) -> Result<Analysis, ()> {
+        let mut game = BatchChoiceGame::default();
+        let mut rounds: Vec<RoundAnalysis> = vec![];
+
+        for (choice_index, choice) in choices.iter().enumerate() {
+            let decision = match choice {
+                BatchChoice::DequeueChoices(dequeues) => {
+                    rounds.push(RoundAnalysis {
+                        number: rounds.len() + 1,
+                        decisions: vec![],
+                    });
+
+                    Some(Decision {
+                        choice_index,
+                        chosen: Choice::Dequeue(dequeues[HUMAN]),
+                        evaluation: evaluate_dequeue(&game, dequeues[HUMAN]),
+                    })
+                }
+                BatchChoice::Actions(actions) if actions[HUMAN] != Action::Concede => {
+                    Some(Decision {
+                        choice_index,
+                        chosen: Choice::Action(actions[HUMAN]),
+                        evaluation: evaluate_action(&game, actions[HUMAN]),
+                    })
+                }
+                _ => None,
+            };
+
+            if let (Some(decision), Some(round)) = (decision, rounds.last_mut()) {
+                round.decisions.push(decision);
+            }
+
+            game.choose(choice.clone())?;
+        }
+
+        Ok(Analysis { rounds })
+    }
+
+    pub fn decisions(&self) -> impl Iterator<Item = &Decision> {
+        self.rounds.iter().flat_map(|round| round.decisions.iter())
+    }
+
+    pub fn mistake_shown_at_phase(&self, phase_index: usize) -> Option<&Decision> {
+        self.decisions()
+            .find(|decision| decision.choice_index + 1 == phase_index && decision.is_mistake())
+    }
+
+    pub fn mistake_phases(&self) -> Vec<usize> {
+        self.decisions()
+            .filter(|decision| decision.is_mistake())
+            .map(|decision| decision.choice_index + 1)
+            .collect()
+    }
+}
+
+struct Search {
+    nodes: usize,
+}
+
+impl Search {
+    fn visit(&mut self, game: &BatchChoiceGame, choice: BatchChoice) -> Result<Outcome, ()> {
+        self.nodes += 1;
+        if self.nodes > SEARCH_NODE_LIMIT {
+            return Err(());
+        }
+
+        let mut game = game.clone();
+        game.choose(choice)
+    }
+
+    fn action_value(&mut self, game: &BatchChoiceGame, human_action: Action) -> Result<f64, ()> {
+        let computer_actions = available_actions(game, COMPUTER);
+        if computer_actions.is_empty() {
+            return Err(());
+        }
+        let mut total = 0.0;
+
+        for &computer_action in &computer_actions {
+            match self.visit(
+                game,
+                BatchChoice::Actions(vec![human_action, computer_action]),
+            )? {
+                Outcome::ActionPhaseDone(outcome) | Outcome::GameOver(outcome) => {
+                    total += f64::from(outcome[HUMAN].1 - outcome[COMPUTER].1);
+                }
+                _ => return Err(()),
+            }
+        }
+
+        Ok(total / computer_actions.len() as f64)
+    }
+
+    fn best_action_value(&mut self, game: &BatchChoiceGame) -> Result<f64, ()> {
+        available_actions(game, HUMAN)
+            .into_iter()
+            .map(|action| self.action_value(game, action))
+            .try_fold(f64::NEG_INFINITY, |best, value| Ok(best.max(value?)))
+    }
+
+    fn dequeue_value(
+        &mut self,
+        game: &BatchChoiceGame,
+        human_dequeue: DequeueChoice,
+    ) -> Result<f64, ()> {
+        let computer_dequeues = game
+            .choices()
+            .dequeue_choices()
+            .expect("should be able to choose dequeue")
+            .remove(COMPUTER);
+        let mut total = 0.0;
+
+        for &computer_dequeue in &computer_dequeues {
+            let mut after_dequeue = game.clone();
+            after_dequeue.choose(BatchChoice::DequeueChoices(vec![
+                human_dequeue,
+                computer_dequeue,
+            ]))?;
+            total += self.best_action_value(&after_dequeue)?;
+        }
+
+        Ok(total / computer_dequeues.len() as f64)
+    }
+}
+
+fn evaluate_action(game: &BatchChoiceGame, chosen: Action) -> Evaluation {
+    let candidates = available_actions(game, HUMAN)
+        .into_iter()
+        .map(Choice::Action)
+        .collect();
+
+    evaluate(
+        Choice::Action(chosen),
+        candidates,
+        |search, choice| match choice {
+            Choice::Action(action) => search.action_value(game, action),
+            Choice::Dequeue(_) => Err(()),
+        },
+    )
+}
+
+fn evaluate_dequeue(game: &BatchChoiceGame, chosen: DequeueChoice) -> Evaluation {
+    let candidates = game
+        .choices()
+        .dequeue_choices()
+        .expect("should be able to choose dequeue")
+        .remove(HUMAN)
+        .into_iter()
+        .map(Choice::Dequeue)
+        .collect();
+
+    evaluate(
+        Choice::Dequeue(chosen),
+        candidates,
+        |search, choice| match choice {
+            Choice::Dequeue(dequeue) => search.dequeue_value(game, dequeue),
+            Choice::Action(_) => Err(()),
+        },
+    )
+}
+
+fn evaluate<F>(chosen: Choice, candidates: Vec<Choice>, value_of: F) -> Evaluation
+where
+    F: Fn(&mut Search, Choice) -> Result<f64, ()>,
+{
+    let mut search = Search { nodes: 0 };
+    let mut chosen_value = None;
+    let mut best: Option<(Choice, f64)> = None;
+
+    for candidate in candidates {
+        let value = match value_of(&mut search, candidate) {
+            Ok(value) => value,
+            Err(()) => return Evaluation::TooComplex,
+        };
+
+        if candidate == chosen {
+            chosen_value = Some(value);
+        }
+        if best.is_none_or(|(_, best_value)| value > best_value) {
+            best = Some((candidate, value));
+        }
+    }
+
+    match (chosen_value, best) {
+        (Some(chosen_value), Some((better, best_value)))
+            if best_value - chosen_value > POINTS_LOST_THRESHOLD =>
+        {
+            let points_lost =
+                NotNan::new(best_value - chosen_value).expect("points lost should not be NaN");
+
+            Evaluation::Better(better, points_lost)
+        }
+        _ => Evaluation::Best,
+    }
+}
+
+fn available_actions(game: &BatchChoiceGame, side: usize) -> Vec<Action> {
+    game.choices()
+        .actions()
+        .expect("should be able to choose action")
+        .remove(side)
+        .into_iter()
+        .filter(|&action| action != Action::Concede)
+        .collect()
+}
+
+const HUMAN: usize = 0;
+const COMPUTER: usize = 1;
+
+const SEARCH_NODE_LIMIT: usize = 5_000;
+const POINTS_LOST_THRESHOLD: f64 = 1e-9;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test_games;
+    use nzscq::choices::{ArsenalItem, Move};
+
+    #[test]
+    fn playing_the_suggested_action_is_not_flagged() {
+        let analysis =
+            Analysis::from_choices(&test_games::first_round_choices(Action::Move(Move::Kick)))
+                .expect("choices should be legal");
+        let action_decision = &analysis.rounds[0].decisions[1];
+        let better = match action_decision.evaluation {
+            Evaluation::Better(Choice::Action(action), _) => action,
+            Evaluation::Best => Action::Move(Move::Kick),
+            evaluation => panic!("unexpected evaluation {:?}", evaluation),
+        };
+
+        let reanalysis = Analysis::from_choices(&test_games::first_round_choices(better))
+            .expect("choices should be legal");
+
+        assert_eq!(1, reanalysis.rounds.len());
+        assert_eq!(
+            Evaluation::Best,
+            reanalysis.rounds[0].decisions[1].evaluation
+        );
+    }
+
+    #[test]
+    fn flagged_actions_are_listed_by_phase() {
+        let analysis =
+            Analysis::from_choices(&test_games::first_round_choices(Action::Move(Move::Kick)))
+                .expect("choices should be legal");
+        let dequeue_decision = &analysis.rounds[0].decisions[0];
+
+        assert_eq!(
+            Choice::Dequeue(DequeueChoice::Decline),
+            dequeue_decision.chosen
+        );
+        assert!(!dequeue_decision.is_mistake());
+        assert_eq!(vec![4], analysis.mistake_phases());
+        assert!(analysis.mistake_shown_at_phase(4).is_some());
+        assert!(analysis.mistake_shown_at_phase(3).is_none());
+    }
+
+    #[test]
+    fn dequeues_of_equal_value_are_not_flagged() {
+        let game = test_games::game_after_boosters();
+        let dequeues = game
+            .choices()
+            .dequeue_choices()
+            .expect("should be able to choose dequeue")
+            .remove(HUMAN);
+
+        assert!(dequeues.contains(&DequeueChoice::DrainAndExit(ArsenalItem::Mirror)));
+        for dequeue in dequeues {
+            assert_eq!(Evaluation::Best, evaluate_dequeue(&game, dequeue));
+        }
+    }
+
+    #[test]
+    fn evaluations_report_points_lost_or_give_up() {
+        let decline = Choice::Dequeue(DequeueChoice::Decline);
+        let just_exit = Choice::Dequeue(DequeueChoice::JustExit);
+        let value_of = |_: &mut Search, choice| Ok(if choice == decline { 0.0 } else { 1.5 });
+
+        assert_eq!(
+            Evaluation::Better(just_exit, NotNan::new(1.5).unwrap()),
+            evaluate(decline, vec![decline, just_exit], value_of)
+        );
+        assert_eq!(
+            Evaluation::Best,
+            evaluate(just_exit, vec![decline, just_exit], value_of)
+        );
+        assert_eq!(
+            Evaluation::TooComplex,
+            evaluate(decline, vec![decline, just_exit], |_, _| Err(()))
+        );
+    }
+
+    #[test]
+    fn searches_give_up_past_the_node_limit() {
+        let game = test_games::game_after_boosters();
+        let declines =
+            BatchChoice::DequeueChoices(vec![DequeueChoice::Decline, DequeueChoice::Decline]);
+        let mut search = Search { nodes: 0 };
+
+        for _ in 0..SEARCH_NODE_LIMIT {
+            assert!(search.visit(&game, declines.clone()).is_ok());
+        }
+        assert_eq!(Err(()), search.visit(&game, declines));
+    }
+}
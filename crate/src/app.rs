@@ -1,18 +1,33 @@
 use crate::{
+    accessibility::AccessibleView,
+    challenge::Challenge,
     click,
     context::Context,
+    description,
+    focus::{self, Direction},
     helpers,
+    history::{MatchHistory, MatchSummary},
+    hover,
+    i18n::Language,
     image_map::ImageMap,
     letterbox::Letterbox,
-    opponent::{Difficulty, Random},
+    notation::GameRecord,
+    opponent::{PrngState, Random},
     paint::{Component, Painter},
-    render::Render,
+    phase::Phase,
+    render::{self, Render},
+    seed_display::SeedDisplay,
+    settings::Settings,
     state::State,
+    stats::Stats,
+    text,
+    touch::{Touch, TouchState},
+    viewport::Drag,
 };
 
-use js_sys::{Date, Function, Math};
+use js_sys::{Date, Function, Math, Reflect};
 use wasm_bindgen::{prelude::*, JsCast};
-use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, HtmlElement, Window};
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, HtmlElement, MediaQueryList, Window};
 
 use std::convert::TryInto;
 use std::f64;
@@ -24,14 +39,29 @@ pub struct App {
     body: HtmlElement,
     canvas: HtmlCanvasElement,
     ctx: CanvasRenderingContext2d,
+    seed_display: SeedDisplay,
+    accessible_view: AccessibleView,
     image_map: ImageMap,
     context: Context,
+    reduced_motion_query: Option<MediaQueryList>,
     state: State,
+    match_history: MatchHistory,
+    queued_choice: Option<click::Action>,
+    focus_index: Option<usize>,
+    pointer_coords: Option<(f64, f64)>,
+    hovered_index: Option<usize>,
+    last_frame: Vec<Component>,
+    touch: Option<Touch>,
+    drag: Option<Drag>,
+    pinch_distance: Option<f64>,
+    accessible_actions: Vec<click::Action>,
     has_drawn_past_completion: bool,
 }
 
 impl App {
     const IDEAL_DIMENSIONS: (u32, u32) = (1800, 1000);
+    const REDUCED_MOTION_QUERY: &'static str = "(prefers-reduced-motion: reduce)";
+    const WHEEL_ZOOM_RATE: f64 = 0.001;
 }
 
 #[wasm_bindgen]
@@ -47,36 +77,61 @@ impl App {
             .get_context("2d")?
             .unwrap()
             .dyn_into::<CanvasRenderingContext2d>()?;
-        let computer_difficulty =
-            helpers::get_local_storage_item(&window, "nzscq_computer_difficulty")
-                .unwrap_or_else(|| "fail".to_string())
-                .try_into()
-                .unwrap_or(Difficulty::Medium);
+        let seed_display = SeedDisplay::new(&document)?;
+        let accessible_view = AccessibleView::new(&document)?;
+        let settings = Settings::load(&window);
+        let match_history = MatchHistory::load(&window);
         let current_time = helpers::millis_to_secs(Date::now());
+        let reduced_motion_query = window.match_media(App::REDUCED_MOTION_QUERY)?;
+        let prefers_reduced_motion = reduced_motion_query
+            .as_ref()
+            .is_some_and(|query| query.matches());
+        let detected_language = window
+            .navigator()
+            .language()
+            .and_then(|tag| Language::from_tag(&tag[..]));
 
         let mut app = App {
             window,
             body,
             canvas,
             ctx,
+            seed_display,
+            accessible_view,
             image_map: get_image
                 .try_into()
                 .expect("should be able to create image map from js image getter"),
             context: Context {
-                computer_difficulty,
+                settings,
                 current_time,
+                prefers_reduced_motion,
+                detected_language,
             },
+            reduced_motion_query,
             state: State::HomeScreen,
+            match_history,
+            queued_choice: None,
+            focus_index: None,
+            pointer_coords: None,
+            hovered_index: None,
+            last_frame: vec![],
+            touch: None,
+            drag: None,
+            pinch_distance: None,
+            accessible_actions: vec![],
             has_drawn_past_completion: false,
         };
 
         app.init()?;
+        app.on_hash_change()?;
 
         Ok(app)
     }
 
     fn init(&mut self) -> Result<(), JsValue> {
         self.body.append_child(&self.canvas)?;
+        self.body.append_child(self.seed_display.container())?;
+        self.body.append_child(self.accessible_view.container())?;
         self.init_canvas()?;
 
         Ok(())
@@ -87,6 +142,7 @@ impl App {
         self.canvas.set_width(ideal_width);
         self.canvas.set_height(ideal_height);
         self.canvas.style().set_property("position", "absolute")?;
+        self.canvas.style().set_property("touch-action", "none")?;
 
         self.resize()
     }
@@ -95,6 +151,17 @@ impl App {
         callback.call1(&JsValue::NULL, &self.canvas)
     }
 
+    pub fn call_with_accessible_button_list(&self, callback: Function) -> Result<JsValue, JsValue> {
+        callback.call1(&JsValue::NULL, self.accessible_view.button_list())
+    }
+
+    pub fn call_with_copy_challenge_link_button(
+        &self,
+        callback: Function,
+    ) -> Result<JsValue, JsValue> {
+        callback.call1(&JsValue::NULL, self.seed_display.copy_link_button())
+    }
+
     pub fn resize(&mut self) -> Result<(), JsValue> {
         let (actual_width, actual_height) = self.dimensions()?;
         let (actual_width, actual_height) = (f64::from(actual_width), f64::from(actual_height));
@@ -124,6 +191,9 @@ impl App {
             style.set_property("left", "0")?;
         }
 
+        self.seed_display
+            .reposition(&self.letterbox()?, self.ideal_dimensions())?;
+
         self.draw()
     }
 
@@ -141,15 +211,500 @@ impl App {
 
     pub fn on_click(&mut self, client_x: u32, client_y: u32) -> Result<(), JsValue> {
         let canvas_coords = self.canvas_coords((client_x, client_y))?;
+
+        if self.drag.take().is_some_and(|drag| drag.has_panned()) {
+            return Ok(());
+        }
+
+        if self.is_animating_phase() {
+            self.skip_animation();
+
+            let components = self.render();
+            if let Some(choice) = click::choice_triggered_by_click_at(canvas_coords, &components) {
+                self.queue_choice(choice);
+            }
+
+            return Ok(());
+        }
+
         let components = self.render();
         let action = click::action_triggered_by_click_at(canvas_coords, &components);
         if let Some(action) = action {
             self.handle_action(action);
+            self.update_seed_display()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn on_mouse_move(&mut self, client_x: u32, client_y: u32) -> Result<(), JsValue> {
+        let canvas_coords = self.canvas_coords((client_x, client_y))?;
+        self.pointer_coords = Some(canvas_coords);
+        self.drag_to(canvas_coords);
+
+        let hovered_index = self.hovered_index_in(&self.last_frame);
+        if hovered_index != self.hovered_index {
+            self.has_drawn_past_completion = false;
+        }
+
+        Ok(())
+    }
+
+    pub fn on_mouse_down(&mut self, client_x: u32, client_y: u32) -> Result<(), JsValue> {
+        let canvas_coords = self.canvas_coords((client_x, client_y))?;
+        self.start_drag(canvas_coords);
+
+        Ok(())
+    }
+
+    pub fn on_mouse_up(&mut self) {
+        if let Some(drag) = &mut self.drag {
+            drag.is_pressed = false;
+        }
+    }
+
+    pub fn on_mouse_leave(&mut self) -> Result<(), JsValue> {
+        self.pointer_coords = None;
+        self.drag = None;
+
+        if self.hovered_index.is_some() {
+            self.has_drawn_past_completion = false;
+        }
+
+        Ok(())
+    }
+
+    pub fn on_touch_start(&mut self, client_x: u32, client_y: u32) -> Result<(), JsValue> {
+        let canvas_coords = self.canvas_coords((client_x, client_y))?;
+        self.touch = Some(Touch::new(
+            canvas_coords,
+            helpers::millis_to_secs(Date::now()),
+        ));
+        self.start_drag(canvas_coords);
+
+        Ok(())
+    }
+
+    pub fn on_touch_move(&mut self, client_x: u32, client_y: u32) -> Result<(), JsValue> {
+        let canvas_coords = self.canvas_coords((client_x, client_y))?;
+        self.drag_to(canvas_coords);
+
+        if let Some(touch) = &mut self.touch {
+            if touch.has_moved_to(canvas_coords) {
+                if touch.is_inspecting() {
+                    self.stop_inspecting_held_move();
+                }
+                if let Some(touch) = &mut self.touch {
+                    touch.state = TouchState::Cancelled;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn on_pinch(
+        &mut self,
+        client_x0: u32,
+        client_y0: u32,
+        client_x1: u32,
+        client_y1: u32,
+    ) -> Result<(), JsValue> {
+        let (x0, y0) = self.canvas_coords((client_x0, client_y0))?;
+        let (x1, y1) = self.canvas_coords((client_x1, client_y1))?;
+        let distance = (x1 - x0).hypot(y1 - y0);
+        let midpoint = (0.5 * (x0 + x1), 0.5 * (y0 + y1));
+
+        self.drag = None;
+        if let (Some(previous_distance), Some(view)) =
+            (self.pinch_distance, self.state.matrix_view_mut())
+        {
+            if previous_distance > 0.0 {
+                view.zoom_at(midpoint, distance / previous_distance);
+                self.has_drawn_past_completion = false;
+            }
+        }
+        self.pinch_distance = Some(distance);
+
+        Ok(())
+    }
+
+    /// Returns whether the touch was consumed by a long-press, a pan, or a pinch,
+    /// in which case the click that follows it should be suppressed.
+    pub fn on_touch_end(&mut self) -> bool {
+        let was_panning = self.drag.take().is_some_and(|drag| drag.has_panned());
+        let was_pinching = self.pinch_distance.take().is_some();
+
+        match self.touch.take() {
+            Some(touch) if touch.is_inspecting() => {
+                self.stop_inspecting_held_move();
+                true
+            }
+            _ => was_panning || was_pinching,
+        }
+    }
+
+    pub fn on_wheel(
+        &mut self,
+        client_x: u32,
+        client_y: u32,
+        delta_y: f64,
+    ) -> Result<bool, JsValue> {
+        let canvas_coords = self.canvas_coords((client_x, client_y))?;
+
+        if let Some(round_log) = self.state.round_log_mut() {
+            if round_log.is_expanded && render::is_over_round_log(canvas_coords) {
+                if delta_y < 0.0 {
+                    round_log.scroll_up();
+                } else if delta_y > 0.0 {
+                    round_log.scroll_down();
+                }
+                self.has_drawn_past_completion = false;
+
+                return Ok(true);
+            }
+        }
+
+        match self.state.matrix_view_mut() {
+            Some(view) if view.viewport.contains(canvas_coords) => {
+                view.zoom_at(canvas_coords, (-delta_y * App::WHEEL_ZOOM_RATE).exp());
+                self.has_drawn_past_completion = false;
+
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn start_drag(&mut self, canvas_coords: (f64, f64)) {
+        self.drag = match self.state.matrix_view_mut() {
+            Some(view) if view.viewport.contains(canvas_coords) => Some(Drag::new(canvas_coords)),
+            _ => None,
+        };
+    }
+
+    fn drag_to(&mut self, canvas_coords: (f64, f64)) {
+        let delta = match &mut self.drag {
+            Some(drag) if drag.is_pressed => drag.move_to(canvas_coords),
+            _ => return,
+        };
+
+        if let Some(view) = self.state.matrix_view_mut() {
+            view.pan_by(delta);
+            self.has_drawn_past_completion = false;
+        }
+    }
+
+    fn update_touch(&mut self) {
+        let current_time = helpers::millis_to_secs(Date::now());
+        let start_coords = match self.touch {
+            Some(touch) if touch.is_long_press(current_time) => touch.start_coords(),
+            _ => return,
+        };
+
+        if self.is_animating_phase() {
+            return;
+        }
+
+        let inspected_move = click::move_pictured_at(start_coords, &self.last_frame);
+        let did_inspect = match (inspected_move, self.state.phase_mut()) {
+            (Some(m), Some(phase)) => phase.inspect_move_while_held(m).is_ok(),
+            _ => false,
+        };
+
+        if let Some(touch) = &mut self.touch {
+            touch.state = match inspected_move {
+                Some(m) if did_inspect => TouchState::Inspecting(m),
+                _ => TouchState::Cancelled,
+            };
+        }
+        if did_inspect {
+            self.has_drawn_past_completion = false;
+        }
+    }
+
+    fn stop_inspecting_held_move(&mut self) {
+        if let Some(phase) = self.state.phase_mut() {
+            phase.stop_inspecting_held_move();
+        }
+        self.has_drawn_past_completion = false;
+    }
+
+    fn hovered_index_in(&self, components: &[Component]) -> Option<usize> {
+        self.pointer_coords
+            .and_then(|coords| click::index_of_component_clicked_at(coords, components))
+            .filter(|&index| hover::is_hoverable(&components[index]))
+    }
+
+    fn update_cursor(&mut self, hovered_index: Option<usize>) -> Result<(), JsValue> {
+        if hovered_index.is_some() != self.hovered_index.is_some() {
+            let cursor = if hovered_index.is_some() {
+                "pointer"
+            } else {
+                "default"
+            };
+            self.canvas.style().set_property("cursor", cursor)?;
+        }
+        self.hovered_index = hovered_index;
+
+        Ok(())
+    }
+
+    pub fn on_key_down(&mut self, key: String) -> Result<bool, JsValue> {
+        let direction = match &key[..] {
+            "ArrowUp" => Some(Direction::Up),
+            "ArrowDown" => Some(Direction::Down),
+            "ArrowLeft" => Some(Direction::Left),
+            "ArrowRight" => Some(Direction::Right),
+            _ => None,
+        };
+        if let Some(direction) = direction {
+            let focusables = focus::focusables(&self.render_state());
+            self.focus_index = focus::next_focus(&focusables, self.focus_index, direction);
+            self.has_drawn_past_completion = false;
+
+            return Ok(true);
+        }
+
+        if self.is_animating_phase() {
+            self.skip_animation();
+
+            if let Some(action) = self.action_triggered_by_key(&key[..]) {
+                self.queue_choice(action);
+            }
+
+            return Ok(true);
+        }
+
+        match self.action_triggered_by_key(&key[..]) {
+            Some(action) => {
+                self.handle_action(action);
+                self.update_seed_display()?;
+
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn action_triggered_by_key(&mut self, key: &str) -> Option<click::Action> {
+        let components = self.render_state();
+
+        match key {
+            "Enter" | " " => {
+                let focusables = focus::focusables(&components);
+                self.focus_index
+                    .and_then(|index| focusables.get(index))
+                    .map(|focusable| focusable.action.clone())
+            }
+            "Escape" => match self.state {
+                State::HomeScreen => None,
+                _ => Some(click::Action::NavigateHome),
+            },
+            "+" | "=" => components
+                .iter()
+                .filter_map(Component::on_click)
+                .find(|action| *action == click::Action::ZoomMatrixIn),
+            "-" => components
+                .iter()
+                .filter_map(Component::on_click)
+                .find(|action| *action == click::Action::ZoomMatrixOut),
+            "0" => components
+                .iter()
+                .filter_map(Component::on_click)
+                .find(|action| *action == click::Action::ResetMatrixView),
+            "i" | "I" => components
+                .iter()
+                .filter_map(Component::on_click)
+                .find(|action| {
+                    matches!(
+                        action,
+                        click::Action::WaitForUserToChooseMoveToInspect
+                            | click::Action::StopInspectingMove
+                    )
+                }),
+            _ => focus::selection_index(key)
+                .and_then(|index| focus::selectable_actions(&components).get(index).cloned()),
+        }
+    }
+
+    pub fn on_accessible_button(&mut self, index: usize) -> Result<(), JsValue> {
+        let action = match self.accessible_actions.get(index) {
+            Some(action) => action.clone(),
+            None => return Ok(()),
+        };
+
+        if self.is_animating_phase() {
+            self.skip_animation();
+            self.queue_choice(action);
+
+            return Ok(());
+        }
+
+        self.handle_action(action);
+        self.update_seed_display()
+    }
+
+    fn update_accessible_view(&mut self) -> Result<(), JsValue> {
+        let mut actions: Vec<click::Action> = vec![];
+        for focusable in focus::focusables(&self.last_frame) {
+            if !actions.contains(&focusable.action) {
+                actions.push(focusable.action);
+            }
+        }
+
+        let messages = self.context.messages();
+        let labels = actions
+            .iter()
+            .map(|action| description::describe_action(action, messages))
+            .collect();
+        self.accessible_actions = actions;
+        self.accessible_view.update(
+            messages,
+            description::describe_state(&self.state, messages),
+            labels,
+        )
+    }
+
+    fn is_animating_phase(&mut self) -> bool {
+        let current_time = helpers::millis_to_secs(Date::now());
+
+        self.state
+            .is_animating_phase(current_time, self.context.settings.animation_speed)
+    }
+
+    fn skip_animation(&mut self) {
+        self.state.skip_animation();
+        self.has_drawn_past_completion = false;
+    }
+
+    fn queue_choice(&mut self, action: click::Action) {
+        if action.is_choice() {
+            self.queued_choice = Some(action);
+        }
+    }
+
+    pub fn on_hash_change(&mut self) -> Result<(), JsValue> {
+        let location = self.window.location();
+
+        if let Some(challenge) = Challenge::from_fragment(&location.hash()?[..]) {
+            location.set_hash("")?;
+            self.state.start_challenge_game(
+                helpers::millis_to_secs(Date::now()),
+                &challenge.seed[..],
+                challenge
+                    .difficulty
+                    .unwrap_or(self.context.settings.computer_difficulty),
+            );
+            self.start_animation();
+            self.update_seed_display()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn copy_challenge_link(&self) -> Result<JsValue, JsValue> {
+        let link = match self.challenge_link()? {
+            Some(link) => link,
+            None => return Ok(JsValue::UNDEFINED),
+        };
+        let navigator = self.window.navigator();
+
+        if Reflect::has(&navigator, &JsValue::from_str("clipboard"))? {
+            Ok(navigator.clipboard().write_text(&link[..]).into())
+        } else {
+            self.prompt_challenge_link(&link)?;
+
+            Ok(JsValue::UNDEFINED)
+        }
+    }
+
+    pub fn on_challenge_link_copied(&self) {
+        self.seed_display.mark_link_copied(self.context.messages());
+    }
+
+    pub fn on_challenge_link_copy_failed(&self) -> Result<(), JsValue> {
+        match self.challenge_link()? {
+            Some(link) => self.prompt_challenge_link(&link),
+            None => Ok(()),
+        }
+    }
+
+    fn challenge_link(&self) -> Result<Option<String>, JsValue> {
+        if let State::SinglePlayer(state) = &self.state {
+            let challenge = Challenge {
+                seed: state.record.seed.clone(),
+                difficulty: Some(state.record.difficulty),
+            };
+            let location = self.window.location();
+
+            Ok(Some(format!(
+                "{}{}{}{}",
+                location.origin()?,
+                location.pathname()?,
+                location.search()?,
+                challenge.fragment()
+            )))
+        } else {
+            Ok(None)
         }
+    }
+
+    fn prompt_challenge_link(&self, link: &str) -> Result<(), JsValue> {
+        self.window.prompt_with_message_and_default(
+            &self.context.messages().text("prompt.copy_challenge_link")[..],
+            link,
+        )?;
 
         Ok(())
     }
 
+    fn update_seed_display(&self) -> Result<(), JsValue> {
+        if let State::SinglePlayer(state) = &self.state {
+            self.seed_display.show(
+                &state.record.seed[..],
+                state.record.difficulty,
+                self.context.messages(),
+            )
+        } else {
+            self.seed_display.hide()
+        }
+    }
+
+    pub fn game_record(&self) -> Option<String> {
+        if let State::SinglePlayer(state) = &self.state {
+            Some(state.record.to_string())
+        } else {
+            None
+        }
+    }
+
+    pub fn load_game_record(&mut self, record: &str) -> Result<(), JsValue> {
+        let record: GameRecord = record
+            .parse()
+            .map_err(|error| JsValue::from_str(&format!("invalid game record: {}", error)[..]))?;
+        self.state
+            .resume_single_player_game(helpers::millis_to_secs(Date::now()), &record)
+            .map_err(|_| {
+                JsValue::from_str("game record does not match the computer's choices for its seed")
+            })?;
+        self.start_animation();
+
+        self.update_seed_display()
+    }
+
+    pub fn replay_game_record(&mut self, record: &str) -> Result<(), JsValue> {
+        let record: GameRecord = record
+            .parse()
+            .map_err(|error| JsValue::from_str(&format!("invalid game record: {}", error)[..]))?;
+        self.state
+            .start_replay(helpers::millis_to_secs(Date::now()), record)
+            .map_err(|_| JsValue::from_str("game record should contain only legal choices"))?;
+        self.has_drawn_past_completion = false;
+
+        self.update_seed_display()
+    }
+
     fn canvas_coords(&self, client_coords: (u32, u32)) -> Result<(f64, f64), JsValue> {
         let (x, y) = client_coords;
         let (mut x, mut y) = (f64::from(x), f64::from(y));
@@ -167,6 +722,9 @@ impl App {
     }
 
     fn handle_action(&mut self, action: click::Action) {
+        self.queued_choice = None;
+        self.focus_index = None;
+
         let mut should_start_animation = true;
         let mut prevent_animation_start = || should_start_animation = false;
 
@@ -176,38 +734,119 @@ impl App {
                     self.state.start_single_player_game(
                         helpers::millis_to_secs(Date::now()),
                         &JsPrng.random().to_string()[..],
-                        self.context.computer_difficulty,
+                        self.context.settings.computer_difficulty,
                     );
                 }
 
                 click::Action::NavigateToSettingsScreen => self.state = State::SettingsScreen,
 
+                click::Action::NavigateToStatsScreen => {
+                    self.state = State::StatsScreen(Box::new(Stats::from(&self.match_history)))
+                }
+
+                click::Action::NavigateToEncyclopedia => {
+                    self.state = State::EncyclopediaScreen(None)
+                }
+
+                click::Action::StartSandbox => {
+                    self.state
+                        .start_sandbox(helpers::millis_to_secs(Date::now()), vec![])
+                        .expect("should be able to start an empty sandbox");
+                }
+
                 action => panic!(
                     "Action {:?} should never be emitted when state == Homescreen",
                     action
                 ),
             },
 
+            State::StatsScreen(_) => match action {
+                click::Action::NavigateHome => self.state = State::HomeScreen,
+                click::Action::ReplayMatch(index) => {
+                    let record = self.match_history.matches[index].record.clone();
+                    self.state
+                        .start_replay(helpers::millis_to_secs(Date::now()), record)
+                        .expect("recorded game should be replayable");
+                }
+
+                action => panic!(
+                    "Action {:?} should never be emitted when state == StatsScreen",
+                    action
+                ),
+            },
+
+            State::EncyclopediaScreen(selected_move) => match action {
+                click::Action::NavigateHome => self.state = State::HomeScreen,
+                click::Action::SelectEncyclopediaMove(m) => *selected_move = Some(m),
+                click::Action::NavigateToMatchupMatrix => {
+                    self.state = State::MatrixScreen(Box::default())
+                }
+
+                action => panic!(
+                    "Action {:?} should never be emitted when state == EncyclopediaScreen",
+                    action
+                ),
+            },
+
+            State::MatrixScreen(view) => match action {
+                click::Action::NavigateHome => self.state = State::HomeScreen,
+                click::Action::NavigateToEncyclopedia => {
+                    self.state = State::EncyclopediaScreen(None)
+                }
+                click::Action::SelectMatrixCell(row, column) => {
+                    view.selected_cell = Some((row, column))
+                }
+                click::Action::SetMatrixRowFilter(filter) => view.set_row_filter(filter),
+                click::Action::SetMatrixColumnFilter(filter) => view.set_column_filter(filter),
+                click::Action::ZoomMatrixIn => view.zoom_in(),
+                click::Action::ZoomMatrixOut => view.zoom_out(),
+                click::Action::ResetMatrixView => view.viewport.reset(),
+                click::Action::StopPropagation => {}
+
+                action => panic!(
+                    "Action {:?} should never be emitted when state == MatrixScreen",
+                    action
+                ),
+            },
+
             State::SettingsScreen => match action {
                 click::Action::NavigateHome => self.state = State::HomeScreen,
                 click::Action::SetComputerDifficulty(difficulty) => {
-                    self.context.computer_difficulty = difficulty;
-                    helpers::set_local_storage_item(
-                        &self.window,
-                        "nzscq_computer_difficulty",
-                        &difficulty.to_string()[..],
-                    )
+                    self.context.settings.computer_difficulty = difficulty;
+                    self.context.settings.save(&self.window);
+                }
+                click::Action::SetAnimationSpeed(animation_speed) => {
+                    self.context.settings.animation_speed = animation_speed;
+                    self.context.settings.save(&self.window);
+                }
+                click::Action::SetTheme(theme) => {
+                    self.context.settings.theme = theme;
+                    self.context.settings.save(&self.window);
+                }
+                click::Action::ToggleReducedMotion => {
+                    self.context.settings.reduce_motion = !self.context.settings.reduce_motion;
+                    self.context.settings.save(&self.window);
+                }
+                click::Action::SetLanguage(language) => {
+                    self.context.settings.language = language;
+                    self.context.settings.save(&self.window);
+                }
+                click::Action::SetHintsPerGame(hints_per_game) => {
+                    self.context.settings.hints_per_game = hints_per_game;
+                    self.context.settings.save(&self.window);
                 }
                 click::Action::PromptUserForCustomSeed => {
                     let seed = self
                         .window
-                        .prompt_with_message("Enter your seed:")
+                        .prompt_with_message(
+                            &self.context.messages().text("prompt.custom_seed")[..],
+                        )
                         .expect("should be able to prompt user for seed");
                     if let Some(seed) = seed {
                         self.state.start_single_player_game(
                             helpers::millis_to_secs(Date::now()),
                             &seed[..],
-                            self.context.computer_difficulty,
+                            self.context.settings.computer_difficulty,
                         );
                     }
                 }
@@ -232,13 +871,36 @@ impl App {
                 }
 
                 click::Action::ChooseAction(human_action) => {
-                    state.handle_action_choice(human_action)
+                    state.handle_action_choice(human_action);
+
+                    if let Phase::GameOver(phase) = &state.phase {
+                        self.match_history.push(MatchSummary::new(
+                            Date::now() as u64,
+                            state.record.clone(),
+                            state.game.winner_index().expect("game should be over"),
+                            &phase.scoreboard,
+                            state.hints_used,
+                        ));
+                        self.match_history.save(&self.window);
+                    }
                 }
 
                 click::Action::NavigateHome => {
                     self.state = State::HomeScreen;
                 }
 
+                click::Action::ShowHint => {
+                    prevent_animation_start();
+                    state.show_hint(self.context.settings.hints_per_game);
+                }
+
+                click::Action::UndoChoice => {
+                    prevent_animation_start();
+                    state
+                        .undo()
+                        .expect("earlier choices should replay the same way");
+                }
+
                 click::Action::WaitForUserToChooseMoveToInspect => {
                     prevent_animation_start();
                     state
@@ -263,11 +925,184 @@ impl App {
                         .expect("should be able to stop inspecting move");
                 }
 
+                click::Action::ForecastQueue(side) => {
+                    prevent_animation_start();
+                    state
+                        .phase
+                        .forecast_queue(side)
+                        .expect("should be able to forecast queue");
+                }
+
+                click::Action::ToggleRoundLog => {
+                    prevent_animation_start();
+                    state.round_log.toggle();
+                }
+
+                click::Action::ScrollRoundLogUp => {
+                    prevent_animation_start();
+                    state.round_log.scroll_up();
+                }
+
+                click::Action::ScrollRoundLogDown => {
+                    prevent_animation_start();
+                    state.round_log.scroll_down();
+                }
+
+                click::Action::StopPropagation => prevent_animation_start(),
+
+                click::Action::StartReplay => {
+                    let record = state.record.clone();
+                    self.state
+                        .start_replay(helpers::millis_to_secs(Date::now()), record)
+                        .expect("recorded game should be replayable");
+                }
+
+                click::Action::AnalyzeGame => {
+                    let record = state.record.clone();
+                    self.state
+                        .start_analysis(helpers::millis_to_secs(Date::now()), record)
+                        .expect("recorded game should be analyzable");
+                }
+
                 action => panic!(
                     "Action {:?} should never be emitted when state == SinglePlayer",
                     action
                 ),
             },
+
+            State::Replay(state) => {
+                prevent_animation_start();
+                let current_time = helpers::millis_to_secs(Date::now());
+
+                match action {
+                    click::Action::ToggleReplayPlayback => state.toggle_playback(current_time),
+                    click::Action::StepReplayForward => state.step_forward(current_time),
+                    click::Action::StepReplayBackward => state.step_backward(current_time),
+                    click::Action::SkipToNextRound => state.skip_to_next_round(current_time),
+                    click::Action::SkipToPreviousRound => {
+                        state.skip_to_previous_round(current_time)
+                    }
+                    click::Action::SeekReplay(index) => state.seek(index, current_time),
+                    click::Action::StartReplay => state.restart(current_time),
+                    click::Action::AnalyzeGame => {
+                        state.analyze().expect("recorded game should be analyzable")
+                    }
+                    click::Action::BranchReplay => {
+                        let choices = state.choices_so_far();
+                        self.state
+                            .start_sandbox(current_time, choices)
+                            .expect("replayed choices should be legal");
+                    }
+                    click::Action::StopPropagation => {}
+
+                    click::Action::NavigateHome => {
+                        self.state = State::HomeScreen;
+                    }
+
+                    click::Action::WaitForUserToChooseMoveToInspect => state
+                        .phase_mut()
+                        .wait_for_user_to_choose_move_to_inspect()
+                        .expect("should be able to wait for user to choose move to inspect"),
+
+                    click::Action::InspectMove(m) => state
+                        .phase_mut()
+                        .inspect_move(m)
+                        .expect("should be able to inspect move"),
+
+                    click::Action::StopInspectingMove => state
+                        .phase_mut()
+                        .stop_inspecting_move()
+                        .expect("should be able to stop inspecting move"),
+
+                    click::Action::ForecastQueue(side) => state
+                        .phase_mut()
+                        .forecast_queue(side)
+                        .expect("should be able to forecast queue"),
+
+                    action => panic!(
+                        "Action {:?} should never be emitted when state == Replay",
+                        action
+                    ),
+                }
+            }
+
+            State::Sandbox(state) => match action {
+                click::Action::ChooseCharacter(character) => {
+                    if !state.handle_character_choice(character) {
+                        prevent_animation_start();
+                    }
+                }
+
+                click::Action::ChooseBooster(booster) => {
+                    if !state.handle_booster_choice(booster) {
+                        prevent_animation_start();
+                    }
+                }
+
+                click::Action::ChooseDequeue(dequeue) => {
+                    if !state.handle_dequeue_choice(dequeue) {
+                        prevent_animation_start();
+                    }
+                }
+
+                click::Action::ChooseAction(action) => {
+                    if !state.handle_action_choice(action) {
+                        prevent_animation_start();
+                    }
+                }
+
+                click::Action::UndoChoice => {
+                    prevent_animation_start();
+                    state.undo().expect("remaining choices should be legal");
+                }
+
+                click::Action::RedoChoice => {
+                    state.redo().expect("undone choice should be legal");
+                }
+
+                click::Action::NavigateHome => {
+                    self.state = State::HomeScreen;
+                }
+
+                click::Action::WaitForUserToChooseMoveToInspect => {
+                    prevent_animation_start();
+                    state
+                        .phase_mut()
+                        .wait_for_user_to_choose_move_to_inspect()
+                        .expect("should be able to wait for user to choose move to inspect");
+                }
+
+                click::Action::InspectMove(m) => {
+                    prevent_animation_start();
+                    state
+                        .phase_mut()
+                        .inspect_move(m)
+                        .expect("should be able to inspect move")
+                }
+
+                click::Action::StopInspectingMove => {
+                    prevent_animation_start();
+                    state
+                        .phase_mut()
+                        .stop_inspecting_move()
+                        .expect("should be able to stop inspecting move");
+                }
+
+                click::Action::ForecastQueue(side) => {
+                    prevent_animation_start();
+                    state
+                        .phase_mut()
+                        .forecast_queue(side)
+                        .expect("should be able to forecast queue");
+                }
+
+                click::Action::StopPropagation => prevent_animation_start(),
+
+                action => panic!(
+                    "Action {:?} should never be emitted when state == Sandbox",
+                    action
+                ),
+            },
         }
 
         if should_start_animation {
@@ -284,13 +1119,28 @@ impl App {
     }
 
     pub fn draw_if_needed(&mut self) -> Result<(), JsValue> {
+        self.update_touch();
+
         let current_time = helpers::millis_to_secs(Date::now());
 
-        if self.state.is_current_time_past_completion(current_time) {
+        let animation_speed = self.context.settings.animation_speed;
+
+        if self.state.update(current_time, animation_speed) {
+            self.has_drawn_past_completion = false;
+        }
+
+        if self
+            .state
+            .is_current_time_past_completion(current_time, animation_speed)
+        {
             if !self.has_drawn_past_completion {
                 self.has_drawn_past_completion = true;
 
-                self.draw()
+                self.draw()?;
+                self.update_accessible_view()
+            } else if let Some(choice) = self.queued_choice.take() {
+                self.handle_action(choice);
+                self.update_seed_display()
             } else {
                 Ok(())
             }
@@ -301,6 +1151,8 @@ impl App {
 
     fn draw(&mut self) -> Result<(), JsValue> {
         let components = self.render();
+        self.update_cursor(self.hovered_index_in(&components))?;
+        self.last_frame = components.clone();
         let ideal_dimensions = self.ideal_dimensions();
         let body_style = self.body.style();
         let mut painter = Painter::new(&self.ctx, &body_style, &self.image_map, ideal_dimensions);
@@ -310,9 +1162,37 @@ impl App {
     }
 
     fn render(&mut self) -> Vec<Component> {
+        let mut components = self.render_state();
+
+        let highlight = self
+            .hovered_index_in(&components)
+            .and_then(|index| hover::hover_highlight(&components[index], self.context.theme()));
+        if let Some(highlight) = highlight {
+            components.push(highlight);
+        }
+
+        let focused_bounds = self.focus_index.and_then(|index| {
+            focus::focusables(&components)
+                .get(index)
+                .map(|focusable| focusable.bounds.clone())
+        });
+        if let Some(bounds) = focused_bounds {
+            components.push(focus::focus_ring(&bounds, self.context.theme()));
+        }
+
+        components
+    }
+
+    fn render_state(&mut self) -> Vec<Component> {
         self.context.current_time = helpers::millis_to_secs(Date::now());
+        if let Some(query) = &self.reduced_motion_query {
+            self.context.prefers_reduced_motion = query.matches();
+        }
 
-        self.state.render(&self.context)
+        let mut components = self.state.render(&self.context);
+        text::measure(&mut components, &self.ctx);
+
+        components
     }
 
     fn ideal_dimensions(&self) -> (u32, u32) {
@@ -334,4 +1214,12 @@ impl Random for JsPrng {
     fn random(&mut self) -> f64 {
         Math::random()
     }
+
+    fn snapshot(&self) -> Option<PrngState> {
+        None
+    }
+
+    fn restore(&mut self, _: PrngState) -> Result<(), ()> {
+        Err(())
+    }
 }
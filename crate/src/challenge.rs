@@ -0,0 +1,113 @@
+use crate::opponent::Difficulty;
+
+use std::convert::TryFrom;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Challenge {
+    pub seed: String,
+    pub difficulty: Option<Difficulty>,
+}
+
+impl Challenge {
+    pub fn from_fragment(fragment: &str) -> Option<Challenge> {
+        let mut seed = None;
+        let mut difficulty = None;
+
+        for pair in fragment.trim_start_matches('#').split('&') {
+            if let Some((key, value)) = pair.split_once('=') {
+                match key {
+                    SEED_KEY => seed = percent_decode(value),
+                    DIFFICULTY_KEY => {
+                        difficulty =
+                            percent_decode(value).and_then(|value| Difficulty::try_from(value).ok())
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        seed.filter(|seed| !seed.is_empty())
+            .map(|seed| Challenge { seed, difficulty })
+    }
+
+    pub fn fragment(&self) -> String {
+        let mut fragment = format!("#{}={}", SEED_KEY, percent_encode(&self.seed[..]));
+
+        if let Some(difficulty) = self.difficulty {
+            fragment.push_str(&format!(
+                "&{}={}",
+                DIFFICULTY_KEY,
+                difficulty.to_string().to_lowercase()
+            ));
+        }
+
+        fragment
+    }
+}
+
+fn percent_encode(s: &str) -> String {
+    s.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+fn percent_decode(s: &str) -> Option<String> {
+    let mut bytes = vec![];
+    let mut rest = s.as_bytes();
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+
+    String::from_utf8(bytes).ok()
+}
+
+const SEED_KEY: &str = "seed";
+const DIFFICULTY_KEY: &str = "difficulty";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fragments_round_trip() {
+        let challenge = Challenge {
+            seed: "0.42 & friends #2".to_string(),
+            difficulty: Some(Difficulty::Medium),
+        };
+
+        assert_eq!(
+            Some(challenge.clone()),
+            Challenge::from_fragment(&challenge.fragment()[..])
+        );
+    }
+
+    #[test]
+    fn difficulty_is_optional() {
+        assert_eq!(
+            Some(Challenge {
+                seed: "da".to_string(),
+                difficulty: None,
+            }),
+            Challenge::from_fragment("#seed=da&difficulty=impossible")
+        );
+    }
+
+    #[test]
+    fn fragments_without_seeds_are_ignored() {
+        assert_eq!(None, Challenge::from_fragment("#difficulty=easy"));
+        assert_eq!(None, Challenge::from_fragment("#seed=%E"));
+    }
+}
@@ -1,7 +1,12 @@
 use crate::{
+    encyclopedia::MoveSource,
+    i18n::Language,
     opponent::Difficulty,
-    paint::Component,
+    paint::{Component, ImageType},
+    settings::{AnimationSpeed, ThemeName},
     shapes::{Circle, Rect},
+    side::Side,
+    text,
 };
 
 use nzscq::choices::{self, Booster, Character, DequeueChoice, Move};
@@ -10,22 +15,54 @@ pub fn action_triggered_by_click_at(
     canvas_coords: (f64, f64),
     components: &[Component],
 ) -> Option<Action> {
-    let front_to_back = components.iter().rev();
+    index_of_component_clicked_at(canvas_coords, components)
+        .and_then(|index| components[index].on_click())
+}
 
-    for component in front_to_back {
-        if component.touches(canvas_coords) {
-            if let Some(on_click) = component.on_click() {
-                return Some(on_click);
-            }
+pub fn choice_triggered_by_click_at(
+    canvas_coords: (f64, f64),
+    components: &[Component],
+) -> Option<Action> {
+    action_triggered_by_click_at(canvas_coords, components).filter(Action::is_choice)
+}
+
+pub fn index_of_component_clicked_at(
+    canvas_coords: (f64, f64),
+    components: &[Component],
+) -> Option<usize> {
+    let front_to_back = components.iter().enumerate().rev();
+
+    for (index, component) in front_to_back {
+        if component.touches(canvas_coords) && component.on_click().is_some() {
+            return Some(index);
         }
     }
     None
 }
 
-#[derive(Debug, Clone)]
+pub fn move_pictured_at(canvas_coords: (f64, f64), components: &[Component]) -> Option<Move> {
+    components
+        .iter()
+        .rev()
+        .find_map(|component| match component {
+            Component::Image {
+                image_type: ImageType::Move(m),
+                shape,
+                ..
+            } if shape.touches(canvas_coords) => Some(*m),
+            _ => None,
+        })
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum Action {
     StartSinglePlayerGame,
     NavigateToSettingsScreen,
+    NavigateToStatsScreen,
+    NavigateToEncyclopedia,
+    NavigateToMatchupMatrix,
+    StartSandbox,
+    ReplayMatch(usize),
 
     ChooseCharacter(Character),
     ChooseBooster(Booster),
@@ -34,14 +71,56 @@ pub enum Action {
     WaitForUserToChooseMoveToInspect,
     InspectMove(Move),
     StopInspectingMove,
+    ForecastQueue(Side),
+    StartReplay,
+    ToggleRoundLog,
+    ScrollRoundLogUp,
+    ScrollRoundLogDown,
+    AnalyzeGame,
+    ShowHint,
+
+    ToggleReplayPlayback,
+    StepReplayForward,
+    StepReplayBackward,
+    SkipToNextRound,
+    SkipToPreviousRound,
+    SeekReplay(usize),
+    BranchReplay,
+    UndoChoice,
+    RedoChoice,
+
+    SelectEncyclopediaMove(Move),
+    SelectMatrixCell(Move, Move),
+    SetMatrixRowFilter(Option<MoveSource>),
+    SetMatrixColumnFilter(Option<MoveSource>),
+    ZoomMatrixIn,
+    ZoomMatrixOut,
+    ResetMatrixView,
 
     SetComputerDifficulty(Difficulty),
+    SetAnimationSpeed(AnimationSpeed),
+    SetTheme(ThemeName),
+    ToggleReducedMotion,
+    SetLanguage(Option<Language>),
+    SetHintsPerGame(u8),
     NavigateHome,
     PromptUserForCustomSeed,
 
     StopPropagation,
 }
 
+impl Action {
+    pub fn is_choice(&self) -> bool {
+        matches!(
+            self,
+            Action::ChooseCharacter(_)
+                | Action::ChooseBooster(_)
+                | Action::ChooseDequeue(_)
+                | Action::ChooseAction(_)
+        )
+    }
+}
+
 trait Touches {
     fn touches(&self, canvas_coords: (f64, f64)) -> bool;
 }
@@ -54,6 +133,9 @@ impl Touches for Component {
             Component::Circle { shape, .. } => shape.touches(canvas_coords),
             Component::Image { shape, .. } => shape.touches(canvas_coords),
             Component::UnclickablePath { .. } => false,
+            Component::Text { .. } => {
+                text::bounds(self).is_some_and(|bounds| bounds.touches(canvas_coords))
+            }
         }
     }
 }
@@ -75,3 +157,39 @@ impl Touches for Circle {
         distance_squared <= radius_squared
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colors::Rgba;
+
+    fn button(x: f64, action: Action) -> Component {
+        Component::Circle {
+            fill_color: Rgba::transparent(),
+            shape: Circle {
+                x,
+                y: 0.0,
+                radius: 10.0,
+            },
+            on_click: Some(action),
+        }
+    }
+
+    #[test]
+    fn only_choices_are_taken_from_clicks() {
+        let components = vec![
+            button(0.0, Action::ChooseCharacter(Character::Ninja)),
+            button(100.0, Action::StartReplay),
+        ];
+
+        assert_eq!(
+            Some(Action::ChooseCharacter(Character::Ninja)),
+            choice_triggered_by_click_at((0.0, 0.0), &components)
+        );
+        assert_eq!(
+            None,
+            choice_triggered_by_click_at((100.0, 0.0), &components)
+        );
+        assert_eq!(None, choice_triggered_by_click_at((50.0, 0.0), &components));
+    }
+}
@@ -1,7 +1,37 @@
-use crate::opponent::Difficulty;
+use crate::i18n::{Language, Messages};
+use crate::render::Motion;
+use crate::settings::Settings;
+use crate::theme::Theme;
 
 #[derive(Debug, Clone)]
 pub struct Context {
-    pub computer_difficulty: Difficulty,
+    pub settings: Settings,
     pub current_time: f64,
+    pub prefers_reduced_motion: bool,
+    pub detected_language: Option<Language>,
+}
+
+impl Context {
+    pub fn theme(&self) -> &'static Theme {
+        Theme::named(self.settings.theme)
+    }
+
+    pub fn motion(&self) -> Motion {
+        if self.settings.reduce_motion || self.prefers_reduced_motion {
+            Motion::Reduced
+        } else {
+            Motion::Full
+        }
+    }
+
+    pub fn language(&self) -> Language {
+        self.settings
+            .language
+            .or(self.detected_language)
+            .unwrap_or(Language::English)
+    }
+
+    pub fn messages(&self) -> Messages {
+        Messages::new(self.language())
+    }
 }
@@ -0,0 +1,711 @@
+use crate::analysis::{Choice, Decision, Evaluation, RoundAnalysis};
+use crate::click::Action;
+use crate::encyclopedia::{Matchup, MoveSource};
+use crate::forecast::QueueForecast;
+use crate::game_summary::GameSummary;
+use crate::helpers;
+use crate::hint::{Hint, HintReason};
+use crate::i18n::Messages;
+use crate::matchup_matrix;
+use crate::opponent::{ActionPreference, DequeuePreference};
+use crate::phase::Phase;
+use crate::replay::ReplayState;
+use crate::round_log::{RoundLog, RoundLogEntry};
+use crate::sandbox::SandboxState;
+use crate::settings::{AnimationSpeed, ThemeName};
+use crate::side::Side;
+use crate::state::{SinglePlayerState, State};
+
+use nzscq::{
+    choices::{self, ArsenalItem, Booster, Character, DequeueChoice, Move},
+    game::Config,
+    outcomes::ActionPointsDestroyed,
+    scoreboard::{ActionlessPlayer, DequeueingPlayer, Queue},
+};
+
+pub fn describe_state(state: &State, messages: Messages) -> String {
+    match state {
+        State::HomeScreen => messages.text("state.home"),
+        State::SettingsScreen => messages.text("state.settings"),
+        State::StatsScreen(stats) => {
+            messages.format("state.stats", &[&stats.current_streak, &stats.best_streak])
+        }
+        State::EncyclopediaScreen(None) => messages.text("state.encyclopedia"),
+        State::EncyclopediaScreen(Some(m)) => {
+            let matchup = Matchup::from(*m);
+
+            messages.format(
+                "state.encyclopedia_move",
+                &[
+                    &messages.move_name(*m),
+                    &move_list(&matchup.wins, messages),
+                    &move_list(&matchup.losses, messages),
+                    &move_list(&matchup.trades, messages),
+                    &move_list(&matchup.neutral, messages),
+                ],
+            )
+        }
+        State::MatrixScreen(view) => match view.selected_cell {
+            Some((row, column)) => {
+                let points = matchup_matrix::cell_points(row, column);
+
+                messages.format(
+                    "state.matrix_cell",
+                    &[
+                        &messages.move_name(row),
+                        &messages.move_name(column),
+                        &points[0],
+                        &points[1],
+                    ],
+                )
+            }
+            None => messages.format("state.matrix", &[&view.rows().len(), &view.columns().len()]),
+        },
+        State::SinglePlayer(state) => describe_single_player(state, messages),
+        State::Replay(state) => describe_replay(state, messages),
+        State::Sandbox(state) => describe_sandbox(state, messages),
+    }
+}
+
+pub fn describe_phase(phase: &Phase, messages: Messages) -> String {
+    if let Some((side, forecasts)) = phase.queue_forecast() {
+        return describe_forecasts(side, forecasts, messages);
+    }
+
+    match phase {
+        Phase::ChooseCharacter(phase) => messages.format(
+            "phase.choose_character",
+            &[&character_list(&phase.available_characters, messages)],
+        ),
+
+        Phase::RechooseCharacter(phase) => messages.format(
+            "phase.rechoose_character",
+            &[
+                &messages.character_name(phase.previously_mutually_chosen_character),
+                &character_list(&phase.available_characters, messages),
+            ],
+        ),
+
+        Phase::ChooseBooster(phase) => messages.format(
+            "phase.choose_booster",
+            &[
+                &messages.character_name(phase.previous_outcome[HUMAN].0),
+                &messages.character_name(phase.previous_outcome[COMPUTER].0),
+                &booster_list(&phase.available_boosters, messages),
+            ],
+        ),
+
+        Phase::ChooseFirstDequeue(phase) => messages.format(
+            "phase.choose_first_dequeue",
+            &[
+                &describe_players(
+                    PlayerSummary::from(&phase.scoreboard[HUMAN]),
+                    PlayerSummary::from(&phase.scoreboard[COMPUTER]),
+                    messages,
+                ),
+                &dequeue_list(&phase.available_dequeues[HUMAN], messages),
+            ],
+        ),
+
+        Phase::ChooseAction(phase) => messages.format(
+            "phase.choose_action",
+            &[
+                &dequeue_name(phase.previous_outcome[HUMAN], messages),
+                &dequeue_name(phase.previous_outcome[COMPUTER], messages),
+                &describe_players(
+                    PlayerSummary::from(&phase.scoreboard[HUMAN]),
+                    PlayerSummary::from(&phase.scoreboard[COMPUTER]),
+                    messages,
+                ),
+                &action_list(&phase.available_actions[HUMAN], messages),
+            ],
+        ),
+
+        Phase::ChooseSubsequentDequeue(phase) => messages.format(
+            "phase.choose_subsequent_dequeue",
+            &[
+                &describe_outcome(&phase.previous_outcome, messages),
+                &describe_players(
+                    PlayerSummary::from(&phase.scoreboard[HUMAN]),
+                    PlayerSummary::from(&phase.scoreboard[COMPUTER]),
+                    messages,
+                ),
+                &dequeue_list(&phase.available_dequeues[HUMAN], messages),
+            ],
+        ),
+
+        Phase::GameOver(phase) => {
+            let human_health =
+                helpers::opponent_points_to_own_health(phase.scoreboard[COMPUTER].points);
+            let computer_health =
+                helpers::opponent_points_to_own_health(phase.scoreboard[HUMAN].points);
+            let result = if phase.scoreboard[HUMAN].points >= Config::default().points_to_win {
+                messages.text("outcome.won")
+            } else {
+                messages.text("outcome.lost")
+            };
+
+            messages.format(
+                "phase.game_over",
+                &[
+                    &describe_outcome(&phase.previous_outcome, messages),
+                    &result,
+                    &human_health,
+                    &computer_health,
+                ],
+            )
+        }
+    }
+}
+
+pub fn describe_action(action: &Action, messages: Messages) -> String {
+    match action {
+        Action::StartSinglePlayerGame => messages.text("action.start_single_player_game"),
+        Action::NavigateToSettingsScreen => messages.text("action.settings"),
+        Action::NavigateToStatsScreen => messages.text("action.stats"),
+        Action::NavigateToEncyclopedia => messages.text("action.encyclopedia"),
+        Action::NavigateToMatchupMatrix => messages.text("action.matchup_matrix"),
+        Action::StartSandbox => messages.text("action.start_sandbox"),
+        Action::ReplayMatch(index) => messages.format("action.replay_match", &[&(index + 1)]),
+
+        Action::ChooseCharacter(character) => {
+            messages.format("action.choose", &[&messages.character_name(*character)])
+        }
+        Action::ChooseBooster(booster) => {
+            messages.format("action.choose", &[&messages.booster_name(*booster)])
+        }
+        Action::ChooseDequeue(dequeue) => dequeue_name(*dequeue, messages),
+        Action::ChooseAction(action) => {
+            messages.format("action.play", &[&action_name(*action, messages)])
+        }
+        Action::WaitForUserToChooseMoveToInspect => messages.text("action.inspect_a_move"),
+        Action::InspectMove(m) => messages.format("action.inspect", &[&messages.move_name(*m)]),
+        Action::StopInspectingMove => messages.text("action.stop_inspecting"),
+        Action::ForecastQueue(side) => {
+            messages.format("action.forecast_queue", &[&side_name(*side, messages)])
+        }
+        Action::StartReplay => messages.text("action.watch_replay"),
+        Action::ToggleRoundLog => messages.text("action.toggle_round_log"),
+        Action::ScrollRoundLogUp => messages.text("action.scroll_round_log_up"),
+        Action::ScrollRoundLogDown => messages.text("action.scroll_round_log_down"),
+        Action::AnalyzeGame => messages.text("action.analyze_game"),
+        Action::ShowHint => messages.text("action.show_hint"),
+
+        Action::ToggleReplayPlayback => messages.text("action.toggle_replay_playback"),
+        Action::StepReplayForward => messages.text("action.step_replay_forward"),
+        Action::StepReplayBackward => messages.text("action.step_replay_backward"),
+        Action::SkipToNextRound => messages.text("action.next_round"),
+        Action::SkipToPreviousRound => messages.text("action.previous_round"),
+        Action::SeekReplay(index) => messages.format("action.seek_replay", &[&(index + 1)]),
+        Action::BranchReplay => messages.text("action.branch_replay"),
+        Action::UndoChoice => messages.text("action.undo_choice"),
+        Action::RedoChoice => messages.text("action.redo_choice"),
+
+        Action::SelectEncyclopediaMove(m) => {
+            messages.format("action.look_up_move", &[&messages.move_name(*m)])
+        }
+        Action::SelectMatrixCell(row, column) => messages.format(
+            "action.select_matrix_cell",
+            &[&messages.move_name(*row), &messages.move_name(*column)],
+        ),
+        Action::SetMatrixRowFilter(filter) => messages.format(
+            "action.filter_matrix_rows",
+            &[&move_source_name(*filter, messages)],
+        ),
+        Action::SetMatrixColumnFilter(filter) => messages.format(
+            "action.filter_matrix_columns",
+            &[&move_source_name(*filter, messages)],
+        ),
+        Action::ZoomMatrixIn => messages.text("action.zoom_in"),
+        Action::ZoomMatrixOut => messages.text("action.zoom_out"),
+        Action::ResetMatrixView => messages.text("action.reset_zoom"),
+
+        Action::SetComputerDifficulty(difficulty) => messages.format(
+            "action.set_difficulty",
+            &[&messages.difficulty_name(*difficulty)],
+        ),
+        Action::SetAnimationSpeed(speed) => match speed {
+            AnimationSpeed::Half => messages.text("action.speed_half"),
+            AnimationSpeed::Normal => messages.text("action.speed_normal"),
+            AnimationSpeed::Double => messages.text("action.speed_double"),
+            AnimationSpeed::Instant => messages.text("action.speed_instant"),
+        },
+        Action::SetTheme(theme) => match theme {
+            ThemeName::Classic => messages.text("action.theme_classic"),
+            ThemeName::Dark => messages.text("action.theme_dark"),
+            ThemeName::HighContrast => messages.text("action.theme_high_contrast"),
+            ThemeName::Deuteranopia => messages.text("action.theme_deuteranopia"),
+        },
+        Action::ToggleReducedMotion => messages.text("action.toggle_reduced_motion"),
+        Action::SetLanguage(Some(language)) => {
+            messages.format("action.set_language", &[&messages.language_name(*language)])
+        }
+        Action::SetLanguage(None) => messages.text("action.detect_language"),
+        Action::SetHintsPerGame(hints_per_game) => {
+            messages.format("action.set_hints_per_game", &[hints_per_game])
+        }
+        Action::NavigateHome => messages.text("action.home"),
+        Action::PromptUserForCustomSeed => messages.text("action.custom_seed"),
+
+        Action::StopPropagation => String::new(),
+    }
+}
+
+struct PlayerSummary<'a> {
+    points: u8,
+    character: Character,
+    booster: Booster,
+    arsenal: &'a [ArsenalItem],
+    queue: &'a Queue,
+}
+
+impl<'a> From<&'a DequeueingPlayer> for PlayerSummary<'a> {
+    fn from(player: &'a DequeueingPlayer) -> PlayerSummary<'a> {
+        PlayerSummary {
+            points: player.points,
+            character: player.character,
+            booster: player.booster,
+            arsenal: &player.arsenal,
+            queue: &player.queue,
+        }
+    }
+}
+
+impl<'a> From<&'a ActionlessPlayer> for PlayerSummary<'a> {
+    fn from(player: &'a ActionlessPlayer) -> PlayerSummary<'a> {
+        PlayerSummary {
+            points: player.points,
+            character: player.character,
+            booster: player.booster,
+            arsenal: &player.arsenal,
+            queue: &player.queue,
+        }
+    }
+}
+
+fn describe_players(human: PlayerSummary, computer: PlayerSummary, messages: Messages) -> String {
+    format!(
+        "{} {}",
+        describe_player(
+            &messages.text("player.you"),
+            &human,
+            computer.points,
+            messages
+        ),
+        describe_player(
+            &messages.text("player.computer"),
+            &computer,
+            human.points,
+            messages
+        )
+    )
+}
+
+fn describe_player(
+    name: &str,
+    player: &PlayerSummary,
+    opponent_points: u8,
+    messages: Messages,
+) -> String {
+    let queue = player.queue;
+
+    messages.format(
+        "player.summary",
+        &[
+            &name,
+            &messages.character_name(player.character),
+            &messages.booster_name(player.booster),
+            &helpers::opponent_points_to_own_health(opponent_points),
+            &item_list(player.arsenal, messages),
+            &item_list(queue.entrance.as_slice(), messages),
+            &item_list(&queue.pool, messages),
+            &item_list(queue.exit.as_slice(), messages),
+        ],
+    )
+}
+
+fn describe_forecasts(side: Side, forecasts: &[QueueForecast], messages: Messages) -> String {
+    let entries = forecasts
+        .iter()
+        .map(|forecast| {
+            let queue = &forecast.queue;
+
+            messages.format(
+                "forecast.entry",
+                &[
+                    &dequeue_name(forecast.dequeue, messages),
+                    &item_list(&forecast.arsenal, messages),
+                    &item_list(queue.entrance.as_slice(), messages),
+                    &item_list(&queue.pool, messages),
+                    &item_list(queue.exit.as_slice(), messages),
+                ],
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    messages.format(
+        "phase.queue_forecast",
+        &[&side_name(side, messages), &entries],
+    )
+}
+
+fn side_name(side: Side, messages: Messages) -> String {
+    match side {
+        Side::Left => messages.text("player.you"),
+        Side::Right => messages.text("player.computer"),
+    }
+}
+
+pub fn describe_round_analysis(round: &RoundAnalysis, messages: Messages) -> String {
+    let mistake = round
+        .decisions
+        .iter()
+        .find_map(|decision| match decision.evaluation {
+            Evaluation::Better(better, points_lost) => Some((decision.chosen, better, points_lost)),
+            _ => None,
+        });
+
+    if let Some((chosen, better, points_lost)) = mistake {
+        return messages.format(
+            "analysis.round_mistake",
+            &[
+                &round.number,
+                &choice_name(chosen, messages),
+                &choice_name(better, messages),
+                &format!("{:.2}", points_lost),
+            ],
+        );
+    }
+
+    let chosen = round
+        .decisions
+        .last()
+        .map(|decision| choice_name(decision.chosen, messages))
+        .unwrap_or_default();
+    let key = if round
+        .decisions
+        .iter()
+        .any(|decision| decision.evaluation == Evaluation::TooComplex)
+    {
+        "analysis.round_unanalyzed"
+    } else {
+        "analysis.round_best"
+    };
+
+    messages.format(key, &[&round.number, &chosen])
+}
+
+pub fn describe_mistake(decision: &Decision, messages: Messages) -> Option<String> {
+    match decision.evaluation {
+        Evaluation::Better(better, points_lost) => Some(messages.format(
+            "analysis.better",
+            &[
+                &choice_name(decision.chosen, messages),
+                &choice_name(better, messages),
+                &format!("{:.2}", points_lost),
+            ],
+        )),
+        _ => None,
+    }
+}
+
+fn describe_sandbox(state: &SandboxState, messages: Messages) -> String {
+    let seat = match state.seat() {
+        Side::Left => messages.text("sandbox.seat_left"),
+        Side::Right => messages.text("sandbox.seat_right"),
+    };
+
+    messages.format(
+        "state.sandbox",
+        &[&seat, &describe_phase(&state.seated_phase(), messages)],
+    )
+}
+
+fn describe_replay(state: &ReplayState, messages: Messages) -> String {
+    let replay = messages.format("state.replay", &[&describe_phase(state.phase(), messages)]);
+
+    match state.analysis() {
+        Some(analysis) => messages.format(
+            "state.replay_analysis",
+            &[
+                &replay,
+                &analysis
+                    .rounds
+                    .iter()
+                    .map(|round| describe_round_analysis(round, messages))
+                    .collect::<Vec<String>>()
+                    .join(" "),
+            ],
+        ),
+        None => replay,
+    }
+}
+
+fn describe_single_player(state: &SinglePlayerState, messages: Messages) -> String {
+    let phase = describe_phase(&state.phase, messages);
+    let phase = match &state.summary {
+        Some(summary) => messages.format(
+            "state.game_summary",
+            &[&phase, &describe_summary(summary, messages)],
+        ),
+        None => phase,
+    };
+    let phase = match &state.hint {
+        Some(hint) => messages.format("state.hint", &[&phase, &describe_hint(hint, messages)]),
+        None => phase,
+    };
+
+    if state.round_log.is_expanded {
+        messages.format(
+            "state.round_log",
+            &[&phase, &describe_round_log(&state.round_log, messages)],
+        )
+    } else {
+        phase
+    }
+}
+
+pub fn describe_hint(hint: &Hint, messages: Messages) -> String {
+    let reason_key = match hint.reason {
+        HintReason::Dequeue(DequeuePreference::DrainAndExit) => "hint.reason.drain_and_exit",
+        HintReason::Dequeue(DequeuePreference::JustExit) => "hint.reason.just_exit",
+        HintReason::Action(ActionPreference::GuaranteesWin) => "hint.reason.guarantees_win",
+        HintReason::Action(ActionPreference::GuaranteesPoint) => "hint.reason.guarantees_point",
+        HintReason::Action(ActionPreference::GuaranteesOpponentWontScore) => {
+            "hint.reason.guarantees_opponent_wont_score"
+        }
+        HintReason::Action(ActionPreference::WinPossible) => "hint.reason.win_possible",
+        HintReason::Action(ActionPreference::NonLossPossible) => "hint.reason.non_loss_possible",
+        HintReason::NoPreference => "hint.reason.no_preference",
+    };
+
+    messages.format(
+        "hint.suggestion",
+        &[
+            &choice_name(hint.choice, messages),
+            &messages.text(reason_key),
+        ],
+    )
+}
+
+fn describe_summary(summary: &GameSummary, messages: Messages) -> String {
+    let health = |side: usize| {
+        messages.join(
+            summary.health[side]
+                .iter()
+                .map(|health| health.to_string())
+                .collect(),
+        )
+    };
+
+    messages.format(
+        "summary.description",
+        &[
+            &summary.rounds(),
+            &health(HUMAN),
+            &health(COMPUTER),
+            &summary.items_destroyed[HUMAN],
+            &summary.items_destroyed[COMPUTER],
+            &summary.mirrors_used[HUMAN],
+            &summary.mirrors_used[COMPUTER],
+            &item_list(&summary.scoring_items[HUMAN], messages),
+            &item_list(&summary.scoring_items[COMPUTER], messages),
+        ],
+    )
+}
+
+fn describe_round_log(round_log: &RoundLog, messages: Messages) -> String {
+    round_log
+        .entries
+        .iter()
+        .map(|entry| match entry {
+            RoundLogEntry::Characters {
+                characters,
+                is_rechoose: true,
+            } => messages.format(
+                "log.entry_rechoose",
+                &[&messages.character_name(characters[HUMAN])],
+            ),
+            RoundLogEntry::Characters { characters, .. } => messages.format(
+                "log.entry_characters",
+                &[
+                    &messages.character_name(characters[HUMAN]),
+                    &messages.character_name(characters[COMPUTER]),
+                ],
+            ),
+            RoundLogEntry::Boosters(boosters) => messages.format(
+                "log.entry_boosters",
+                &[
+                    &messages.booster_name(boosters[HUMAN]),
+                    &messages.booster_name(boosters[COMPUTER]),
+                ],
+            ),
+            RoundLogEntry::Round {
+                number,
+                dequeues,
+                outcome,
+                ..
+            } => messages.format(
+                "log.entry_round",
+                &[
+                    number,
+                    &dequeue_name(dequeues[HUMAN], messages),
+                    &dequeue_name(dequeues[COMPUTER], messages),
+                    &outcome
+                        .as_ref()
+                        .map(|outcome| describe_outcome(outcome, messages))
+                        .unwrap_or_default(),
+                ],
+            ),
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn describe_outcome(outcome: &[ActionPointsDestroyed; 2], messages: Messages) -> String {
+    let mut sentences = vec![messages.format(
+        "outcome.round",
+        &[
+            &action_name(outcome[HUMAN].0, messages),
+            &outcome[HUMAN].1,
+            &action_name(outcome[COMPUTER].0, messages),
+            &outcome[COMPUTER].1,
+        ],
+    )];
+    if outcome[HUMAN].2 {
+        sentences.push(messages.text("outcome.human_action_destroyed"));
+    }
+    if outcome[COMPUTER].2 {
+        sentences.push(messages.text("outcome.computer_action_destroyed"));
+    }
+
+    sentences.join(" ")
+}
+
+fn item_list(items: &[ArsenalItem], messages: Messages) -> String {
+    messages.join(
+        items
+            .iter()
+            .map(|&item| item_name(item, messages))
+            .collect(),
+    )
+}
+
+fn move_list(moves: &[Move], messages: Messages) -> String {
+    messages.join(moves.iter().map(|&m| messages.move_name(m)).collect())
+}
+
+fn move_source_name(source: Option<MoveSource>, messages: Messages) -> String {
+    match source {
+        Some(MoveSource::Character(character)) => messages.character_name(character),
+        Some(MoveSource::Booster(booster)) => messages.booster_name(booster),
+        None => messages.text("matrix.all_moves"),
+    }
+}
+
+fn character_list(characters: &[Character], messages: Messages) -> String {
+    messages.join(
+        characters
+            .iter()
+            .map(|&character| messages.character_name(character))
+            .collect(),
+    )
+}
+
+fn booster_list(boosters: &[Booster], messages: Messages) -> String {
+    messages.join(
+        boosters
+            .iter()
+            .map(|&booster| messages.booster_name(booster))
+            .collect(),
+    )
+}
+
+fn dequeue_list(dequeues: &[DequeueChoice], messages: Messages) -> String {
+    messages.join(
+        dequeues
+            .iter()
+            .map(|&dequeue| dequeue_name(dequeue, messages))
+            .collect(),
+    )
+}
+
+fn action_list(actions: &[choices::Action], messages: Messages) -> String {
+    messages.join(
+        actions
+            .iter()
+            .map(|&action| action_name(action, messages))
+            .collect(),
+    )
+}
+
+fn item_name(item: ArsenalItem, messages: Messages) -> String {
+    match item {
+        ArsenalItem::Mirror => messages.text("item.mirror"),
+        ArsenalItem::Move(m) => messages.move_name(m),
+    }
+}
+
+fn action_name(action: choices::Action, messages: Messages) -> String {
+    match action {
+        choices::Action::Move(m) => messages.move_name(m),
+        choices::Action::Mirror(m) => messages.format("action.mirror", &[&messages.move_name(m)]),
+        choices::Action::Concede => messages.text("action.concede"),
+    }
+}
+
+pub fn choice_name(choice: Choice, messages: Messages) -> String {
+    match choice {
+        Choice::Dequeue(dequeue) => dequeue_name(dequeue, messages),
+        Choice::Action(action) => action_name(action, messages),
+    }
+}
+
+pub fn dequeue_name(dequeue: DequeueChoice, messages: Messages) -> String {
+    match dequeue {
+        DequeueChoice::DrainAndExit(item) => {
+            messages.format("dequeue.drain_and_exit", &[&item_name(item, messages)])
+        }
+        DequeueChoice::JustExit => messages.text("dequeue.just_exit"),
+        DequeueChoice::Decline => messages.text("dequeue.decline"),
+    }
+}
+
+const HUMAN: usize = 0;
+const COMPUTER: usize = 1;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Language;
+    use nzscq::game::BatchChoiceGame;
+
+    #[test]
+    fn initial_phase_lists_available_characters() {
+        let phase = Phase::initial(&BatchChoiceGame::default());
+        let description = describe_phase(&phase, Messages::new(Language::English));
+
+        assert!(description.starts_with("Choose a character: "));
+        assert!(description.contains("Ninja"));
+        assert!(description.contains("Zombie"));
+
+        let description = describe_phase(&phase, Messages::new(Language::Spanish));
+
+        assert!(description.starts_with("Elige un personaje: "));
+        assert!(description.contains("Zombi"));
+    }
+
+    #[test]
+    fn outcomes_mention_destroyed_actions() {
+        let kick = choices::Action::Move(Move::Kick);
+        let outcome = [
+            ActionPointsDestroyed(kick, 1, false),
+            ActionPointsDestroyed(kick, 0, true),
+        ];
+        let description = describe_outcome(&outcome, Messages::new(Language::English));
+
+        assert!(description.ends_with("The computer's move was destroyed."));
+        assert!(!description.contains("Your move was destroyed."));
+    }
+}
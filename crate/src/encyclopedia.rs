@@ -0,0 +1,188 @@
+use crate::helpers;
+
+use nzscq::choices::{Action, Booster, Character, Move, PointsAgainst};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MoveSource {
+    Character(Character),
+    Booster(Booster),
+}
+
+impl MoveSource {
+    pub fn logo_move(self) -> Move {
+        match self {
+            MoveSource::Character(character) => helpers::character_logo_move(character),
+            MoveSource::Booster(booster) => helpers::booster_logo_move(booster)
+                .expect("only boosters with moves should be move sources"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MoveGroup {
+    pub source: MoveSource,
+    pub moves: Vec<Move>,
+}
+
+pub fn move_groups() -> Vec<MoveGroup> {
+    Character::all()
+        .into_iter()
+        .flat_map(|character| {
+            let character_group = MoveGroup {
+                source: MoveSource::Character(character),
+                moves: character.moves(),
+            };
+            let booster_groups = character
+                .boosters()
+                .into_iter()
+                .filter(|&booster| helpers::booster_logo_move(booster).is_some())
+                .map(move |booster| MoveGroup {
+                    source: MoveSource::Booster(booster),
+                    moves: booster_moves(character, booster),
+                });
+
+            vec![character_group].into_iter().chain(booster_groups)
+        })
+        .collect()
+}
+
+fn booster_moves(character: Character, booster: Booster) -> Vec<Move> {
+    let character_moves = character.moves();
+    let mut replaced_moves = character_moves.clone();
+    booster.replace_moves(&mut replaced_moves);
+
+    booster
+        .moves()
+        .into_iter()
+        .chain(
+            replaced_moves
+                .into_iter()
+                .filter(|m| !character_moves.contains(m)),
+        )
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interaction {
+    Win,
+    Loss,
+    Trade,
+    Neutral,
+}
+
+impl Interaction {
+    fn between(own: Action, other: Action) -> Interaction {
+        Interaction::from(helpers::vec2_to_arr2(Action::points_of(&[own, other])))
+    }
+}
+
+impl From<[u8; 2]> for Interaction {
+    fn from(points: [u8; 2]) -> Interaction {
+        match points {
+            [0, 0] => Interaction::Neutral,
+            [_, 0] => Interaction::Win,
+            [0, _] => Interaction::Loss,
+            _ => Interaction::Trade,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Matchup {
+    pub wins: Vec<Move>,
+    pub losses: Vec<Move>,
+    pub trades: Vec<Move>,
+    pub neutral: Vec<Move>,
+    pub against_mirror: Interaction,
+    pub is_destructive: bool,
+    pub is_single_use: bool,
+}
+
+impl From<Move> for Matchup {
+    fn from(m: Move) -> Matchup {
+        let mut matchup = Matchup {
+            wins: vec![],
+            losses: vec![],
+            trades: vec![],
+            neutral: vec![],
+            against_mirror: Interaction::between(Action::Move(m), Action::Mirror(m)),
+            is_destructive: m.is_destructive(),
+            is_single_use: m.is_single_use(),
+        };
+
+        for other in Move::all() {
+            let bucket = match Interaction::between(Action::Move(m), Action::Move(other)) {
+                Interaction::Win => &mut matchup.wins,
+                Interaction::Loss => &mut matchup.losses,
+                Interaction::Trade => &mut matchup.trades,
+                Interaction::Neutral => &mut matchup.neutral,
+            };
+            bucket.push(other);
+        }
+
+        matchup
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_move_is_in_exactly_one_group() {
+        let grouped: Vec<Move> = move_groups()
+            .into_iter()
+            .flat_map(|group| group.moves)
+            .collect();
+
+        for m in Move::all() {
+            assert_eq!(
+                1,
+                grouped.iter().filter(|&&grouped| grouped == m).count(),
+                "{:?} should be in exactly one group",
+                m
+            );
+        }
+        assert_eq!(Move::all().len(), grouped.len());
+    }
+
+    #[test]
+    fn matchups_are_mirror_images_of_each_other() {
+        for m in Move::all() {
+            let matchup = Matchup::from(m);
+
+            assert_eq!(
+                Move::all().len(),
+                matchup.wins.len()
+                    + matchup.losses.len()
+                    + matchup.trades.len()
+                    + matchup.neutral.len()
+            );
+            for other in Move::all() {
+                let reverse = Matchup::from(other);
+
+                assert_eq!(matchup.wins.contains(&other), reverse.losses.contains(&m));
+                assert_eq!(matchup.losses.contains(&other), reverse.wins.contains(&m));
+                assert_eq!(matchup.trades.contains(&other), reverse.trades.contains(&m));
+                assert_eq!(
+                    matchup.neutral.contains(&other),
+                    reverse.neutral.contains(&m)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn moves_tie_against_their_own_mirror() {
+        assert!(Move::Zap.is_destructive());
+        assert_eq!(
+            Interaction::Neutral,
+            Matchup::from(Move::Zap).against_mirror
+        );
+        assert!(!Move::Kick.is_destructive());
+        assert_eq!(
+            Interaction::Neutral,
+            Matchup::from(Move::Kick).against_mirror
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use crate::click::Action;
+use crate::paint::{Component, Path, Stroke};
+use crate::shapes::Rect;
+use crate::text;
+use crate::theme::Theme;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone)]
+pub struct Focusable {
+    pub action: Action,
+    pub bounds: Rect,
+}
+
+pub fn focusables(components: &[Component]) -> Vec<Focusable> {
+    components
+        .iter()
+        .filter_map(|component| {
+            let action = component.on_click()?;
+            if let Action::StopPropagation = action {
+                return None;
+            }
+
+            Some(Focusable {
+                action,
+                bounds: bounds(component)?,
+            })
+        })
+        .collect()
+}
+
+pub fn selectable_actions(components: &[Component]) -> Vec<Action> {
+    let mut actions: Vec<Action> = vec![];
+
+    for action in components.iter().filter_map(Component::on_click) {
+        let is_selectable = action.is_choice() || matches!(action, Action::InspectMove(_));
+        if is_selectable && !actions.contains(&action) {
+            actions.push(action);
+        }
+    }
+
+    actions
+}
+
+pub fn selection_index(key: &str) -> Option<usize> {
+    let mut chars = key.chars();
+    let c = chars.next()?.to_ascii_lowercase();
+    if chars.next().is_some() {
+        return None;
+    }
+
+    SELECTION_KEYS.find(c)
+}
+
+pub fn next_focus(
+    focusables: &[Focusable],
+    current: Option<usize>,
+    direction: Direction,
+) -> Option<usize> {
+    let current = match current.filter(|&i| i < focusables.len()) {
+        Some(current) => current,
+        None => return if focusables.is_empty() { None } else { Some(0) },
+    };
+    let (x, y) = center(&focusables[current].bounds);
+
+    focusables
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != current)
+        .filter_map(|(i, focusable)| {
+            let (other_x, other_y) = center(&focusable.bounds);
+            let (dx, dy) = (other_x - x, other_y - y);
+            let (primary, secondary) = match direction {
+                Direction::Up => (-dy, dx),
+                Direction::Down => (dy, dx),
+                Direction::Left => (-dx, dy),
+                Direction::Right => (dx, dy),
+            };
+
+            if primary > 0.0 {
+                Some((i, primary + SECONDARY_AXIS_PENALTY * secondary.abs()))
+            } else {
+                None
+            }
+        })
+        .min_by(|(_, a), (_, b)| a.partial_cmp(b).expect("scores should not be NaN"))
+        .map(|(i, _)| i)
+        .or(Some(current))
+}
+
+pub fn focus_ring(bounds: &Rect, theme: &Theme) -> Component {
+    let ring = Rect {
+        x: bounds.x - RING_PADDING,
+        y: bounds.y - RING_PADDING,
+        width: bounds.width + 2.0 * RING_PADDING,
+        height: bounds.height + 2.0 * RING_PADDING,
+    };
+
+    Component::UnclickablePath {
+        path: Path::rounded_rect(&ring, RING_CORNER_RADIUS),
+        fill_color: None,
+        stroke: Some(Stroke {
+            color: theme.focus_ring,
+            width: RING_WIDTH,
+        }),
+    }
+}
+
+fn bounds(component: &Component) -> Option<Rect> {
+    match component {
+        Component::Rect { shape, .. } => Some(shape.clone()),
+        Component::Image { shape, .. } => Some(shape.clone()),
+        Component::Circle { shape, .. } => Some(Rect {
+            x: shape.x - shape.radius,
+            y: shape.y - shape.radius,
+            width: 2.0 * shape.radius,
+            height: 2.0 * shape.radius,
+        }),
+        Component::Text { .. } => text::bounds(component),
+        Component::Background { .. } | Component::UnclickablePath { .. } => None,
+    }
+}
+
+fn center(rect: &Rect) -> (f64, f64) {
+    (rect.x + 0.5 * rect.width, rect.y + 0.5 * rect.height)
+}
+
+const SELECTION_KEYS: &str = "123456789abcdefghjklmnopqrstuvwxyz";
+const SECONDARY_AXIS_PENALTY: f64 = 2.0;
+const RING_PADDING: f64 = 8.0;
+const RING_CORNER_RADIUS: f64 = 16.0;
+const RING_WIDTH: f64 = 6.0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn focusable(x: f64, y: f64) -> Focusable {
+        Focusable {
+            action: Action::NavigateHome,
+            bounds: Rect {
+                x,
+                y,
+                width: 10.0,
+                height: 10.0,
+            },
+        }
+    }
+
+    #[test]
+    fn selection_keys_skip_the_inspector_key() {
+        assert_eq!(Some(0), selection_index("1"));
+        assert_eq!(Some(9), selection_index("A"));
+        assert_eq!(Some(17), selection_index("j"));
+        assert_eq!(None, selection_index("i"));
+        assert_eq!(None, selection_index("Enter"));
+    }
+
+    #[test]
+    fn arrows_prefer_aligned_neighbours() {
+        let focusables = vec![
+            focusable(0.0, 0.0),
+            focusable(100.0, 60.0),
+            focusable(120.0, 0.0),
+            focusable(0.0, 100.0),
+        ];
+
+        assert_eq!(Some(0), next_focus(&focusables, None, Direction::Right));
+        assert_eq!(Some(2), next_focus(&focusables, Some(0), Direction::Right));
+        assert_eq!(Some(3), next_focus(&focusables, Some(0), Direction::Down));
+        assert_eq!(Some(0), next_focus(&focusables, Some(0), Direction::Up));
+    }
+}
@@ -0,0 +1,91 @@
+use crate::helpers;
+
+use nzscq::{
+    choices::{ArsenalItem, BatchChoice, DequeueChoice},
+    game::BatchChoiceGame,
+    scoreboard::Queue,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QueueForecast {
+    pub dequeue: DequeueChoice,
+    pub arsenal: Vec<ArsenalItem>,
+    pub queue: Queue,
+}
+
+pub fn queue_forecasts(game: &BatchChoiceGame) -> [Vec<QueueForecast>; 2] {
+    let available_dequeues = game
+        .choices()
+        .dequeue_choices()
+        .expect("should be able to choose dequeue");
+
+    helpers::vec2_to_arr2(
+        (0..2)
+            .map(|side| {
+                let other_dequeue = available_dequeues[1 - side][0];
+
+                available_dequeues[side]
+                    .iter()
+                    .map(|&dequeue| {
+                        let mut dequeues = vec![other_dequeue, other_dequeue];
+                        dequeues[side] = dequeue;
+
+                        forecast(game, dequeues, side)
+                    })
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
+fn forecast(game: &BatchChoiceGame, dequeues: Vec<DequeueChoice>, side: usize) -> QueueForecast {
+    let dequeue = dequeues[side];
+    let mut game = game.clone();
+    game.choose(BatchChoice::DequeueChoices(dequeues))
+        .expect("available dequeues should be legal");
+    let player = game
+        .scoreboard()
+        .actionless()
+        .expect("should be choosing actions")
+        .remove(side);
+
+    QueueForecast {
+        dequeue,
+        arsenal: player.arsenal,
+        queue: player.queue,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test_games;
+
+    #[test]
+    fn draining_moves_an_item_from_the_pool_to_the_exit() {
+        let game = test_games::game_after_boosters();
+        let before = game
+            .scoreboard()
+            .dequeueing()
+            .expect("should be dequeueing")
+            .remove(0);
+
+        let forecasts = queue_forecasts(&game);
+
+        for forecast in &forecasts[0] {
+            match forecast.dequeue {
+                DequeueChoice::DrainAndExit(item) => {
+                    assert_eq!(Some(item), forecast.queue.exit);
+                    assert!(!forecast.queue.pool.contains(&item));
+                }
+                DequeueChoice::Decline => assert_eq!(before.arsenal, forecast.arsenal),
+                DequeueChoice::JustExit => {}
+            }
+        }
+        let available_dequeues = game
+            .choices()
+            .dequeue_choices()
+            .expect("should be able to choose dequeue");
+        assert_eq!(available_dequeues[1].len(), forecasts[1].len());
+    }
+}
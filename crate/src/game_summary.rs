@@ -0,0 +1,98 @@
+use crate::helpers;
+
+use nzscq::{
+    choices::{Action, ArsenalItem, BatchChoice, Character},
+    game::BatchChoiceGame,
+    outcomes::{ActionPointsDestroyed, Outcome},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GameSummary {
+    pub characters: [Character; 2],
+    pub health: [Vec<u8>; 2],
+    pub items_destroyed: [usize; 2],
+    pub mirrors_used: [usize; 2],
+    pub scoring_items: [Vec<ArsenalItem>; 2],
+}
+
+impl GameSummary {
+    pub fn from_choices(choices: &[BatchChoice]) -> Result<GameSummary, ()> {
+        let mut game = BatchChoiceGame::default();
+        let mut summary: Option<GameSummary> = None;
+
+        for choice in choices {
+            match game.choose(choice.clone())? {
+                Outcome::CharacterPhaseDone(headstarts) => {
+                    summary = Some(GameSummary {
+                        characters: [headstarts[0].0, headstarts[1].0],
+                        health: [
+                            vec![helpers::opponent_points_to_own_health(headstarts[1].1)],
+                            vec![helpers::opponent_points_to_own_health(headstarts[0].1)],
+                        ],
+                        items_destroyed: [0, 0],
+                        mirrors_used: [0, 0],
+                        scoring_items: [vec![], vec![]],
+                    });
+                }
+                Outcome::ActionPhaseDone(outcome) | Outcome::GameOver(outcome) => {
+                    summary.as_mut().ok_or(())?.record_round(&outcome);
+                }
+                _ => {}
+            }
+        }
+
+        summary.ok_or(())
+    }
+
+    pub fn rounds(&self) -> usize {
+        self.health[0].len() - 1
+    }
+
+    fn record_round(&mut self, outcome: &[ActionPointsDestroyed]) {
+        for side in 0..2 {
+            let ActionPointsDestroyed(action, points, is_destroyed) = outcome[side];
+            let opponent_points = outcome[1 - side].1;
+            let health = *self.health[side]
+                .last()
+                .expect("should have initial health");
+            self.health[side].push((health as i8 - opponent_points).max(0) as u8);
+
+            if is_destroyed {
+                self.items_destroyed[side] += 1;
+            }
+
+            let item = match action {
+                Action::Move(m) => ArsenalItem::Move(m),
+                Action::Mirror(_) => {
+                    self.mirrors_used[side] += 1;
+                    ArsenalItem::Mirror
+                }
+                Action::Concede => continue,
+            };
+
+            if points > 0 && !self.scoring_items[side].contains(&item) {
+                self.scoring_items[side].push(item);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test_games;
+    use nzscq::choices::Move;
+
+    #[test]
+    fn health_drops_by_the_points_the_opponent_scores() {
+        let choices = test_games::first_round_choices(Action::Move(Move::Kick));
+        let summary = GameSummary::from_choices(&choices).expect("choices should be legal");
+
+        assert_eq!(1, summary.rounds());
+        assert_eq!([vec![5, 5], vec![5, 4]], summary.health);
+        assert_eq!(
+            [vec![ArsenalItem::Move(Move::Kick)], vec![]],
+            summary.scoring_items
+        );
+    }
+}
@@ -83,6 +83,14 @@ pub fn set_local_storage_item(window: &Window, key: &str, value: &str) {
     }
 }
 
+pub fn remove_local_storage_item(window: &Window, key: &str) {
+    if let Ok(Some(storage)) = window.local_storage() {
+        storage
+            .remove_item(key)
+            .expect("should be able to remove local storage item");
+    }
+}
+
 pub const SQRT_3: f64 = 1.732_050_807_568_877_2;
 
 pub trait QueueArsenal {
@@ -109,3 +117,40 @@ impl QueueArsenal for DequeueingPlayer {
         &self.arsenal
     }
 }
+
+#[cfg(test)]
+pub mod test_games {
+    use nzscq::choices::{Action, BatchChoice, Booster, Character, DequeueChoice, Move};
+    use nzscq::game::BatchChoiceGame;
+
+    pub fn opening_choices() -> Vec<BatchChoice> {
+        vec![
+            BatchChoice::Characters(vec![Character::Ninja, Character::Zombie]),
+            BatchChoice::Boosters(vec![Booster::Shadow, Booster::Regenerative]),
+        ]
+    }
+
+    pub fn first_round_choices(human_action: Action) -> Vec<BatchChoice> {
+        let mut choices = opening_choices();
+        choices.push(BatchChoice::DequeueChoices(vec![
+            DequeueChoice::Decline,
+            DequeueChoice::Decline,
+        ]));
+        choices.push(BatchChoice::Actions(vec![
+            human_action,
+            Action::Move(Move::Rampage),
+        ]));
+
+        choices
+    }
+
+    pub fn game_after_boosters() -> BatchChoiceGame {
+        let mut game = BatchChoiceGame::default();
+        for choice in opening_choices() {
+            game.choose(choice)
+                .expect("opening choices should be legal");
+        }
+
+        game
+    }
+}
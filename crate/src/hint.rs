@@ -0,0 +1,119 @@
+use crate::analysis::Choice;
+use crate::opponent::{self, ActionPreference, DequeuePreference};
+
+use nzscq::{choices::BatchChoices, game::BatchChoiceGame};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HintReason {
+    Dequeue(DequeuePreference),
+    Action(ActionPreference),
+    NoPreference,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hint {
+    pub choice: Choice,
+    pub reason: HintReason,
+}
+
+pub fn hint(game: &BatchChoiceGame) -> Option<Hint> {
+    match game.choices() {
+        BatchChoices::DequeueChoices(mut choices) => {
+            let (dequeues, preference) = opponent::preferred_dequeues(choices.remove(HUMAN));
+
+            Some(Hint {
+                choice: Choice::Dequeue(*dequeues.first()?),
+                reason: preference.map_or(HintReason::NoPreference, HintReason::Dequeue),
+            })
+        }
+        BatchChoices::Actions(_) => {
+            let (actions, preference) = opponent::preferred_actions(game, HUMAN)?;
+
+            Some(Hint {
+                choice: Choice::Action(*actions.first()?),
+                reason: preference.map_or(HintReason::NoPreference, HintReason::Action),
+            })
+        }
+        _ => None,
+    }
+}
+
+const HUMAN: usize = 0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test_games;
+    use nzscq::choices::{Action, ArsenalItem, BatchChoice, DequeueChoice, Move};
+
+    #[test]
+    fn hints_are_only_given_for_dequeues_and_actions() {
+        assert_eq!(None, hint(&BatchChoiceGame::default()));
+
+        let mut game = test_games::game_after_boosters();
+        let dequeue_hint = hint(&game).expect("should hint a dequeue");
+        assert!(matches!(dequeue_hint.choice, Choice::Dequeue(_)));
+
+        game.choose(BatchChoice::DequeueChoices(vec![
+            DequeueChoice::Decline,
+            DequeueChoice::Decline,
+        ]))
+        .unwrap();
+        let action_hint = hint(&game).expect("should hint an action");
+        assert!(matches!(action_hint.choice, Choice::Action(_)));
+        assert_ne!(HintReason::NoPreference, action_hint.reason);
+    }
+
+    #[test]
+    fn hints_against_an_opponent_one_point_from_winning_avoid_losing_first() {
+        let decline = DequeueChoice::Decline;
+        let drain = |m| DequeueChoice::DrainAndExit(ArsenalItem::Move(m));
+        let actions = |human, computer| {
+            BatchChoice::Actions(vec![Action::Move(human), Action::Move(computer)])
+        };
+        let choices = vec![
+            BatchChoice::DequeueChoices(vec![decline, decline]),
+            actions(Move::Kick, Move::Muscle),
+            BatchChoice::DequeueChoices(vec![decline, decline]),
+            actions(Move::NinjaSword, Move::Rampage),
+            BatchChoice::DequeueChoices(vec![drain(Move::Kick), drain(Move::Muscle)]),
+            actions(Move::Nunchucks, Move::Regenerate),
+            BatchChoice::DequeueChoices(vec![decline, decline]),
+            actions(Move::ShadowFireball, Move::Zap),
+            BatchChoice::DequeueChoices(vec![drain(Move::NinjaSword), decline]),
+            actions(Move::ShadowSlip, Move::Gravedigger),
+            BatchChoice::DequeueChoices(vec![drain(Move::Nunchucks), drain(Move::Rampage)]),
+            actions(Move::Kick, Move::Muscle),
+        ];
+        let mut game = test_games::game_after_boosters();
+        for choice in choices {
+            game.choose(choice).unwrap();
+        }
+
+        let mut rampage_only = game.clone();
+        rampage_only
+            .choose(BatchChoice::DequeueChoices(vec![
+                drain(Move::ShadowSlip),
+                drain(Move::Gravedigger),
+            ]))
+            .unwrap();
+        assert_eq!(4, rampage_only.scoreboard().actionless().unwrap()[1].points);
+        assert_eq!(
+            Some(Hint {
+                choice: Choice::Action(Action::Move(Move::NinjaSword)),
+                reason: HintReason::NoPreference,
+            }),
+            hint(&rampage_only)
+        );
+
+        game.choose(BatchChoice::DequeueChoices(vec![
+            drain(Move::ShadowSlip),
+            decline,
+        ]))
+        .unwrap();
+        assert_eq!(
+            HintReason::Action(ActionPreference::GuaranteesWin),
+            hint(&game).unwrap().reason
+        );
+    }
+}
@@ -0,0 +1,268 @@
+use crate::helpers;
+use crate::notation::GameRecord;
+use crate::opponent::Difficulty;
+
+use nzscq::choices::{BatchChoice, Booster, Character};
+use nzscq::scoreboard::FinishedPlayer;
+
+use web_sys::Window;
+
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MatchHistory {
+    pub matches: Vec<MatchSummary>,
+}
+
+impl MatchHistory {
+    pub fn load(window: &Window) -> MatchHistory {
+        helpers::get_local_storage_item(window, STORAGE_KEY)
+            .map(|history| history.parse().unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, window: &Window) {
+        helpers::set_local_storage_item(window, STORAGE_KEY, &self.to_string()[..]);
+    }
+
+    pub fn push(&mut self, summary: MatchSummary) {
+        self.matches.push(summary);
+
+        if self.matches.len() > MAX_MATCHES {
+            let excess = self.matches.len() - MAX_MATCHES;
+            self.matches.drain(..excess);
+        }
+    }
+}
+
+impl Display for MatchHistory {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (i, summary) in self.matches.iter().enumerate() {
+            if i > 0 {
+                writeln!(f, "{}", MATCH_SEPARATOR)?;
+            }
+            write!(f, "{}", summary)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for MatchHistory {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<MatchHistory, ()> {
+        let mut matches = vec![];
+        let mut current = String::new();
+
+        for line in s.lines().chain(std::iter::once(MATCH_SEPARATOR)) {
+            if line.trim() == MATCH_SEPARATOR {
+                if !current.trim().is_empty() {
+                    if let Ok(summary) = current.parse() {
+                        matches.push(summary);
+                    }
+                }
+                current.clear();
+            } else {
+                current.push_str(line);
+                current.push('\n');
+            }
+        }
+
+        Ok(MatchHistory { matches })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchSummary {
+    pub date: u64,
+    pub did_human_win: bool,
+    pub health: [u8; 2],
+    pub hints_used: u8,
+    pub record: GameRecord,
+}
+
+impl MatchSummary {
+    pub fn new(
+        date: u64,
+        record: GameRecord,
+        winner_index: usize,
+        scoreboard: &[FinishedPlayer; 2],
+        hints_used: u8,
+    ) -> MatchSummary {
+        MatchSummary {
+            date,
+            did_human_win: winner_index == HUMAN,
+            health: [
+                helpers::opponent_points_to_own_health(scoreboard[COMPUTER].points),
+                helpers::opponent_points_to_own_health(scoreboard[HUMAN].points),
+            ],
+            hints_used,
+            record,
+        }
+    }
+
+    pub fn difficulty(&self) -> Difficulty {
+        self.record.difficulty
+    }
+
+    pub fn characters(&self) -> Option<[Character; 2]> {
+        self.record
+            .choices
+            .iter()
+            .rev()
+            .find_map(|choice| match choice {
+                BatchChoice::Characters(characters) => {
+                    Some([characters[HUMAN], characters[COMPUTER]])
+                }
+                _ => None,
+            })
+    }
+
+    pub fn boosters(&self) -> Option<[Booster; 2]> {
+        self.record.choices.iter().find_map(|choice| match choice {
+            BatchChoice::Boosters(boosters) => Some([boosters[HUMAN], boosters[COMPUTER]]),
+            _ => None,
+        })
+    }
+}
+
+impl Display for MatchSummary {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        writeln!(f, "{}={}", DATE_HEADER, self.date)?;
+        writeln!(
+            f,
+            "{}={}",
+            RESULT_HEADER,
+            if self.did_human_win { WIN } else { LOSS }
+        )?;
+        writeln!(
+            f,
+            "{}={} {}",
+            HEALTH_HEADER, self.health[HUMAN], self.health[COMPUTER]
+        )?;
+        writeln!(f, "{}={}", HINTS_HEADER, self.hints_used)?;
+        write!(f, "{}", self.record)
+    }
+}
+
+impl FromStr for MatchSummary {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<MatchSummary, ()> {
+        let mut lines = s.lines();
+        let date = header_value(lines.next(), DATE_HEADER)?
+            .parse()
+            .map_err(|_| ())?;
+        let did_human_win = match header_value(lines.next(), RESULT_HEADER)? {
+            WIN => true,
+            LOSS => false,
+            _ => return Err(()),
+        };
+        let health: Vec<u8> = header_value(lines.next(), HEALTH_HEADER)?
+            .split_whitespace()
+            .map(|health| health.parse().map_err(|_| ()))
+            .collect::<Result<_, ()>>()?;
+        if health.len() != 2 {
+            return Err(());
+        }
+        let mut lines = lines.peekable();
+        let hints_used = match lines.peek().and_then(|line| line.split_once('=')) {
+            Some((HINTS_HEADER, value)) => {
+                lines.next();
+                value.trim().parse().map_err(|_| ())?
+            }
+            _ => 0,
+        };
+        let record = lines
+            .collect::<Vec<&str>>()
+            .join("\n")
+            .parse()
+            .map_err(|_| ())?;
+
+        Ok(MatchSummary {
+            date,
+            did_human_win,
+            health: helpers::vec2_to_arr2(health),
+            hints_used,
+            record,
+        })
+    }
+}
+
+fn header_value<'a>(line: Option<&'a str>, header: &str) -> Result<&'a str, ()> {
+    let (key, value) = line.ok_or(())?.split_once('=').ok_or(())?;
+
+    if key.trim() == header {
+        Ok(value.trim())
+    } else {
+        Err(())
+    }
+}
+
+const STORAGE_KEY: &str = "nzscq_match_history";
+const MAX_MATCHES: usize = 500;
+const MATCH_SEPARATOR: &str = "---";
+
+const DATE_HEADER: &str = "date";
+const RESULT_HEADER: &str = "result";
+const HEALTH_HEADER: &str = "health";
+const HINTS_HEADER: &str = "hints";
+const WIN: &str = "win";
+const LOSS: &str = "loss";
+
+const HUMAN: usize = 0;
+const COMPUTER: usize = 1;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(date: u64, did_human_win: bool) -> MatchSummary {
+        MatchSummary {
+            date,
+            did_human_win,
+            health: [2, 0],
+            hints_used: 1,
+            record: "seed=history\ndifficulty=medium\nninja zombie\nshadow regenerative\n"
+                .parse()
+                .unwrap(),
+        }
+    }
+
+    #[test]
+    fn history_round_trips() {
+        let history = MatchHistory {
+            matches: vec![summary(1, true), summary(2, false)],
+        };
+
+        assert_eq!(Ok(history.clone()), history.to_string().parse());
+    }
+
+    #[test]
+    fn corrupt_matches_are_skipped() {
+        let history = MatchHistory {
+            matches: vec![summary(1, true), summary(2, false)],
+        };
+        let text = history.to_string().replacen("result=win", "result=draw", 1);
+
+        assert_eq!(
+            vec![summary(2, false)],
+            text.parse::<MatchHistory>().unwrap().matches
+        );
+    }
+
+    #[test]
+    fn summaries_saved_before_hints_use_none() {
+        let text = summary(1, true).to_string().replacen("hints=1\n", "", 1);
+
+        assert_eq!(
+            Ok(MatchSummary {
+                hints_used: 0,
+                ..summary(1, true)
+            }),
+            text.parse()
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use crate::click::Action;
+use crate::paint::Component;
+use crate::text;
+use crate::theme::Theme;
+
+pub fn is_hoverable(component: &Component) -> bool {
+    match component.on_click() {
+        Some(Action::StopPropagation) | None => false,
+        Some(_) => true,
+    }
+}
+
+pub fn hover_highlight(component: &Component, theme: &Theme) -> Option<Component> {
+    if !is_hoverable(component) {
+        return None;
+    }
+
+    match component {
+        Component::Rect { shape, .. } | Component::Image { shape, .. } => Some(Component::Rect {
+            fill_color: theme.hover_highlight,
+            shape: shape.clone(),
+            on_click: None,
+        }),
+        Component::Circle { shape, .. } => Some(Component::Circle {
+            fill_color: theme.hover_highlight,
+            shape: shape.clone(),
+            on_click: None,
+        }),
+        Component::Text { .. } => text::bounds(component).map(|shape| Component::Rect {
+            fill_color: theme.hover_highlight,
+            shape,
+            on_click: None,
+        }),
+        Component::Background { .. } | Component::UnclickablePath { .. } => None,
+    }
+}
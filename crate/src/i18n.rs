@@ -0,0 +1,223 @@
+use crate::opponent::Difficulty;
+
+use nzscq::choices::{Booster, Character, Move};
+
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    English,
+    Spanish,
+    Chinese,
+}
+
+impl Language {
+    pub fn all() -> Vec<Language> {
+        vec![Language::English, Language::Spanish, Language::Chinese]
+    }
+
+    pub fn from_tag(tag: &str) -> Option<Language> {
+        let primary_subtag = tag.split(['-', '_']).next()?;
+
+        primary_subtag.to_lowercase().parse().ok()
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Language::English => include_str!("i18n/en.txt"),
+            Language::Spanish => include_str!("i18n/es.txt"),
+            Language::Chinese => include_str!("i18n/zh.txt"),
+        }
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Language::English => write!(f, "en"),
+            Language::Spanish => write!(f, "es"),
+            Language::Chinese => write!(f, "zh"),
+        }
+    }
+}
+
+impl FromStr for Language {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Language, ()> {
+        match s {
+            "en" => Ok(Language::English),
+            "es" => Ok(Language::Spanish),
+            "zh" => Ok(Language::Chinese),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Catalog(HashMap<&'static str, &'static str>);
+
+impl Catalog {
+    fn parse(source: &'static str) -> Catalog {
+        Catalog(
+            source
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .filter_map(|line| line.split_once('='))
+                .map(|(key, value)| {
+                    let value = value.trim();
+                    let value = value
+                        .strip_prefix('"')
+                        .and_then(|value| value.strip_suffix('"'))
+                        .unwrap_or(value);
+
+                    (key.trim(), value)
+                })
+                .collect(),
+        )
+    }
+
+    fn of(language: Language) -> &'static Catalog {
+        static ENGLISH: OnceLock<Catalog> = OnceLock::new();
+        static SPANISH: OnceLock<Catalog> = OnceLock::new();
+        static CHINESE: OnceLock<Catalog> = OnceLock::new();
+
+        let cell = match language {
+            Language::English => &ENGLISH,
+            Language::Spanish => &SPANISH,
+            Language::Chinese => &CHINESE,
+        };
+
+        cell.get_or_init(|| Catalog::parse(language.source()))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Messages {
+    pub language: Language,
+}
+
+impl Messages {
+    pub fn new(language: Language) -> Messages {
+        Messages { language }
+    }
+
+    pub fn text(self, key: &str) -> String {
+        Catalog::of(self.language)
+            .0
+            .get(key)
+            .or_else(|| Catalog::of(Language::English).0.get(key))
+            .map_or_else(|| key.to_string(), |value| value.to_string())
+    }
+
+    pub fn format(self, key: &str, args: &[&dyn Display]) -> String {
+        args.iter()
+            .enumerate()
+            .fold(self.text(key), |text, (i, arg)| {
+                text.replace(&format!("{{{}}}", i)[..], &arg.to_string()[..])
+            })
+    }
+
+    pub fn join(self, items: Vec<String>) -> String {
+        if items.is_empty() {
+            self.text("list.none")
+        } else {
+            items.join(&self.text("list.separator")[..])
+        }
+    }
+
+    pub fn move_name(self, m: Move) -> String {
+        self.text(&format!("move.{:?}", m)[..])
+    }
+
+    pub fn character_name(self, character: Character) -> String {
+        self.text(&format!("character.{:?}", character)[..])
+    }
+
+    pub fn booster_name(self, booster: Booster) -> String {
+        self.text(&format!("booster.{:?}", booster)[..])
+    }
+
+    pub fn difficulty_name(self, difficulty: Difficulty) -> String {
+        self.text(&format!("difficulty.{:?}", difficulty)[..])
+    }
+
+    pub fn language_name(self, language: Language) -> String {
+        self.text(&format!("language.{}", language)[..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    fn keys(language: Language) -> HashSet<&'static str> {
+        Catalog::of(language).0.keys().cloned().collect()
+    }
+
+    #[test]
+    fn every_catalog_has_every_key() {
+        let english = keys(Language::English);
+        let messages = Messages::new(Language::English);
+        let generated_keys = Move::all()
+            .into_iter()
+            .map(|m| format!("move.{:?}", m))
+            .chain(
+                Character::all()
+                    .into_iter()
+                    .map(|c| format!("character.{:?}", c)),
+            )
+            .chain(
+                Booster::all()
+                    .into_iter()
+                    .map(|b| format!("booster.{:?}", b)),
+            )
+            .chain(
+                Difficulty::all()
+                    .into_iter()
+                    .map(|d| format!("difficulty.{:?}", d)),
+            )
+            .chain(
+                Language::all()
+                    .into_iter()
+                    .map(|l| format!("language.{}", l)),
+            );
+
+        for key in generated_keys {
+            assert!(english.contains(&key[..]), "en is missing {}", key);
+        }
+        for language in Language::all() {
+            let catalog_keys = keys(language);
+            for key in &english {
+                assert!(
+                    catalog_keys.contains(key),
+                    "{} is missing {}",
+                    language,
+                    key
+                );
+            }
+            for key in &catalog_keys {
+                assert!(
+                    english.contains(key),
+                    "{} has unknown key {}",
+                    language,
+                    key
+                );
+            }
+        }
+        assert_eq!("Ninja Sword", messages.move_name(Move::NinjaSword));
+    }
+
+    #[test]
+    fn languages_are_detected_from_tags() {
+        assert_eq!(Some(Language::Spanish), Language::from_tag("es-MX"));
+        assert_eq!(Some(Language::Chinese), Language::from_tag("zh_Hans"));
+        assert_eq!(None, Language::from_tag("fr"));
+    }
+}
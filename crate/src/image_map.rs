@@ -1,5 +1,4 @@
 use crate::helpers;
-use crate::opponent::Difficulty;
 use crate::paint::ImageType;
 
 use nzscq::choices::{Booster, Character, Move};
@@ -66,23 +65,6 @@ impl ImageMapBuilder {
         self.insert(ImageType::SettingsButton, "SettingsButton")?;
         self.insert(ImageType::Star, "Star")?;
         self.insert(ImageType::EmptyStar, "EmptyStar")?;
-        self.insert(
-            ImageType::ComputerDifficulty(Difficulty::Stupid),
-            "ComputerDifficultyStupid",
-        )?;
-        self.insert(
-            ImageType::ComputerDifficulty(Difficulty::Easy),
-            "ComputerDifficultyEasy",
-        )?;
-        self.insert(
-            ImageType::ComputerDifficulty(Difficulty::Medium),
-            "ComputerDifficultyMedium",
-        )?;
-        self.insert(ImageType::TutorialButton, "TutorialButton")?;
-        self.insert(ImageType::PassAndPlayButton, "PassAndPlayButton")?;
-        self.insert(ImageType::CustomSeedButton, "CustomSeedButton")?;
-        self.insert(ImageType::InspectMoveButton, "InspectMoveButton")?;
-        self.insert(ImageType::StopInspectingButton, "StopInspectingButton")?;
 
         Ok(ImageMap(self.map))
     }
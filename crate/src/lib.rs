@@ -1,21 +1,45 @@
 #![warn(clippy::all)]
 
+mod accessibility;
+mod analysis;
 mod app;
 mod canvas_dimensions;
+mod challenge;
 mod click;
 mod colors;
 mod context;
+mod description;
+mod encyclopedia;
+mod focus;
+mod forecast;
+mod game_summary;
 mod helpers;
+mod hint;
+mod history;
+mod hover;
+mod i18n;
 mod image_map;
 mod letterbox;
+mod matchup_matrix;
+mod notation;
 mod opponent;
 mod paint;
 mod phase;
 mod render;
+mod replay;
+mod round_log;
+mod sandbox;
+mod seed_display;
+mod settings;
 mod shapes;
 mod side;
 mod state;
+mod stats;
+mod text;
+mod theme;
+mod touch;
 mod transform;
+mod viewport;
 mod xorshift;
 
 use app::App;
@@ -0,0 +1,136 @@
+use crate::encyclopedia::{self, MoveSource};
+use crate::helpers;
+use crate::shapes::Rect;
+use crate::viewport::{Viewport, ZOOM_STEP};
+
+use nzscq::choices::{Move, PointsAgainst};
+
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct MatrixView {
+    pub row_filter: Option<MoveSource>,
+    pub column_filter: Option<MoveSource>,
+    pub selected_cell: Option<(Move, Move)>,
+    pub viewport: Viewport,
+}
+
+impl Default for MatrixView {
+    fn default() -> MatrixView {
+        MatrixView {
+            row_filter: None,
+            column_filter: None,
+            selected_cell: None,
+            viewport: Viewport::new(GRID_FRAME),
+        }
+    }
+}
+
+impl MatrixView {
+    pub fn rows(&self) -> Vec<Move> {
+        filtered_moves(self.row_filter)
+    }
+
+    pub fn columns(&self) -> Vec<Move> {
+        filtered_moves(self.column_filter)
+    }
+
+    pub fn cell_size(&self) -> f64 {
+        let columns = self.columns().len().max(1) as f64;
+        let rows = self.rows().len().max(1) as f64;
+
+        (GRID_FRAME.width / columns).min(GRID_FRAME.height / rows)
+    }
+
+    pub fn content_size(&self) -> (f64, f64) {
+        let cell_size = self.cell_size();
+
+        (
+            cell_size * self.columns().len() as f64,
+            cell_size * self.rows().len() as f64,
+        )
+    }
+
+    pub fn set_row_filter(&mut self, filter: Option<MoveSource>) {
+        self.row_filter = filter;
+        self.viewport.reset();
+    }
+
+    pub fn set_column_filter(&mut self, filter: Option<MoveSource>) {
+        self.column_filter = filter;
+        self.viewport.reset();
+    }
+
+    pub fn pan_by(&mut self, delta: (f64, f64)) {
+        let content_size = self.content_size();
+        self.viewport.pan_by(delta, content_size);
+    }
+
+    pub fn zoom_at(&mut self, canvas_coords: (f64, f64), factor: f64) {
+        let content_size = self.content_size();
+        self.viewport.zoom_at(canvas_coords, factor, content_size);
+    }
+
+    pub fn zoom_in(&mut self) {
+        let content_size = self.content_size();
+        self.viewport.zoom_at_center(ZOOM_STEP, content_size);
+    }
+
+    pub fn zoom_out(&mut self) {
+        let content_size = self.content_size();
+        self.viewport.zoom_at_center(1.0 / ZOOM_STEP, content_size);
+    }
+}
+
+pub fn cell_points(row: Move, column: Move) -> [u8; 2] {
+    helpers::vec2_to_arr2(Move::points_of(&[row, column]))
+}
+
+fn filtered_moves(filter: Option<MoveSource>) -> Vec<Move> {
+    match filter {
+        Some(source) => encyclopedia::move_groups()
+            .into_iter()
+            .find(|group| group.source == source)
+            .map(|group| group.moves)
+            .unwrap_or_default(),
+        None => encyclopedia::move_groups()
+            .into_iter()
+            .flat_map(|group| group.moves)
+            .collect(),
+    }
+}
+
+pub const GRID_FRAME: Rect = Rect {
+    x: 120.0,
+    y: 260.0,
+    width: 1080.0,
+    height: 710.0,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nzscq::choices::Character;
+
+    #[test]
+    fn filters_restrict_rows_and_columns_to_a_group() {
+        let mut view = MatrixView::default();
+        view.zoom_in();
+        view.set_row_filter(Some(MoveSource::Character(Character::Ninja)));
+
+        assert_eq!(
+            vec![Move::Kick, Move::NinjaSword, Move::Nunchucks],
+            view.rows()
+        );
+        assert_eq!(Move::all().len(), view.columns().len());
+        assert_eq!(1.0, view.viewport.zoom);
+        assert_eq!([0, 0], cell_points(Move::Kick, Move::Kick));
+    }
+
+    #[test]
+    fn unfiltered_grid_fills_the_frame() {
+        let view = MatrixView::default();
+        let (width, height) = view.content_size();
+
+        assert!(width <= GRID_FRAME.width);
+        assert!((height - GRID_FRAME.height).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,542 @@
+use crate::opponent::Difficulty;
+
+use nzscq::choices::{
+    Action, ArsenalItem, BatchChoice, BatchChoices, Booster, Character, DequeueChoice, Move,
+};
+use nzscq::game::BatchChoiceGame;
+
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GameRecord {
+    pub seed: String,
+    pub difficulty: Difficulty,
+    pub choices: Vec<BatchChoice>,
+}
+
+impl GameRecord {
+    pub fn new(seed: &str, difficulty: Difficulty) -> GameRecord {
+        GameRecord {
+            seed: seed.to_string(),
+            difficulty,
+            choices: vec![],
+        }
+    }
+}
+
+impl Display for GameRecord {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        writeln!(f, "{}={}", SEED_HEADER, self.seed)?;
+        writeln!(
+            f,
+            "{}={}",
+            DIFFICULTY_HEADER,
+            self.difficulty.to_string().to_lowercase()
+        )?;
+
+        for choice in &self.choices {
+            writeln!(f, "{}", choice_notation(choice))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for GameRecord {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<GameRecord, ParseError> {
+        let mut seed: Option<String> = None;
+        let mut difficulty: Option<Difficulty> = None;
+        let mut game = BatchChoiceGame::default();
+        let mut choices = vec![];
+
+        for (index, line) in s.lines().enumerate() {
+            let line_number = index + 1;
+            let error = |kind| ParseError {
+                line: line_number,
+                kind,
+            };
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with(COMMENT_PREFIX) {
+                continue;
+            }
+
+            if let Some(value) = header_value(line, SEED_HEADER) {
+                if seed.is_some() || !choices.is_empty() {
+                    return Err(error(ParseErrorKind::UnexpectedHeader(SEED_HEADER)));
+                }
+                seed = Some(value.to_string());
+            } else if let Some(value) = header_value(line, DIFFICULTY_HEADER) {
+                if difficulty.is_some() || !choices.is_empty() {
+                    return Err(error(ParseErrorKind::UnexpectedHeader(DIFFICULTY_HEADER)));
+                }
+                difficulty =
+                    Some(Difficulty::try_from(value).map_err(|_| {
+                        error(ParseErrorKind::UnknownDifficulty(value.to_string()))
+                    })?);
+            } else {
+                if seed.is_none() {
+                    return Err(error(ParseErrorKind::MissingHeader(SEED_HEADER)));
+                }
+                if difficulty.is_none() {
+                    return Err(error(ParseErrorKind::MissingHeader(DIFFICULTY_HEADER)));
+                }
+
+                let choice = parse_choice(line, game.choices()).map_err(error)?;
+                game.choose(choice.clone())
+                    .map_err(|_| error(ParseErrorKind::IllegalChoices))?;
+                choices.push(choice);
+            }
+        }
+
+        let end_of_input = s.lines().count() + 1;
+        let seed = seed.ok_or(ParseError {
+            line: end_of_input,
+            kind: ParseErrorKind::MissingHeader(SEED_HEADER),
+        })?;
+        let difficulty = difficulty.ok_or(ParseError {
+            line: end_of_input,
+            kind: ParseErrorKind::MissingHeader(DIFFICULTY_HEADER),
+        })?;
+
+        Ok(GameRecord {
+            seed,
+            difficulty,
+            choices,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    MissingHeader(&'static str),
+    UnexpectedHeader(&'static str),
+    UnknownDifficulty(String),
+    UnknownToken(String),
+    WrongChoiceCount { expected: usize, found: usize },
+    ExpectedParentheses,
+    UnexpectedParentheses,
+    IllegalChoice { player: usize, token: String },
+    IllegalChoices,
+    GameAlreadyOver,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.kind)
+    }
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingHeader(header) => {
+                write!(f, "expected a `{}=` header before any choices", header)
+            }
+            ParseErrorKind::UnexpectedHeader(header) => write!(
+                f,
+                "`{}=` header must appear once, before any choices",
+                header
+            ),
+            ParseErrorKind::UnknownDifficulty(difficulty) => {
+                write!(f, "unknown difficulty `{}`", difficulty)
+            }
+            ParseErrorKind::UnknownToken(token) => write!(f, "unknown token `{}`", token),
+            ParseErrorKind::WrongChoiceCount { expected, found } => {
+                write!(f, "expected {} choices, found {}", expected, found)
+            }
+            ParseErrorKind::ExpectedParentheses => {
+                write!(f, "dequeue choices must be wrapped in parentheses")
+            }
+            ParseErrorKind::UnexpectedParentheses => {
+                write!(f, "only dequeue choices may be wrapped in parentheses")
+            }
+            ParseErrorKind::IllegalChoice { player, token } => write!(
+                f,
+                "`{}` is not a legal choice for the {}",
+                token,
+                player_name(*player)
+            ),
+            ParseErrorKind::IllegalChoices => write!(f, "these choices are not legal together"),
+            ParseErrorKind::GameAlreadyOver => write!(f, "the game is already over"),
+        }
+    }
+}
+
+fn player_name(player: usize) -> &'static str {
+    match player {
+        0 => "human",
+        1 => "computer",
+        _ => "unknown player",
+    }
+}
+
+fn header_value<'a>(line: &'a str, header: &str) -> Option<&'a str> {
+    let (key, value) = line.split_once('=')?;
+
+    if key.trim() == header {
+        Some(value.trim())
+    } else {
+        None
+    }
+}
+
+fn parse_choice(line: &str, available: BatchChoices) -> Result<BatchChoice, ParseErrorKind> {
+    let is_parenthesized = line.starts_with('(') && line.ends_with(')');
+    let is_dequeue_phase = matches!(available, BatchChoices::DequeueChoices(_));
+
+    if is_dequeue_phase && !is_parenthesized {
+        return Err(ParseErrorKind::ExpectedParentheses);
+    }
+    if !is_dequeue_phase && (line.starts_with('(') || line.ends_with(')')) {
+        return Err(ParseErrorKind::UnexpectedParentheses);
+    }
+
+    let body = if is_parenthesized {
+        &line[1..line.len() - 1]
+    } else {
+        line
+    };
+    let tokens: Vec<&str> = body.split_whitespace().collect();
+
+    match available {
+        BatchChoices::Characters(available) => {
+            parse_tokens(&tokens, &available, character_from_token).map(BatchChoice::Characters)
+        }
+        BatchChoices::Boosters(available) => {
+            parse_tokens(&tokens, &available, booster_from_token).map(BatchChoice::Boosters)
+        }
+        BatchChoices::DequeueChoices(available) => {
+            parse_tokens(&tokens, &available, dequeue_choice_from_token)
+                .map(BatchChoice::DequeueChoices)
+        }
+        BatchChoices::Actions(available) => {
+            parse_tokens(&tokens, &available, action_from_token).map(BatchChoice::Actions)
+        }
+        BatchChoices::None => Err(ParseErrorKind::GameAlreadyOver),
+    }
+}
+
+fn parse_tokens<T, F>(
+    tokens: &[&str],
+    available: &[Vec<T>],
+    from_token: F,
+) -> Result<Vec<T>, ParseErrorKind>
+where
+    T: PartialEq,
+    F: Fn(&str) -> Option<T>,
+{
+    if tokens.len() != available.len() {
+        return Err(ParseErrorKind::WrongChoiceCount {
+            expected: available.len(),
+            found: tokens.len(),
+        });
+    }
+
+    tokens
+        .iter()
+        .zip(available)
+        .enumerate()
+        .map(|(player, (&token, player_choices))| {
+            let choice =
+                from_token(token).ok_or_else(|| ParseErrorKind::UnknownToken(token.to_string()))?;
+
+            if player_choices.contains(&choice) {
+                Ok(choice)
+            } else {
+                Err(ParseErrorKind::IllegalChoice {
+                    player,
+                    token: token.to_string(),
+                })
+            }
+        })
+        .collect()
+}
+
+fn choice_notation(choice: &BatchChoice) -> String {
+    match choice {
+        BatchChoice::Characters(characters) => join(characters, |&c| character_token(c)),
+        BatchChoice::Boosters(boosters) => join(boosters, |&b| booster_token(b)),
+        BatchChoice::DequeueChoices(dequeues) => {
+            format!("({})", join(dequeues, |&d| dequeue_choice_token(d)))
+        }
+        BatchChoice::Actions(actions) => join(actions, |&a| action_token(a)),
+    }
+}
+
+fn join<T, F>(items: &[T], to_token: F) -> String
+where
+    F: Fn(&T) -> String,
+{
+    items
+        .iter()
+        .map(to_token)
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+pub fn move_abbreviation(m: Move) -> &'static str {
+    MOVE_ABBREVIATIONS
+        .iter()
+        .find(|(abbreviated, _)| *abbreviated == m)
+        .map(|(_, abbreviation)| *abbreviation)
+        .expect("every move should have an abbreviation")
+}
+
+pub fn move_from_abbreviation(abbreviation: &str) -> Option<Move> {
+    MOVE_ABBREVIATIONS
+        .iter()
+        .find(|(_, candidate)| *candidate == abbreviation)
+        .map(|(m, _)| *m)
+}
+
+fn character_token(c: Character) -> String {
+    c.to_string().to_lowercase()
+}
+
+fn character_from_token(token: &str) -> Option<Character> {
+    Character::all()
+        .into_iter()
+        .find(|&c| character_token(c) == token)
+}
+
+fn booster_token(b: Booster) -> String {
+    if b == Booster::None {
+        NO_BOOSTER.to_string()
+    } else {
+        b.to_string().to_lowercase().replace(' ', "")
+    }
+}
+
+fn booster_from_token(token: &str) -> Option<Booster> {
+    Booster::all()
+        .into_iter()
+        .find(|&b| booster_token(b) == token)
+}
+
+fn arsenal_item_token(item: ArsenalItem) -> String {
+    match item {
+        ArsenalItem::Move(m) => move_abbreviation(m).to_string(),
+        ArsenalItem::Mirror => MIRROR.to_string(),
+    }
+}
+
+fn dequeue_choice_token(choice: DequeueChoice) -> String {
+    match choice {
+        DequeueChoice::DrainAndExit(item) => arsenal_item_token(item),
+        DequeueChoice::JustExit => JUST_EXIT.to_string(),
+        DequeueChoice::Decline => DECLINE.to_string(),
+    }
+}
+
+fn dequeue_choice_from_token(token: &str) -> Option<DequeueChoice> {
+    match token {
+        JUST_EXIT => Some(DequeueChoice::JustExit),
+        DECLINE => Some(DequeueChoice::Decline),
+        MIRROR => Some(DequeueChoice::DrainAndExit(ArsenalItem::Mirror)),
+        _ => {
+            move_from_abbreviation(token).map(|m| DequeueChoice::DrainAndExit(ArsenalItem::Move(m)))
+        }
+    }
+}
+
+fn action_token(action: Action) -> String {
+    match action {
+        Action::Move(m) => move_abbreviation(m).to_string(),
+        Action::Mirror(m) => format!("{}{}{}", MIRROR, MIRROR_SEPARATOR, move_abbreviation(m)),
+        Action::Concede => CONCEDE.to_string(),
+    }
+}
+
+fn action_from_token(token: &str) -> Option<Action> {
+    if token == CONCEDE {
+        Some(Action::Concede)
+    } else if let Some(mirrored) = token.strip_prefix(MIRROR_PREFIX) {
+        move_from_abbreviation(mirrored).map(Action::Mirror)
+    } else {
+        move_from_abbreviation(token).map(Action::Move)
+    }
+}
+
+const SEED_HEADER: &str = "seed";
+const DIFFICULTY_HEADER: &str = "difficulty";
+const COMMENT_PREFIX: &str = "//";
+const NO_BOOSTER: &str = "none";
+const MIRROR: &str = "mirror";
+const MIRROR_SEPARATOR: &str = "::";
+const MIRROR_PREFIX: &str = "mirror::";
+const JUST_EXIT: &str = "exit";
+const DECLINE: &str = "decline";
+const CONCEDE: &str = "concede";
+
+const MOVE_ABBREVIATIONS: [(Move, &str); 29] = [
+    (Move::Kick, "kick"),
+    (Move::NinjaSword, "swo"),
+    (Move::Nunchucks, "nun"),
+    (Move::ShadowFireball, "fire"),
+    (Move::ShadowSlip, "slip"),
+    (Move::RunInCircles, "run"),
+    (Move::LightningFastKarateChop, "chop"),
+    (Move::Rampage, "ram"),
+    (Move::Muscle, "mus"),
+    (Move::Zap, "zap"),
+    (Move::Regenerate, "regen"),
+    (Move::Gravedigger, "grave"),
+    (Move::ZombieCorps, "corps"),
+    (Move::Apocalypse, "apoc"),
+    (Move::SamuraiSword, "sam"),
+    (Move::Helmet, "helm"),
+    (Move::Smash, "smash"),
+    (Move::StrongSmash, "ssmash"),
+    (Move::Lightning, "light"),
+    (Move::Earthquake, "quake"),
+    (Move::Twist, "twist"),
+    (Move::Bend, "bend"),
+    (Move::JugglingKnives, "jug"),
+    (Move::AcidSpray, "acid"),
+    (Move::Nose, "nose"),
+    (Move::BackwardsMoustachio, "bmus"),
+    (Move::NoseOfTheTaunted, "not"),
+    (Move::MustacheMash, "mash"),
+    (Move::BigHairyDeal, "bhd"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opponent::Opponent;
+    use crate::xorshift::Xorshift128Plus;
+
+    fn played_record(seed: &str, difficulty: Difficulty) -> GameRecord {
+        let mut game = BatchChoiceGame::default();
+        let mut computer = Opponent::new(difficulty, Box::new(Xorshift128Plus::from(seed)));
+        let mut record = GameRecord::new(seed, difficulty);
+
+        for turn in 0..MAX_TURNS {
+            let choice = match game.choices() {
+                BatchChoices::Characters(available) => BatchChoice::Characters(vec![
+                    pick(&available[0], turn),
+                    computer.choose_character(&game).unwrap(),
+                ]),
+                BatchChoices::Boosters(available) => BatchChoice::Boosters(vec![
+                    pick(&available[0], turn),
+                    computer.choose_booster(&game).unwrap(),
+                ]),
+                BatchChoices::DequeueChoices(available) => BatchChoice::DequeueChoices(vec![
+                    pick(&available[0], turn),
+                    computer.choose_dequeue(&game).unwrap(),
+                ]),
+                BatchChoices::Actions(available) => BatchChoice::Actions(vec![
+                    pick(&available[0], turn),
+                    computer.choose_action(&game).unwrap(),
+                ]),
+                BatchChoices::None => return record,
+            };
+            game.choose(choice.clone()).unwrap();
+            record.choices.push(choice);
+        }
+
+        panic!("game should finish within {} turns", MAX_TURNS)
+    }
+
+    fn pick<T: Copy>(options: &[T], turn: usize) -> T {
+        options[turn % options.len()]
+    }
+
+    const MAX_TURNS: usize = 1000;
+
+    #[test]
+    fn every_move_has_a_unique_abbreviation() {
+        for m in Move::all() {
+            assert_eq!(Some(m), move_from_abbreviation(move_abbreviation(m)));
+        }
+    }
+
+    #[test]
+    fn finished_games_round_trip() {
+        for &seed in &["da", "foo", "nzscq"] {
+            let record = played_record(seed, Difficulty::Medium);
+            let parsed: GameRecord = record.to_string().parse().unwrap();
+
+            assert_eq!(record, parsed);
+
+            let mut game = BatchChoiceGame::default();
+            for choice in parsed.choices {
+                game.choose(choice).unwrap();
+            }
+            assert!(game.winner_index().is_some());
+        }
+    }
+
+    #[test]
+    fn headers_ignore_whitespace_around_equals_signs() {
+        let record = played_record("abc", Difficulty::Easy);
+        let spaced = record.to_string().replacen("seed=", "seed = ", 1).replacen(
+            "difficulty=",
+            "difficulty = ",
+            1,
+        );
+        let parsed: GameRecord = spaced.parse().unwrap();
+
+        assert_eq!(record, parsed);
+        assert_eq!(record.to_string(), parsed.to_string());
+    }
+
+    #[test]
+    fn comments_and_blank_lines_are_ignored() {
+        let text = "seed=da\ndifficulty=easy\n\n// pick characters\nninja clown\n";
+        let record: GameRecord = text.parse().unwrap();
+
+        assert_eq!(
+            vec![BatchChoice::Characters(vec![
+                Character::Ninja,
+                Character::Clown
+            ])],
+            record.choices
+        );
+    }
+
+    #[test]
+    fn illegal_choices_report_their_line() {
+        let text = "seed=da\ndifficulty=easy\nninja clown\nshadow atlas\n";
+        let error = text.parse::<GameRecord>().unwrap_err();
+
+        assert_eq!(
+            ParseError {
+                line: 4,
+                kind: ParseErrorKind::IllegalChoice {
+                    player: 1,
+                    token: "atlas".to_string(),
+                },
+            },
+            error
+        );
+    }
+
+    #[test]
+    fn dequeue_choices_require_parentheses() {
+        let text = "seed=da\ndifficulty=easy\nninja clown\nshadow backwards\ndecline decline\n";
+        let error = text.parse::<GameRecord>().unwrap_err();
+
+        assert_eq!(5, error.line);
+        assert_eq!(ParseErrorKind::ExpectedParentheses, error.kind);
+    }
+
+    #[test]
+    fn choices_require_headers() {
+        let error = "seed=da\nninja clown\n".parse::<GameRecord>().unwrap_err();
+
+        assert_eq!(2, error.line);
+        assert_eq!(ParseErrorKind::MissingHeader(DIFFICULTY_HEADER), error.kind);
+    }
+}
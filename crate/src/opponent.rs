@@ -3,8 +3,6 @@ use nzscq::{
     game::BatchChoiceGame,
 };
 
-use murmur3::murmur3_32::MurmurHasher;
-
 use std::convert::TryFrom;
 use std::fmt::{self, Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
@@ -17,11 +15,8 @@ pub struct Opponent {
 
 impl Hash for Opponent {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        let mut hasher: MurmurHasher = Default::default();
-        self.prng.murmur_hash(&mut hasher);
-
         self.difficulty.hash(state);
-        state.write_u64(hasher.finish());
+        self.prng.snapshot().hash(state);
     }
 }
 
@@ -32,6 +27,14 @@ impl Opponent {
         Opponent { difficulty, prng }
     }
 
+    pub fn snapshot(&self) -> Option<PrngState> {
+        self.prng.snapshot()
+    }
+
+    pub fn restore(&mut self, snapshot: PrngState) -> Result<(), ()> {
+        self.prng.restore(snapshot)
+    }
+
     pub fn choose_character(&mut self, game: &BatchChoiceGame) -> Option<Character> {
         if let BatchChoices::Characters(mut choices) = game.choices() {
             let computer_choices = choices.remove(Opponent::COMPUTER);
@@ -64,7 +67,7 @@ impl Opponent {
             let computer_choices = match self.difficulty {
                 Difficulty::Stupid => computer_choices,
                 // TODO Make medium drain more cautiously
-                _ => prefer_drain_and_exit(computer_choices),
+                _ => preferred_dequeues(computer_choices).0,
             };
 
             Some(self.rand_choice(computer_choices))
@@ -83,8 +86,8 @@ impl Opponent {
                     None
                 }
             }
-            Difficulty::Medium => actions_that_make_sense_for_medium_difficulty_computer(game)
-                .map(|actions| self.rand_choice(actions)),
+            Difficulty::Medium => preferred_actions(game, Opponent::COMPUTER)
+                .map(|(actions, _)| self.rand_choice(actions)),
         }
     }
 
@@ -96,14 +99,31 @@ impl Opponent {
     }
 }
 
-fn prefer_drain_and_exit(choices: Vec<DequeueChoice>) -> Vec<DequeueChoice> {
-    if choices.iter().any(is_drain_and_exit) {
-        choices.into_iter().filter(is_drain_and_exit).collect()
-    } else if choices.iter().any(is_just_exit) {
-        choices.into_iter().filter(is_just_exit).collect()
-    } else {
-        choices
-    }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DequeuePreference {
+    DrainAndExit,
+    JustExit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActionPreference {
+    GuaranteesWin,
+    GuaranteesPoint,
+    GuaranteesOpponentWontScore,
+    WinPossible,
+    NonLossPossible,
+}
+
+pub fn preferred_dequeues(
+    choices: Vec<DequeueChoice>,
+) -> (Vec<DequeueChoice>, Option<DequeuePreference>) {
+    prefer(
+        choices,
+        vec![
+            (DequeuePreference::DrainAndExit, Box::new(is_drain_and_exit)),
+            (DequeuePreference::JustExit, Box::new(is_just_exit)),
+        ],
+    )
 }
 
 #[allow(clippy::trivially_copy_pass_by_ref)]
@@ -120,61 +140,66 @@ fn is_just_exit(&choice: &DequeueChoice) -> bool {
     choice == DequeueChoice::JustExit
 }
 
-fn actions_that_make_sense_for_medium_difficulty_computer(
+pub fn preferred_actions(
     game: &BatchChoiceGame,
-) -> Option<Vec<Action>> {
+    side: usize,
+) -> Option<(Vec<Action>, Option<ActionPreference>)> {
     if let BatchChoices::Actions(mut choices) = game.choices() {
-        let human_choices = choices.remove(0);
-        let computer_choices = choices.remove(0);
+        let own_choices = choices.remove(side);
+        let opponent_choices = choices.remove(0);
         let mut scoreboard = game
             .scoreboard()
             .actionless()
             .expect("should be on action-choosing phase");
-        let human = scoreboard.remove(0);
+        let opponent = scoreboard.remove(1 - side);
         let guarantees_win: Box<dyn Fn(&Action) -> bool> = Box::new(|&action| {
-            human_choices
-                .iter()
-                .all(|&human_action| PointsAgainst::points_of(&[action, human_action]) == [1, 0])
+            opponent_choices.iter().all(|&opponent_action| {
+                PointsAgainst::points_of(&[action, opponent_action]) == [1, 0]
+            })
         });
         let guarantees_point: Box<dyn Fn(&Action) -> bool> = Box::new(|&action| {
-            human_choices
-                .iter()
-                .all(|&human_action| PointsAgainst::points_of(&[action, human_action])[0] == 1)
-        });
-        let guarantees_human_wont_get_point: Box<dyn Fn(&Action) -> bool> = Box::new(|&action| {
-            human_choices
-                .iter()
-                .all(|&human_action| PointsAgainst::points_of(&[action, human_action])[1] == 0)
+            opponent_choices.iter().all(|&opponent_action| {
+                PointsAgainst::points_of(&[action, opponent_action])[0] == 1
+            })
         });
+        let guarantees_opponent_wont_get_point: Box<dyn Fn(&Action) -> bool> =
+            Box::new(|&action| {
+                opponent_choices.iter().all(|&opponent_action| {
+                    PointsAgainst::points_of(&[action, opponent_action])[1] == 0
+                })
+            });
         let win_possible: Box<dyn Fn(&Action) -> bool> = Box::new(|&action| {
-            human_choices
-                .iter()
-                .any(|&human_action| PointsAgainst::points_of(&[action, human_action]) == [1, 0])
+            opponent_choices.iter().any(|&opponent_action| {
+                PointsAgainst::points_of(&[action, opponent_action]) == [1, 0]
+            })
         });
         let non_loss_possible: Box<dyn Fn(&Action) -> bool> = Box::new(|&action| {
-            human_choices
-                .iter()
-                .any(|&human_action| PointsAgainst::points_of(&[action, human_action]) != [0, 1])
+            opponent_choices.iter().any(|&opponent_action| {
+                PointsAgainst::points_of(&[action, opponent_action]) != [0, 1]
+            })
         });
 
-        if human.points == 4 {
+        if opponent.points == 4 {
             Some(prefer(
-                computer_choices,
+                own_choices,
                 vec![
-                    guarantees_win,
-                    guarantees_human_wont_get_point,
-                    win_possible,
-                    non_loss_possible,
+                    (ActionPreference::GuaranteesWin, guarantees_win),
+                    (
+                        ActionPreference::GuaranteesOpponentWontScore,
+                        guarantees_opponent_wont_get_point,
+                    ),
+                    (ActionPreference::WinPossible, win_possible),
+                    (ActionPreference::NonLossPossible, non_loss_possible),
                 ],
             ))
         } else {
             Some(prefer(
-                computer_choices,
+                own_choices,
                 vec![
-                    guarantees_win,
-                    guarantees_point,
-                    win_possible,
-                    non_loss_possible,
+                    (ActionPreference::GuaranteesWin, guarantees_win),
+                    (ActionPreference::GuaranteesPoint, guarantees_point),
+                    (ActionPreference::WinPossible, win_possible),
+                    (ActionPreference::NonLossPossible, non_loss_possible),
                 ],
             ))
         }
@@ -183,32 +208,31 @@ fn actions_that_make_sense_for_medium_difficulty_computer(
     }
 }
 
-fn prefer<'a, T>(choices: Vec<T>, predicates: Vec<Box<dyn (Fn(&T) -> bool) + 'a>>) -> Vec<T>
+type Predicate<'a, T> = Box<dyn (Fn(&T) -> bool) + 'a>;
+
+fn prefer<'a, T, P>(choices: Vec<T>, predicates: Vec<(P, Predicate<'a, T>)>) -> (Vec<T>, Option<P>)
 where
     T: Clone,
 {
-    for p in predicates {
+    for (preference, p) in predicates {
         let satisfactory_choices: Vec<T> = choices.iter().cloned().filter(|i| p(i)).collect();
         if !satisfactory_choices.is_empty() {
-            return satisfactory_choices;
+            return (satisfactory_choices, Some(preference));
         }
     }
 
-    choices
+    (choices, None)
 }
 
-pub trait Random: Debug + MurmurHash {
+pub type PrngState = (u64, u64);
+
+pub trait Random: Debug {
     fn random(&mut self) -> f64;
-}
 
-pub trait MurmurHash {
-    fn murmur_hash(&self, state: &mut MurmurHasher);
-}
+    /// Generators whose state is hidden from us, like `Math.random`, return `None`.
+    fn snapshot(&self) -> Option<PrngState>;
 
-impl<T: Hash> MurmurHash for T {
-    fn murmur_hash(&self, state: &mut MurmurHasher) {
-        self.hash(state);
-    }
+    fn restore(&mut self, snapshot: PrngState) -> Result<(), ()>;
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -218,6 +242,12 @@ pub enum Difficulty {
     Medium = 2,
 }
 
+impl Difficulty {
+    pub fn all() -> Vec<Difficulty> {
+        vec![Difficulty::Stupid, Difficulty::Easy, Difficulty::Medium]
+    }
+}
+
 impl TryFrom<u8> for Difficulty {
     type Error = ();
 
@@ -261,3 +291,47 @@ impl Display for Difficulty {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::xorshift::Xorshift128Plus;
+    use nzscq::choices::BatchChoice;
+
+    #[test]
+    fn restored_opponents_make_the_same_choices() {
+        let mut game = BatchChoiceGame::default();
+        let mut computer = Opponent::new(
+            Difficulty::Medium,
+            Box::new(Xorshift128Plus::from("mid-game")),
+        );
+        let character = computer.choose_character(&game).unwrap();
+        game.choose(BatchChoice::Characters(vec![Character::Ninja, character]))
+            .unwrap();
+        let booster = computer.choose_booster(&game).unwrap();
+        game.choose(BatchChoice::Boosters(vec![Booster::Shadow, booster]))
+            .unwrap();
+
+        let snapshot = computer.snapshot().expect("should have snapshot");
+        let mut restored = Opponent::new(
+            Difficulty::Medium,
+            Box::new(Xorshift128Plus::from("another seed")),
+        );
+        restored.restore(snapshot).unwrap();
+
+        for _ in 0..10 {
+            assert_eq!(
+                computer.choose_dequeue(&game),
+                restored.choose_dequeue(&game)
+            );
+        }
+        game.choose(BatchChoice::DequeueChoices(vec![
+            DequeueChoice::Decline,
+            DequeueChoice::Decline,
+        ]))
+        .unwrap();
+        for _ in 0..10 {
+            assert_eq!(computer.choose_action(&game), restored.choose_action(&game));
+        }
+    }
+}
@@ -2,8 +2,8 @@ use crate::{
     click::Action,
     colors::Rgba,
     image_map::ImageMap,
-    opponent::Difficulty,
     shapes::{Circle, Rect},
+    text::{Font, TextAlign},
     transform::{Scale, Translate},
 };
 
@@ -78,6 +78,15 @@ impl<'a> Painter<'a> {
                 fill_color,
                 stroke,
             } => self.paint_path(path, fill_color, stroke),
+            Component::Text {
+                text,
+                font,
+                align,
+                color,
+                anchor,
+                max_width,
+                ..
+            } => self.paint_text(&text[..], font, align, color, anchor, max_width),
         }
     }
 
@@ -176,6 +185,27 @@ impl<'a> Painter<'a> {
         Ok(())
     }
 
+    fn paint_text(
+        &mut self,
+        text: &str,
+        font: Font,
+        align: TextAlign,
+        color: Rgba,
+        (x, y): (f64, f64),
+        max_width: Option<f64>,
+    ) -> Result<(), JsValue> {
+        self.ctx.set_font(&font.to_css()[..]);
+        self.ctx.set_text_align(align.to_css());
+        self.ctx.set_text_baseline("middle");
+        self.ctx.set_fill_style_str(&color.to_upper_hash_hex()[..]);
+
+        if let Some(max_width) = max_width {
+            self.ctx.fill_text_with_max_width(text, x, y, max_width)
+        } else {
+            self.ctx.fill_text(text, x, y)
+        }
+    }
+
     fn image_src(&self, image_type: ImageType) -> &HtmlImageElement {
         self.image_map
             .get(image_type)
@@ -209,6 +239,16 @@ pub enum Component {
         fill_color: Option<Rgba>,
         stroke: Option<Stroke>,
     },
+    Text {
+        text: String,
+        font: Font,
+        align: TextAlign,
+        color: Rgba,
+        anchor: (f64, f64),
+        max_width: Option<f64>,
+        measured_width: Option<f64>,
+        on_click: Option<Action>,
+    },
 }
 
 impl Component {
@@ -219,6 +259,93 @@ impl Component {
             Component::Circle { on_click, .. } => on_click.clone(),
             Component::Image { on_click, .. } => on_click.clone(),
             Component::UnclickablePath { .. } => None,
+            Component::Text { on_click, .. } => on_click.clone(),
+        }
+    }
+
+    pub fn on_click_mut(&mut self) -> Option<&mut Option<Action>> {
+        match self {
+            Component::Background { .. } => None,
+            Component::Rect { on_click, .. } => Some(on_click),
+            Component::Circle { on_click, .. } => Some(on_click),
+            Component::Image { on_click, .. } => Some(on_click),
+            Component::UnclickablePath { .. } => None,
+            Component::Text { on_click, .. } => Some(on_click),
+        }
+    }
+
+    pub fn line(start: (f64, f64), end: (f64, f64), stroke: Stroke) -> Component {
+        Component::UnclickablePath {
+            path: Path {
+                start,
+                commands: vec![PathCommand::LineTo(end.0, end.1)],
+            },
+            fill_color: None,
+            stroke: Some(stroke),
+        }
+    }
+
+    pub fn faded(self, opacity: f64) -> Component {
+        let fade = |color: Rgba| color.with_alpha((f64::from(color.3) * opacity) as u8);
+
+        match self {
+            Component::Background { color } => Component::Background { color: fade(color) },
+            Component::Rect {
+                fill_color, shape, ..
+            } => Component::Rect {
+                fill_color: fade(fill_color),
+                shape,
+                on_click: None,
+            },
+            Component::Circle {
+                fill_color, shape, ..
+            } => Component::Circle {
+                fill_color: fade(fill_color),
+                shape,
+                on_click: None,
+            },
+            Component::Image {
+                image_type,
+                alpha,
+                shape,
+                ..
+            } => Component::Image {
+                image_type,
+                alpha: alpha * opacity,
+                shape,
+                on_click: None,
+            },
+            Component::UnclickablePath {
+                path,
+                fill_color,
+                stroke,
+            } => Component::UnclickablePath {
+                path,
+                fill_color: fill_color.map(fade),
+                stroke: stroke.map(|stroke| Stroke {
+                    color: fade(stroke.color),
+                    width: stroke.width,
+                }),
+            },
+            Component::Text {
+                text,
+                font,
+                align,
+                color,
+                anchor,
+                max_width,
+                measured_width,
+                ..
+            } => Component::Text {
+                text,
+                font,
+                align,
+                color: fade(color),
+                anchor,
+                max_width,
+                measured_width,
+                on_click: None,
+            },
         }
     }
 }
@@ -233,7 +360,7 @@ impl Translate for Component {
                 on_click,
             } => Component::Rect {
                 shape: shape.translate(dx, dy),
-                fill_color: fill_color.clone(),
+                fill_color: *fill_color,
                 on_click: on_click.clone(),
             },
             Component::Circle {
@@ -242,7 +369,7 @@ impl Translate for Component {
                 on_click,
             } => Component::Circle {
                 shape: shape.translate(dx, dy),
-                fill_color: fill_color.clone(),
+                fill_color: *fill_color,
                 on_click: on_click.clone(),
             },
             Component::Image {
@@ -262,9 +389,28 @@ impl Translate for Component {
                 stroke,
             } => Component::UnclickablePath {
                 path: path.translate(dx, dy),
-                fill_color: fill_color.clone(),
+                fill_color: *fill_color,
                 stroke: stroke.clone(),
             },
+            Component::Text {
+                text,
+                font,
+                align,
+                color,
+                anchor: (x, y),
+                max_width,
+                measured_width,
+                on_click,
+            } => Component::Text {
+                text: text.clone(),
+                font: *font,
+                align: *align,
+                color: *color,
+                anchor: (x + dx, y + dy),
+                max_width: *max_width,
+                measured_width: *measured_width,
+                on_click: on_click.clone(),
+            },
         }
     }
 }
@@ -279,7 +425,7 @@ impl Scale for Component {
                 on_click,
             } => Component::Rect {
                 shape: shape.scale(scale),
-                fill_color: fill_color.clone(),
+                fill_color: *fill_color,
                 on_click: on_click.clone(),
             },
             Component::Circle {
@@ -288,7 +434,7 @@ impl Scale for Component {
                 on_click,
             } => Component::Circle {
                 shape: shape.scale(scale),
-                fill_color: fill_color.clone(),
+                fill_color: *fill_color,
                 on_click: on_click.clone(),
             },
             Component::Image {
@@ -308,9 +454,31 @@ impl Scale for Component {
                 stroke,
             } => Component::UnclickablePath {
                 path: path.scale(scale),
-                fill_color: fill_color.clone(),
+                fill_color: *fill_color,
                 stroke: stroke.clone(),
             },
+            Component::Text {
+                text,
+                font,
+                align,
+                color,
+                anchor: (x, y),
+                max_width,
+                measured_width,
+                on_click,
+            } => Component::Text {
+                text: text.clone(),
+                font: Font {
+                    size: font.size * scale,
+                    ..*font
+                },
+                align: *align,
+                color: *color,
+                anchor: (x * scale, y * scale),
+                max_width: max_width.map(|max_width| max_width * scale),
+                measured_width: measured_width.map(|measured_width| measured_width * scale),
+                on_click: on_click.clone(),
+            },
         }
     }
 }
@@ -321,6 +489,24 @@ pub struct Path {
     pub commands: Vec<PathCommand>,
 }
 
+impl Path {
+    pub fn rounded_rect(rect: &Rect, corner_radius: f64) -> Path {
+        let (left, top) = (rect.x, rect.y);
+        let (right, bottom) = (rect.x + rect.width, rect.y + rect.height);
+        let radius = corner_radius.min(0.5 * rect.width.min(rect.height));
+
+        Path {
+            start: (left + radius, top),
+            commands: vec![
+                PathCommand::ArcTo(right, top, right, bottom, radius),
+                PathCommand::ArcTo(right, bottom, left, bottom, radius),
+                PathCommand::ArcTo(left, bottom, left, top, radius),
+                PathCommand::ArcTo(left, top, right, top, radius),
+            ],
+        }
+    }
+}
+
 impl Translate for Path {
     fn translate(&self, dx: f64, dy: f64) -> Path {
         Path {
@@ -399,12 +585,6 @@ pub enum ImageType {
     SettingsButton,
     Star,
     EmptyStar,
-    ComputerDifficulty(Difficulty),
-    TutorialButton,
-    PassAndPlayButton,
-    CustomSeedButton,
-    InspectMoveButton,
-    StopInspectingButton,
 }
 
 impl From<ArsenalItem> for ImageType {
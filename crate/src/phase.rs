@@ -1,16 +1,22 @@
+use crate::context::Context;
+use crate::forecast::{self, QueueForecast};
+use crate::helpers;
 use crate::paint::Component;
 use crate::render::{
     phase_renderers::{
         ActionChoosingPhaseRenderer, BoosterChoosingPhaseRenderer, CharacterChoosingPhaseRenderer,
         CharacterRechoosingPhaseRenderer, FirstDequeueingPhaseRenderer, GameOverPhaseRenderer,
-        SubsequentDequeueingPhaseRenderer,
+        SubsequentDequeueingPhaseRenderer, RESULTS_START,
     },
     Render,
 };
+use crate::settings::AnimationSpeed;
+use crate::side::Side;
 
 use nzscq::{
-    choices::{Action, Booster, Character, DequeueChoice, Move},
-    outcomes::{ActionPointsDestroyed, CharacterHeadstart},
+    choices::{Action, BatchChoice, Booster, Character, DequeueChoice, Move},
+    game::BatchChoiceGame,
+    outcomes::{ActionPointsDestroyed, CharacterHeadstart, Outcome},
     scoreboard::{ActionlessPlayer, DequeueingPlayer, FinishedPlayer},
 };
 
@@ -26,12 +32,171 @@ pub enum Phase {
 }
 
 impl Phase {
-    pub fn is_elapsed_time_past_completion(&self, elapsed_time: f64) -> bool {
-        elapsed_time > self.duration()
+    pub fn is_elapsed_time_past_completion(
+        &self,
+        elapsed_time: f64,
+        animation_speed: AnimationSpeed,
+    ) -> bool {
+        elapsed_time > self.duration(animation_speed)
     }
 
-    fn duration(&self) -> f64 {
+    pub fn initial(game: &BatchChoiceGame) -> Phase {
+        Phase::ChooseCharacter(ChooseCharacterPhase {
+            available_characters: game
+                .choices()
+                .characters()
+                .expect("should be able to choose character")
+                .remove(HUMAN),
+        })
+    }
+
+    pub fn next(&self, game: &mut BatchChoiceGame, choices: BatchChoice) -> Result<Phase, ()> {
+        let previous_choices = game.choices();
+        let outcome = game.choose(choices)?;
+
+        let phase = match outcome {
+            Outcome::CharacterPhaseDone(character_headstarts) => {
+                Phase::ChooseBooster(ChooseBoosterPhase {
+                    previously_available_characters: previous_choices
+                        .characters()
+                        .expect("should have been able to choose character")
+                        .remove(HUMAN),
+                    previous_outcome: character_headstarts,
+                    available_boosters: game
+                        .choices()
+                        .boosters()
+                        .expect("should be able to choose booster")
+                        .remove(HUMAN),
+                })
+            }
+
+            Outcome::CharacterPhaseRechoose(characters) => {
+                Phase::RechooseCharacter(RechooseCharacterPhase {
+                    previously_available_characters: previous_choices
+                        .characters()
+                        .expect("should have been able to choose character")
+                        .remove(HUMAN),
+                    previously_mutually_chosen_character: characters[0],
+                    available_characters: game
+                        .choices()
+                        .characters()
+                        .expect("should be able to choose character")
+                        .remove(HUMAN),
+                })
+            }
+
+            Outcome::BoosterPhaseDone(_) => Phase::ChooseFirstDequeue(ChooseFirstDequeuePhase {
+                previously_available_boosters: previous_choices
+                    .boosters()
+                    .expect("should have been able to choose booster")
+                    .remove(HUMAN),
+                scoreboard: helpers::vec2_to_arr2(
+                    game.scoreboard()
+                        .dequeueing()
+                        .expect("should be dequeueing"),
+                ),
+                available_dequeues: helpers::vec2_to_arr2(
+                    game.choices()
+                        .dequeue_choices()
+                        .expect("should be able to choose dequeue"),
+                ),
+                forecasts: forecast::queue_forecasts(game),
+                inspector_state: MoveInspectorState::NotInspecting,
+            }),
+
+            Outcome::DequeuePhaseDone(dequeues) => Phase::ChooseAction(ChooseActionPhase {
+                previous_scoreboard: self
+                    .dequeueing_scoreboard()
+                    .expect("should be on a dequeueing phase"),
+                previously_available_dequeues: helpers::vec2_to_arr2(
+                    previous_choices
+                        .dequeue_choices()
+                        .expect("should have been able to choose dequeue"),
+                ),
+                previous_outcome: helpers::vec2_to_arr2(dequeues),
+                scoreboard: helpers::vec2_to_arr2(
+                    game.scoreboard()
+                        .actionless()
+                        .expect("should be choosing actions"),
+                ),
+                available_actions: helpers::vec2_to_arr2(
+                    game.choices()
+                        .actions()
+                        .expect("should be able to choose action"),
+                ),
+                inspector_state: MoveInspectorState::NotInspecting,
+            }),
+
+            Outcome::ActionPhaseDone(action_points_destroyed) => {
+                Phase::ChooseSubsequentDequeue(ChooseSubsequentDequeuePhase {
+                    previous_scoreboard: self
+                        .actionless_scoreboard()
+                        .expect("should be on action-choosing phase"),
+                    previously_available_actions: helpers::vec2_to_arr2(
+                        previous_choices
+                            .actions()
+                            .expect("should have been able to choose action"),
+                    ),
+                    previous_outcome: helpers::vec2_to_arr2(action_points_destroyed),
+                    scoreboard: helpers::vec2_to_arr2(
+                        game.scoreboard()
+                            .dequeueing()
+                            .expect("should be dequeueing"),
+                    ),
+                    available_dequeues: helpers::vec2_to_arr2(
+                        game.choices()
+                            .dequeue_choices()
+                            .expect("should be able to choose dequeue"),
+                    ),
+                    forecasts: forecast::queue_forecasts(game),
+                    inspector_state: MoveInspectorState::NotInspecting,
+                })
+            }
+
+            Outcome::GameOver(action_points_destroyed) => Phase::GameOver(GameOverPhase {
+                previous_scoreboard: self
+                    .actionless_scoreboard()
+                    .expect("should be on action-choosing phase"),
+                previously_available_actions: helpers::vec2_to_arr2(
+                    previous_choices
+                        .actions()
+                        .expect("should have been able to choose action"),
+                ),
+                previous_outcome: helpers::vec2_to_arr2(action_points_destroyed),
+                scoreboard: helpers::vec2_to_arr2(
+                    game.scoreboard().final_().expect("game should be over"),
+                ),
+            }),
+        };
+
+        Ok(phase)
+    }
+
+    fn dequeueing_scoreboard(&self) -> Option<[DequeueingPlayer; 2]> {
         match self {
+            Phase::ChooseFirstDequeue(ChooseFirstDequeuePhase { scoreboard, .. }) => {
+                Some(scoreboard.clone())
+            }
+            Phase::ChooseSubsequentDequeue(ChooseSubsequentDequeuePhase { scoreboard, .. }) => {
+                Some(scoreboard.clone())
+            }
+            _ => None,
+        }
+    }
+
+    fn actionless_scoreboard(&self) -> Option<[ActionlessPlayer; 2]> {
+        match self {
+            Phase::ChooseAction(ChooseActionPhase { scoreboard, .. }) => Some(scoreboard.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn starts_round(&self) -> bool {
+        !matches!(self, Phase::ChooseAction(_))
+    }
+
+    pub fn duration(&self, animation_speed: AnimationSpeed) -> f64 {
+        let duration = match self {
             Phase::ChooseCharacter(_) => durations::CHOOSING_CHARACTERS,
             Phase::RechooseCharacter(_) => durations::RECHOOSING_CHARACTERS,
             Phase::ChooseBooster(_) => durations::CHOOSING_BOOSTERS,
@@ -39,7 +204,9 @@ impl Phase {
             Phase::ChooseAction(_) => durations::CHOOSING_ACTION,
             Phase::ChooseSubsequentDequeue(_) => durations::CHOOSING_SUBSEQUENT_DEQUEUE,
             Phase::GameOver(_) => durations::GAME_OVER,
-        }
+        };
+
+        duration / animation_speed.multiplier()
     }
 
     pub fn wait_for_user_to_choose_move_to_inspect(&mut self) -> Result<(), ()> {
@@ -110,44 +277,149 @@ impl Phase {
         }
     }
 
-    fn completion_factor(&self, animation_start_time: f64, current_time: f64) -> f64 {
+    pub fn forecast_queue(&mut self, side: Side) -> Result<(), ()> {
+        match self {
+            Phase::ChooseFirstDequeue(phase) => {
+                phase.inspector_state = MoveInspectorState::Forecasting(side);
+
+                Ok(())
+            }
+            Phase::ChooseSubsequentDequeue(phase) => {
+                phase.inspector_state = MoveInspectorState::Forecasting(side);
+
+                Ok(())
+            }
+
+            _ => Err(()),
+        }
+    }
+
+    pub fn previous_action_outcome(&self) -> Option<&[ActionPointsDestroyed; 2]> {
+        match self {
+            Phase::ChooseSubsequentDequeue(phase) => Some(&phase.previous_outcome),
+            Phase::GameOver(phase) => Some(&phase.previous_outcome),
+            _ => None,
+        }
+    }
+
+    pub fn queue_forecast(&self) -> Option<(Side, &[QueueForecast])> {
+        let (inspector_state, forecasts) = match self {
+            Phase::ChooseFirstDequeue(phase) => (phase.inspector_state, &phase.forecasts),
+            Phase::ChooseSubsequentDequeue(phase) => (phase.inspector_state, &phase.forecasts),
+            _ => return None,
+        };
+
+        match inspector_state {
+            MoveInspectorState::Forecasting(side) => Some((side, &forecasts[side as usize][..])),
+            _ => None,
+        }
+    }
+
+    pub fn inspect_move_while_held(&mut self, m: Move) -> Result<(), ()> {
+        let inspector_state = self.inspector_state_mut().ok_or(())?;
+        match inspector_state {
+            MoveInspectorState::NotInspecting | MoveInspectorState::InspectingWhileHeld(_) => {
+                *inspector_state = MoveInspectorState::InspectingWhileHeld(m);
+                Ok(())
+            }
+            _ => Err(()),
+        }
+    }
+
+    pub fn stop_inspecting_held_move(&mut self) {
+        if let Some(inspector_state) = self.inspector_state_mut() {
+            if let MoveInspectorState::InspectingWhileHeld(_) = inspector_state {
+                *inspector_state = MoveInspectorState::NotInspecting;
+            }
+        }
+    }
+
+    fn inspector_state_mut(&mut self) -> Option<&mut MoveInspectorState> {
+        match self {
+            Phase::ChooseFirstDequeue(phase) => Some(&mut phase.inspector_state),
+            Phase::ChooseAction(phase) => Some(&mut phase.inspector_state),
+            Phase::ChooseSubsequentDequeue(phase) => Some(&mut phase.inspector_state),
+            _ => None,
+        }
+    }
+
+    pub fn is_showing_results(
+        &self,
+        animation_start_time: f64,
+        current_time: f64,
+        animation_speed: AnimationSpeed,
+    ) -> bool {
+        matches!(self, Phase::GameOver(_))
+            && self.completion_factor(animation_start_time, current_time, animation_speed)
+                >= RESULTS_START
+    }
+
+    fn completion_factor(
+        &self,
+        animation_start_time: f64,
+        current_time: f64,
+        animation_speed: AnimationSpeed,
+    ) -> f64 {
+        let duration = self.duration(animation_speed);
+        if duration <= 0.0 {
+            return 1.0;
+        }
+
         let elapsed_time = current_time - animation_start_time;
-        let factor = elapsed_time / self.duration();
+        let factor = elapsed_time / duration;
 
         factor.min(1.0)
     }
 }
 
-impl Render<(f64, f64)> for Phase {
-    fn render(&self, (animation_start_time, current_time): (f64, f64)) -> Vec<Component> {
-        let completion_factor = self.completion_factor(animation_start_time, current_time);
+impl<'a> Render<(f64, AnimationSpeed, Side, &'a Context)> for Phase {
+    fn render(
+        &self,
+        (animation_start_time, animation_speed, seat, context): (
+            f64,
+            AnimationSpeed,
+            Side,
+            &'a Context,
+        ),
+    ) -> Vec<Component> {
+        let completion_factor =
+            self.completion_factor(animation_start_time, context.current_time, animation_speed);
+        let theme = context.theme();
+        let motion = context.motion();
+        let messages = context.messages();
 
         match self {
             Phase::ChooseCharacter(phase) => {
-                CharacterChoosingPhaseRenderer::new(phase).render(completion_factor)
+                CharacterChoosingPhaseRenderer::new(phase, theme, motion).render(completion_factor)
             }
 
             Phase::RechooseCharacter(phase) => {
-                CharacterRechoosingPhaseRenderer::new(phase).render(completion_factor)
+                CharacterRechoosingPhaseRenderer::new(phase, theme, motion)
+                    .render(completion_factor)
             }
 
             Phase::ChooseBooster(phase) => {
-                BoosterChoosingPhaseRenderer::new(phase).render(completion_factor)
+                BoosterChoosingPhaseRenderer::new(phase, theme, motion).render(completion_factor)
             }
 
             Phase::ChooseFirstDequeue(phase) => {
-                FirstDequeueingPhaseRenderer::new(phase).render(completion_factor)
+                FirstDequeueingPhaseRenderer::new(phase, theme, motion, messages, seat)
+                    .render(completion_factor)
             }
 
             Phase::ChooseAction(phase) => {
-                ActionChoosingPhaseRenderer::new(phase).render(completion_factor)
+                ActionChoosingPhaseRenderer::new(phase, theme, motion, messages, seat)
+                    .render(completion_factor)
             }
 
             Phase::ChooseSubsequentDequeue(phase) => {
-                SubsequentDequeueingPhaseRenderer::new(phase).render(completion_factor)
+                SubsequentDequeueingPhaseRenderer::new(phase, theme, motion, messages, seat)
+                    .render(completion_factor)
             }
 
-            Phase::GameOver(phase) => GameOverPhaseRenderer::new(phase).render(completion_factor),
+            Phase::GameOver(phase) => {
+                GameOverPhaseRenderer::new(phase, theme, motion).render(completion_factor)
+            }
         }
     }
 }
@@ -176,6 +448,7 @@ pub struct ChooseFirstDequeuePhase {
     pub previously_available_boosters: Vec<Booster>,
     pub scoreboard: [DequeueingPlayer; 2],
     pub available_dequeues: [Vec<DequeueChoice>; 2],
+    pub forecasts: [Vec<QueueForecast>; 2],
     pub inspector_state: MoveInspectorState,
 }
 
@@ -196,6 +469,7 @@ pub struct ChooseSubsequentDequeuePhase {
     pub previous_outcome: [ActionPointsDestroyed; 2],
     pub scoreboard: [DequeueingPlayer; 2],
     pub available_dequeues: [Vec<DequeueChoice>; 2],
+    pub forecasts: [Vec<QueueForecast>; 2],
     pub inspector_state: MoveInspectorState,
 }
 
@@ -212,14 +486,17 @@ pub enum MoveInspectorState {
     NotInspecting,
     WaitingForUserToChooseMove,
     Inspecting(Move),
+    InspectingWhileHeld(Move),
+    Forecasting(Side),
 }
 
 impl MoveInspectorState {
     pub fn move_(self) -> Option<Move> {
-        if let MoveInspectorState::Inspecting(m) = self {
-            Some(m)
-        } else {
-            None
+        match self {
+            MoveInspectorState::Inspecting(m) | MoveInspectorState::InspectingWhileHeld(m) => {
+                Some(m)
+            }
+            _ => None,
         }
     }
 }
@@ -233,3 +510,52 @@ mod durations {
     pub const CHOOSING_SUBSEQUENT_DEQUEUE: f64 = 2.0;
     pub const GAME_OVER: f64 = 2.5;
 }
+
+const HUMAN: usize = 0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test_games;
+
+    fn phase() -> Phase {
+        Phase::initial(&BatchChoiceGame::default())
+    }
+
+    #[test]
+    fn animation_speed_scales_duration() {
+        let phase = phase();
+        let normal = phase.duration(AnimationSpeed::Normal);
+
+        assert_eq!(2.0 * normal, phase.duration(AnimationSpeed::Half));
+        assert_eq!(0.5 * normal, phase.duration(AnimationSpeed::Double));
+    }
+
+    #[test]
+    fn instant_animations_start_on_their_final_frame() {
+        let phase = phase();
+
+        assert_eq!(
+            1.0,
+            phase.completion_factor(5.0, 5.0, AnimationSpeed::Instant)
+        );
+        assert!(phase.is_elapsed_time_past_completion(0.001, AnimationSpeed::Instant));
+    }
+
+    #[test]
+    fn holding_a_move_leaves_an_open_inspector_alone() {
+        let mut game = BatchChoiceGame::default();
+        let mut phase = phase();
+        for choice in test_games::opening_choices() {
+            phase = phase.next(&mut game, choice).unwrap();
+        }
+        *phase.inspector_state_mut().unwrap() = MoveInspectorState::Inspecting(Move::Kick);
+
+        assert_eq!(Err(()), phase.inspect_move_while_held(Move::Rampage));
+        phase.stop_inspecting_held_move();
+        assert_eq!(
+            Some(&mut MoveInspectorState::Inspecting(Move::Kick)),
+            phase.inspector_state_mut()
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use crate::analysis::{Analysis, RoundAnalysis};
+use crate::canvas_dimensions;
+use crate::click::Action;
+use crate::colors::Rgba;
+use crate::description;
+use crate::i18n::Messages;
+use crate::paint::Component;
+use crate::render::{self, Render};
+use crate::shapes::Rect;
+use crate::text::TextAlign;
+use crate::theme::Theme;
+
+pub struct AnalysisPanel<'a> {
+    pub analysis: &'a Analysis,
+    pub phase_index: usize,
+    pub is_expanded: bool,
+    pub theme: &'a Theme,
+    pub messages: Messages,
+}
+
+impl<'a> AnalysisPanel<'a> {
+    fn banner(&self) -> Vec<Component> {
+        let message = self
+            .analysis
+            .mistake_shown_at_phase(self.phase_index)
+            .and_then(|decision| description::describe_mistake(decision, self.messages));
+
+        match message {
+            Some(message) => vec![
+                Component::Rect {
+                    fill_color: self.theme.settings_screen_background,
+                    shape: Rect {
+                        x: BANNER_X,
+                        y: BANNER_Y,
+                        width: BANNER_WIDTH,
+                        height: BANNER_HEIGHT,
+                    },
+                    on_click: None,
+                },
+                render::aligned_label(
+                    message,
+                    (canvas_dimensions::CENTER_X, BANNER_Y + 0.5 * BANNER_HEIGHT),
+                    BANNER_FONT_SIZE,
+                    TextAlign::Center,
+                    self.theme.stats_loss,
+                    Some(BANNER_WIDTH - 2.0 * PADDING),
+                ),
+            ],
+            None => vec![],
+        }
+    }
+
+    fn panel(&self) -> Vec<Component> {
+        let rounds = &self.analysis.rounds;
+        let current_round = self.current_round();
+        let first_row = current_round
+            .unwrap_or(0)
+            .saturating_sub(VISIBLE_ROWS / 2)
+            .min(rounds.len().saturating_sub(VISIBLE_ROWS));
+        let mistake_count = self
+            .analysis
+            .decisions()
+            .filter(|decision| decision.is_mistake())
+            .count();
+
+        let header = vec![
+            Component::Rect {
+                fill_color: self.theme.settings_screen_background,
+                shape: Rect {
+                    x: PANEL_X,
+                    y: PANEL_Y,
+                    width: PANEL_WIDTH,
+                    height: PANEL_HEIGHT,
+                },
+                on_click: Some(Action::StopPropagation),
+            },
+            render::aligned_label(
+                self.messages.text("analysis.title"),
+                (PANEL_X + PADDING, TITLE_Y),
+                TITLE_FONT_SIZE,
+                TextAlign::Left,
+                self.theme.screen_text,
+                Some(PANEL_WIDTH - 2.0 * PADDING),
+            ),
+            render::aligned_label(
+                self.messages
+                    .format("analysis.mistake_count", &[&mistake_count]),
+                (PANEL_X + PADDING, COUNT_Y),
+                ROW_FONT_SIZE,
+                TextAlign::Left,
+                self.theme.screen_text,
+                Some(PANEL_WIDTH - 2.0 * PADDING),
+            ),
+        ];
+        let rows = rounds
+            .iter()
+            .enumerate()
+            .skip(first_row)
+            .take(VISIBLE_ROWS)
+            .enumerate()
+            .flat_map(|(row, (i, round))| {
+                self.row(
+                    round,
+                    LIST_Y + ROW_HEIGHT * row as f64,
+                    Some(i) == current_round,
+                )
+            });
+
+        header.into_iter().chain(rows).collect()
+    }
+
+    fn row(&self, round: &RoundAnalysis, y: f64, is_current: bool) -> Vec<Component> {
+        let has_mistake = round.decisions.iter().any(|decision| decision.is_mistake());
+        let target = round
+            .decisions
+            .iter()
+            .find(|decision| decision.is_mistake())
+            .or_else(|| round.decisions.last())
+            .map(|decision| decision.choice_index + 1);
+
+        vec![
+            Component::Rect {
+                fill_color: if is_current {
+                    self.theme.hover_highlight
+                } else {
+                    Rgba::transparent()
+                },
+                shape: Rect {
+                    x: PANEL_X,
+                    y,
+                    width: PANEL_WIDTH,
+                    height: ROW_HEIGHT,
+                },
+                on_click: target.map(Action::SeekReplay),
+            },
+            render::aligned_label(
+                description::describe_round_analysis(round, self.messages),
+                (PANEL_X + PADDING, y + 0.5 * ROW_HEIGHT),
+                ROW_FONT_SIZE,
+                TextAlign::Left,
+                if has_mistake {
+                    self.theme.stats_loss
+                } else {
+                    self.theme.screen_text
+                },
+                Some(PANEL_WIDTH - 2.0 * PADDING),
+            ),
+        ]
+    }
+
+    fn current_round(&self) -> Option<usize> {
+        self.analysis.rounds.iter().rposition(|round| {
+            round
+                .decisions
+                .first()
+                .is_some_and(|decision| decision.choice_index < self.phase_index)
+        })
+    }
+}
+
+impl<'a> Render<()> for AnalysisPanel<'a> {
+    fn render(&self, _: ()) -> Vec<Component> {
+        if self.is_expanded {
+            self.panel().into_iter().chain(self.banner()).collect()
+        } else {
+            self.banner()
+        }
+    }
+}
+
+const PANEL_X: f64 = 1360.0;
+const PANEL_Y: f64 = 140.0;
+const PANEL_WIDTH: f64 = canvas_dimensions::WIDTH - PANEL_X - 20.0;
+const PANEL_HEIGHT: f64 = 720.0;
+const PADDING: f64 = 16.0;
+const TITLE_Y: f64 = PANEL_Y + 36.0;
+const TITLE_FONT_SIZE: f64 = 32.0;
+const COUNT_Y: f64 = PANEL_Y + 76.0;
+
+const LIST_Y: f64 = PANEL_Y + 100.0;
+const ROW_HEIGHT: f64 = 44.0;
+const ROW_FONT_SIZE: f64 = 20.0;
+const VISIBLE_ROWS: usize = 14;
+
+const BANNER_X: f64 = 460.0;
+const BANNER_Y: f64 = 140.0;
+const BANNER_WIDTH: f64 = 880.0;
+const BANNER_HEIGHT: f64 = 56.0;
+const BANNER_FONT_SIZE: f64 = 22.0;
@@ -1,18 +1,18 @@
-use crate::colors;
 use crate::helpers::SQRT_3;
 use crate::paint::{Component, Path, PathCommand};
 use crate::shapes::dequeue_circle::{self, LEFT_COLUMN_0_X, RIGHT_COLUMN_0_X};
 use crate::side::Side;
+use crate::theme::Theme;
 use crate::transform::Translate;
 
-pub fn up_arrow_above(side: Side, row: usize, column: usize) -> Component {
+pub fn up_arrow_above(side: Side, row: usize, column: usize, theme: &Theme) -> Component {
     match side {
-        Side::Left => left_up_arrow_above(row, column),
-        Side::Right => right_up_arrow_above(row, column),
+        Side::Left => left_up_arrow_above(row, column, theme),
+        Side::Right => right_up_arrow_above(row, column, theme),
     }
 }
 
-fn left_up_arrow_above(row: usize, column: usize) -> Component {
+fn left_up_arrow_above(row: usize, column: usize, theme: &Theme) -> Component {
     Component::UnclickablePath {
         path: Path {
             start: (LEFT_COLUMN_0_X, ROW_0_CENTER_Y - 0.5 * ARROW_HEIGHT),
@@ -28,12 +28,12 @@ fn left_up_arrow_above(row: usize, column: usize) -> Component {
             ],
         }
         .translate(OFFSET * column as f64, OFFSET * row as f64),
-        fill_color: Some(colors::ARROW_COLOR),
+        fill_color: Some(theme.arrow),
         stroke: None,
     }
 }
 
-fn right_up_arrow_above(row: usize, column: usize) -> Component {
+fn right_up_arrow_above(row: usize, column: usize, theme: &Theme) -> Component {
     Component::UnclickablePath {
         path: Path {
             start: (RIGHT_COLUMN_0_X, ROW_0_CENTER_Y - 0.5 * ARROW_HEIGHT),
@@ -49,19 +49,19 @@ fn right_up_arrow_above(row: usize, column: usize) -> Component {
             ],
         }
         .translate(-OFFSET * column as f64, OFFSET * row as f64),
-        fill_color: Some(colors::ARROW_COLOR),
+        fill_color: Some(theme.arrow),
         stroke: None,
     }
 }
 
-pub fn down_arrow_above(side: Side, row: usize, column: usize) -> Component {
+pub fn down_arrow_above(side: Side, row: usize, column: usize, theme: &Theme) -> Component {
     match side {
-        Side::Left => left_down_arrow_above(row, column),
-        Side::Right => right_down_arrow_above(row, column),
+        Side::Left => left_down_arrow_above(row, column, theme),
+        Side::Right => right_down_arrow_above(row, column, theme),
     }
 }
 
-fn left_down_arrow_above(row: usize, column: usize) -> Component {
+fn left_down_arrow_above(row: usize, column: usize, theme: &Theme) -> Component {
     Component::UnclickablePath {
         path: Path {
             start: (LEFT_COLUMN_0_X, ROW_0_CENTER_Y + 0.5 * ARROW_HEIGHT),
@@ -78,12 +78,12 @@ fn left_down_arrow_above(row: usize, column: usize) -> Component {
         }
         .translate(OFFSET * column as f64, OFFSET * row as f64),
 
-        fill_color: Some(colors::ARROW_COLOR),
+        fill_color: Some(theme.arrow),
         stroke: None,
     }
 }
 
-fn right_down_arrow_above(row: usize, column: usize) -> Component {
+fn right_down_arrow_above(row: usize, column: usize, theme: &Theme) -> Component {
     Component::UnclickablePath {
         path: Path {
             start: (RIGHT_COLUMN_0_X, ROW_0_CENTER_Y + 0.5 * ARROW_HEIGHT),
@@ -100,7 +100,7 @@ fn right_down_arrow_above(row: usize, column: usize) -> Component {
         }
         .translate(-OFFSET * column as f64, OFFSET * row as f64),
 
-        fill_color: Some(colors::ARROW_COLOR),
+        fill_color: Some(theme.arrow),
         stroke: None,
     }
 }
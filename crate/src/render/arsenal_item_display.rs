@@ -2,6 +2,7 @@ use crate::click::Action;
 use crate::colors;
 use crate::paint::{Component, ImageType};
 use crate::shapes::dequeue_circle::{self, CirclePosition};
+use crate::theme::Theme;
 
 use nzscq::choices::ArsenalItem;
 
@@ -10,14 +11,17 @@ pub fn arsenal_item_display(
     enabled: bool,
     on_click_if_enabled: Option<Action>,
     position: CirclePosition,
+    theme: &Theme,
 ) -> Vec<Component> {
     let on_click = if enabled { on_click_if_enabled } else { None };
     let CirclePosition { side, column, row } = position;
     let side = side;
     let fill_color = if enabled {
-        colors::arsenal_item_color(item)
+        theme.arsenal_item_color(item)
     } else {
-        colors::arsenal_item_color(item).with_alpha(colors::DISABLED_DEQUEUE_ARSENAL_ITEM_ALPHA)
+        theme
+            .arsenal_item_color(item)
+            .with_alpha(colors::DISABLED_DEQUEUE_ARSENAL_ITEM_ALPHA)
     };
     let image_alpha = if enabled {
         1.0
@@ -43,7 +47,7 @@ pub fn arsenal_item_display(
             vec![]
         } else {
             vec![Component::Circle {
-                fill_color: colors::OVERLAY,
+                fill_color: theme.overlay,
                 shape: dequeue_circle::background_at(side, row, column),
                 on_click: None,
             }]
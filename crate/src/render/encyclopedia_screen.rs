@@ -0,0 +1,275 @@
+use crate::canvas_dimensions;
+use crate::click::Action;
+use crate::context::Context;
+use crate::encyclopedia::{self, Interaction, Matchup, MoveSource};
+use crate::helpers;
+use crate::i18n::Messages;
+use crate::paint::{Component, ImageType};
+use crate::render::{self, lerp::Lerper};
+use crate::shapes::Rect;
+use crate::theme::Theme;
+use crate::transform::{Scale, Translate};
+
+use nzscq::choices::Move;
+
+pub fn encyclopedia_screen(selected_move: Option<Move>, context: &Context) -> Vec<Component> {
+    let theme = context.theme();
+    let messages = context.messages();
+
+    vec![
+        vec![Component::Background {
+            color: theme.settings_screen_background,
+        }],
+        render::home_button(&Lerper::from_completion_factor(1.0), theme)
+            .translate(-canvas_dimensions::CENTER_X, -canvas_dimensions::CENTER_Y)
+            .scale(TARGET_RADIUS / ORIGINAL_RADIUS)
+            .translate(MARGIN + TARGET_RADIUS, MARGIN + TARGET_RADIUS),
+        render::text_button(
+            messages.text("button.matchup_matrix"),
+            Rect {
+                x: DETAILS_X,
+                y: MARGIN,
+                width: canvas_dimensions::WIDTH - MARGIN - DETAILS_X,
+                height: MATRIX_BUTTON_HEIGHT,
+            },
+            Some(Action::NavigateToMatchupMatrix),
+            theme,
+        ),
+        move_groups(selected_move, theme, messages),
+        match selected_move {
+            Some(m) => move_details(m, theme, messages),
+            None => vec![render::label(
+                messages.text("encyclopedia.hint"),
+                (DETAILS_X, TOP + 0.5 * LABEL_FONT_SIZE),
+                LABEL_FONT_SIZE,
+                theme.screen_text,
+            )],
+        },
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn move_groups(selected_move: Option<Move>, theme: &Theme, messages: Messages) -> Vec<Component> {
+    encyclopedia::move_groups()
+        .into_iter()
+        .enumerate()
+        .flat_map(|(i, group)| {
+            let x = LEFT + GROUP_WIDTH * (i / GROUPS_PER_COLUMN) as f64;
+            let y = TOP + GROUP_HEIGHT * (i % GROUPS_PER_COLUMN) as f64;
+            let name = match group.source {
+                MoveSource::Character(character) => messages.character_name(character),
+                MoveSource::Booster(booster) => messages.booster_name(booster),
+            };
+            let logo = group.source.logo_move();
+            let icons = group.moves.into_iter().enumerate().flat_map(move |(j, m)| {
+                let icon_x = x + (ICON_SIZE + ICON_GAP) * (j + 1) as f64 + LOGO_GAP;
+                move_icon(
+                    m,
+                    icon_x,
+                    y + LABEL_HEIGHT,
+                    ICON_SIZE,
+                    Some(m) == selected_move,
+                    theme,
+                )
+            });
+
+            vec![
+                render::label(
+                    name,
+                    (x, y + 0.5 * LABEL_FONT_SIZE),
+                    LABEL_FONT_SIZE,
+                    theme.screen_text,
+                ),
+                Component::Image {
+                    image_type: ImageType::Move(logo),
+                    alpha: LOGO_ALPHA,
+                    shape: Rect {
+                        x,
+                        y: y + LABEL_HEIGHT,
+                        width: ICON_SIZE,
+                        height: ICON_SIZE,
+                    },
+                    on_click: None,
+                },
+            ]
+            .into_iter()
+            .chain(icons)
+        })
+        .collect()
+}
+
+fn move_details(m: Move, theme: &Theme, messages: Messages) -> Vec<Component> {
+    let matchup = Matchup::from(m);
+    let mut y = TOP;
+    let mut components = move_icon(m, DETAILS_X, y, HEADING_ICON_SIZE, false, theme);
+
+    components.push(render::label(
+        messages.move_name(m),
+        (
+            DETAILS_X + HEADING_ICON_SIZE + ICON_GAP,
+            y + 0.5 * HEADING_ICON_SIZE,
+        ),
+        HEADING_FONT_SIZE,
+        theme.screen_text,
+    ));
+    y += HEADING_ICON_SIZE + SECTION_GAP;
+
+    for flag in flags(&matchup, messages) {
+        components.push(render::label(
+            flag,
+            (DETAILS_X, y + 0.5 * LABEL_FONT_SIZE),
+            LABEL_FONT_SIZE,
+            theme.screen_text,
+        ));
+        y += LABEL_HEIGHT;
+    }
+    components.push(render::label(
+        messages.format(
+            "encyclopedia.mirror",
+            &[&interaction_name(matchup.against_mirror, messages)],
+        ),
+        (DETAILS_X, y + 0.5 * LABEL_FONT_SIZE),
+        LABEL_FONT_SIZE,
+        theme.screen_text,
+    ));
+    y += LABEL_HEIGHT + SECTION_GAP;
+
+    let sections = vec![
+        ("encyclopedia.wins", matchup.wins, theme.inspector_victory),
+        (
+            "encyclopedia.losses",
+            matchup.losses,
+            theme.inspector_defeat,
+        ),
+        (
+            "encyclopedia.trades",
+            matchup.trades,
+            theme.inspector_special_tie,
+        ),
+        ("encyclopedia.neutral", matchup.neutral, theme.screen_text),
+    ];
+    for (key, moves, color) in sections {
+        components.push(render::label(
+            messages.text(key),
+            (DETAILS_X, y + 0.5 * LABEL_FONT_SIZE),
+            LABEL_FONT_SIZE,
+            color,
+        ));
+        y += LABEL_HEIGHT;
+
+        for (i, &other) in moves.iter().enumerate() {
+            let column = i % MATCHUP_ICONS_PER_ROW;
+            let row = i / MATCHUP_ICONS_PER_ROW;
+            components.extend(move_icon(
+                other,
+                DETAILS_X + (MATCHUP_ICON_SIZE + ICON_GAP) * column as f64,
+                y + (MATCHUP_ICON_SIZE + ICON_GAP) * row as f64,
+                MATCHUP_ICON_SIZE,
+                false,
+                theme,
+            ));
+        }
+        let rows = helpers::height_in_rows(&moves, MATCHUP_ICONS_PER_ROW);
+        y += (MATCHUP_ICON_SIZE + ICON_GAP) * rows as f64 + SECTION_GAP;
+    }
+
+    components
+}
+
+fn flags(matchup: &Matchup, messages: Messages) -> Vec<String> {
+    let mut flags = vec![];
+    if matchup.is_destructive {
+        flags.push(messages.text("encyclopedia.destructive"));
+    }
+    if matchup.is_single_use {
+        flags.push(messages.text("encyclopedia.single_use"));
+    }
+    if flags.is_empty() {
+        flags.push(messages.text("encyclopedia.reusable"));
+    }
+
+    flags
+}
+
+pub fn interaction_name(interaction: Interaction, messages: Messages) -> String {
+    match interaction {
+        Interaction::Win => messages.text("encyclopedia.interaction_win"),
+        Interaction::Loss => messages.text("encyclopedia.interaction_loss"),
+        Interaction::Trade => messages.text("encyclopedia.interaction_trade"),
+        Interaction::Neutral => messages.text("encyclopedia.interaction_neutral"),
+    }
+}
+
+fn move_icon(
+    m: Move,
+    x: f64,
+    y: f64,
+    size: f64,
+    is_selected: bool,
+    theme: &Theme,
+) -> Vec<Component> {
+    let shape = Rect {
+        x,
+        y,
+        width: size,
+        height: size,
+    };
+    let highlight = if is_selected {
+        Some(Component::Rect {
+            fill_color: theme.inspected_move_highlight,
+            shape: Rect {
+                x: x - SELECTION_PADDING,
+                y: y - SELECTION_PADDING,
+                width: size + 2.0 * SELECTION_PADDING,
+                height: size + 2.0 * SELECTION_PADDING,
+            },
+            on_click: None,
+        })
+    } else {
+        None
+    };
+
+    highlight
+        .into_iter()
+        .chain(vec![
+            Component::Rect {
+                fill_color: theme.move_color(m),
+                shape: shape.clone(),
+                on_click: Some(Action::SelectEncyclopediaMove(m)),
+            },
+            Component::Image {
+                image_type: ImageType::Move(m),
+                alpha: 1.0,
+                shape,
+                on_click: None,
+            },
+        ])
+        .collect()
+}
+
+const ORIGINAL_RADIUS: f64 = 120.0;
+const MARGIN: f64 = 30.0;
+const TARGET_RADIUS: f64 = 40.0;
+
+const TOP: f64 = 150.0;
+const LEFT: f64 = 40.0;
+const GROUPS_PER_COLUMN: usize = 3;
+const GROUP_WIDTH: f64 = 300.0;
+const GROUP_HEIGHT: f64 = 130.0;
+const LABEL_FONT_SIZE: f64 = 24.0;
+const LABEL_HEIGHT: f64 = 36.0;
+const ICON_SIZE: f64 = 60.0;
+const ICON_GAP: f64 = 8.0;
+const LOGO_GAP: f64 = 8.0;
+const LOGO_ALPHA: f64 = 0.5;
+const SELECTION_PADDING: f64 = 6.0;
+
+const DETAILS_X: f64 = 1260.0;
+const HEADING_ICON_SIZE: f64 = 100.0;
+const HEADING_FONT_SIZE: f64 = 36.0;
+const SECTION_GAP: f64 = 16.0;
+const MATCHUP_ICON_SIZE: f64 = 40.0;
+const MATCHUP_ICONS_PER_ROW: usize = 10;
+const MATRIX_BUTTON_HEIGHT: f64 = 90.0;
@@ -0,0 +1,316 @@
+use crate::click::Action;
+use crate::game_summary::GameSummary;
+use crate::i18n::Messages;
+use crate::paint::{Component, ImageType, Stroke};
+use crate::render::{self, Render};
+use crate::shapes::{Circle, Rect};
+use crate::text::TextAlign;
+use crate::theme::Theme;
+
+use nzscq::choices::ArsenalItem;
+
+impl<'a> Render<(&'a Theme, Messages)> for GameSummary {
+    fn render(&self, (theme, messages): (&'a Theme, Messages)) -> Vec<Component> {
+        vec![
+            vec![
+                Component::Rect {
+                    fill_color: theme.settings_screen_background,
+                    shape: Rect {
+                        x: PANEL_X,
+                        y: PANEL_Y,
+                        width: PANEL_WIDTH,
+                        height: PANEL_HEIGHT,
+                    },
+                    on_click: None,
+                },
+                render::aligned_label(
+                    messages.text("summary.title"),
+                    (PANEL_X + PADDING, TITLE_Y),
+                    TITLE_FONT_SIZE,
+                    TextAlign::Left,
+                    theme.screen_text,
+                    None,
+                ),
+            ],
+            self.chart_grid(theme),
+            self.health_line(HUMAN, theme),
+            self.health_line(COMPUTER, theme),
+            self.legend(theme, messages),
+            self.stats_table(theme, messages),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl GameSummary {
+    fn chart_grid(&self, theme: &Theme) -> Vec<Component> {
+        let health_lines = (0..=MAX_HEALTH).flat_map(|health| {
+            let y = health_y(health);
+
+            vec![
+                Component::line(
+                    (CHART_X, y),
+                    (CHART_X + CHART_WIDTH, y),
+                    Stroke {
+                        color: theme.stats_bar_track,
+                        width: GRID_LINE_WIDTH,
+                    },
+                ),
+                render::aligned_label(
+                    health.to_string(),
+                    (CHART_X - AXIS_LABEL_GAP, y),
+                    LABEL_FONT_SIZE,
+                    TextAlign::Right,
+                    theme.screen_text,
+                    None,
+                ),
+            ]
+        });
+        let label_step = self.rounds().div_ceil(MAX_ROUND_LABELS);
+        let round_labels = (0..=self.rounds()).step_by(label_step.max(1)).map(|round| {
+            render::aligned_label(
+                round.to_string(),
+                (
+                    self.round_x(round),
+                    CHART_Y + CHART_HEIGHT + AXIS_LABEL_GAP + 0.5 * LABEL_FONT_SIZE,
+                ),
+                LABEL_FONT_SIZE,
+                TextAlign::Center,
+                theme.screen_text,
+                None,
+            )
+        });
+
+        health_lines.chain(round_labels).collect()
+    }
+
+    fn health_line(&self, side: usize, theme: &Theme) -> Vec<Component> {
+        let color = theme.character_color(self.characters[side]);
+        let points: Vec<(f64, f64)> = self.health[side]
+            .iter()
+            .enumerate()
+            .map(|(round, &health)| (self.round_x(round), health_y(health)))
+            .collect();
+        let segments = points.windows(2).map(|segment| {
+            Component::line(
+                segment[0],
+                segment[1],
+                Stroke {
+                    color,
+                    width: HEALTH_LINE_WIDTH,
+                },
+            )
+        });
+        let dots = points.iter().map(|&(x, y)| Component::Circle {
+            fill_color: color,
+            shape: Circle {
+                x,
+                y,
+                radius: DOT_RADIUS,
+            },
+            on_click: None,
+        });
+
+        segments.chain(dots).collect()
+    }
+
+    fn legend(&self, theme: &Theme, messages: Messages) -> Vec<Component> {
+        [HUMAN, COMPUTER]
+            .iter()
+            .flat_map(|&side| {
+                let x = COLUMN_X[side];
+
+                vec![
+                    Component::Rect {
+                        fill_color: theme.character_color(self.characters[side]),
+                        shape: Rect {
+                            x,
+                            y: LEGEND_Y - 0.5 * SWATCH_SIZE,
+                            width: SWATCH_SIZE,
+                            height: SWATCH_SIZE,
+                        },
+                        on_click: None,
+                    },
+                    render::aligned_label(
+                        messages.format(
+                            "summary.legend",
+                            &[
+                                &messages.text(PLAYER_KEYS[side]),
+                                &messages.character_name(self.characters[side]),
+                            ],
+                        ),
+                        (x + SWATCH_SIZE + SWATCH_GAP, LEGEND_Y),
+                        LABEL_FONT_SIZE,
+                        TextAlign::Left,
+                        theme.screen_text,
+                        None,
+                    ),
+                ]
+            })
+            .collect()
+    }
+
+    fn stats_table(&self, theme: &Theme, messages: Messages) -> Vec<Component> {
+        let label = |key: &str, y: f64| {
+            render::aligned_label(
+                messages.text(key),
+                (PANEL_X + PADDING, y),
+                LABEL_FONT_SIZE,
+                TextAlign::Left,
+                theme.screen_text,
+                None,
+            )
+        };
+        let counts = |counts: [usize; 2], y: f64| {
+            [HUMAN, COMPUTER]
+                .iter()
+                .map(|&side| {
+                    render::aligned_label(
+                        counts[side].to_string(),
+                        (COLUMN_X[side], y),
+                        LABEL_FONT_SIZE,
+                        TextAlign::Left,
+                        theme.screen_text,
+                        None,
+                    )
+                })
+                .collect::<Vec<Component>>()
+        };
+
+        vec![
+            vec![render::aligned_label(
+                messages.format("summary.rounds", &[&self.rounds()]),
+                (PANEL_X + PADDING, ROUNDS_Y),
+                LABEL_FONT_SIZE,
+                TextAlign::Left,
+                theme.screen_text,
+                None,
+            )],
+            vec![label("summary.items_destroyed", DESTROYED_Y)],
+            counts(self.items_destroyed, DESTROYED_Y),
+            vec![label("summary.mirrors_used", MIRRORS_Y)],
+            counts(self.mirrors_used, MIRRORS_Y),
+            vec![label("summary.scoring_moves", SCORING_Y + 0.5 * ICON_SIZE)],
+            scoring_icons(&self.scoring_items[HUMAN], COLUMN_X[HUMAN], theme, messages),
+            scoring_icons(
+                &self.scoring_items[COMPUTER],
+                COLUMN_X[COMPUTER],
+                theme,
+                messages,
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    fn round_x(&self, round: usize) -> f64 {
+        CHART_X + CHART_WIDTH * round as f64 / self.rounds().max(1) as f64
+    }
+}
+
+pub fn analyze_button(theme: &Theme, messages: Messages) -> Vec<Component> {
+    render::text_button(
+        messages.text("button.analyze"),
+        Rect {
+            x: PANEL_X + PANEL_WIDTH - PADDING - ANALYZE_BUTTON_WIDTH,
+            y: TITLE_Y - 0.5 * ANALYZE_BUTTON_HEIGHT,
+            width: ANALYZE_BUTTON_WIDTH,
+            height: ANALYZE_BUTTON_HEIGHT,
+        },
+        Some(Action::AnalyzeGame),
+        theme,
+    )
+}
+
+fn scoring_icons(
+    items: &[ArsenalItem],
+    x: f64,
+    theme: &Theme,
+    messages: Messages,
+) -> Vec<Component> {
+    if items.is_empty() {
+        return vec![render::aligned_label(
+            messages.text("list.none"),
+            (x, SCORING_Y + 0.5 * ICON_SIZE),
+            LABEL_FONT_SIZE,
+            TextAlign::Left,
+            theme.screen_text,
+            None,
+        )];
+    }
+
+    items
+        .iter()
+        .take(ICONS_PER_ROW * ICON_ROWS)
+        .enumerate()
+        .flat_map(|(i, &item)| {
+            let shape = Rect {
+                x: x + (ICON_SIZE + ICON_GAP) * (i % ICONS_PER_ROW) as f64,
+                y: SCORING_Y + (ICON_SIZE + ICON_GAP) * (i / ICONS_PER_ROW) as f64,
+                width: ICON_SIZE,
+                height: ICON_SIZE,
+            };
+
+            vec![
+                Component::Rect {
+                    fill_color: theme.arsenal_item_color(item),
+                    shape: shape.clone(),
+                    on_click: None,
+                },
+                Component::Image {
+                    image_type: ImageType::from(item),
+                    alpha: 1.0,
+                    shape,
+                    on_click: None,
+                },
+            ]
+        })
+        .collect()
+}
+
+fn health_y(health: u8) -> f64 {
+    CHART_Y + CHART_HEIGHT * (1.0 - f64::from(health) / f64::from(MAX_HEALTH))
+}
+
+const HUMAN: usize = 0;
+const COMPUTER: usize = 1;
+const PLAYER_KEYS: [&str; 2] = ["player.you", "player.computer"];
+
+const PANEL_X: f64 = 40.0;
+const PANEL_Y: f64 = 200.0;
+const PANEL_WIDTH: f64 = 700.0;
+const PANEL_HEIGHT: f64 = 670.0;
+const PADDING: f64 = 24.0;
+const TITLE_Y: f64 = 232.0;
+const TITLE_FONT_SIZE: f64 = 36.0;
+const LABEL_FONT_SIZE: f64 = 22.0;
+const ANALYZE_BUTTON_WIDTH: f64 = 220.0;
+const ANALYZE_BUTTON_HEIGHT: f64 = 56.0;
+
+const MAX_HEALTH: u8 = 5;
+const CHART_X: f64 = PANEL_X + 70.0;
+const CHART_Y: f64 = 270.0;
+const CHART_WIDTH: f64 = PANEL_WIDTH - 110.0;
+const CHART_HEIGHT: f64 = 240.0;
+const AXIS_LABEL_GAP: f64 = 12.0;
+const MAX_ROUND_LABELS: usize = 10;
+const GRID_LINE_WIDTH: f64 = 2.0;
+const HEALTH_LINE_WIDTH: f64 = 6.0;
+const DOT_RADIUS: f64 = 7.0;
+
+const LEGEND_Y: f64 = 580.0;
+const SWATCH_SIZE: f64 = 24.0;
+const SWATCH_GAP: f64 = 10.0;
+const COLUMN_X: [f64; 2] = [PANEL_X + 260.0, PANEL_X + 480.0];
+
+const ROUNDS_Y: f64 = 625.0;
+const DESTROYED_Y: f64 = 670.0;
+const MIRRORS_Y: f64 = 715.0;
+const SCORING_Y: f64 = 745.0;
+const ICON_SIZE: f64 = 36.0;
+const ICON_GAP: f64 = 4.0;
+const ICONS_PER_ROW: usize = 5;
+const ICON_ROWS: usize = 2;
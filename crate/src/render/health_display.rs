@@ -7,18 +7,20 @@ use crate::{
     paint::{Component, ImageType},
     render::{
         lerp::{LerpInto, LerpableComponent, Lerper},
-        switch::{Switch, Switch4},
+        switch::{Motion, Switch, Switch4},
     },
     shapes::{CenteredRect, Rect},
+    theme::Theme,
     transform::Translate,
 };
 
-pub struct ConstantHealthDisplay {
+pub struct ConstantHealthDisplay<'a> {
     pub side: Side,
     pub health: u8,
+    pub theme: &'a Theme,
 }
 
-impl Render<()> for ConstantHealthDisplay {
+impl<'a> Render<()> for ConstantHealthDisplay<'a> {
     fn render(&self, _: ()) -> Vec<Component> {
         let hearts = (0..self.health as usize)
             .map(|i| {
@@ -26,22 +28,26 @@ impl Render<()> for ConstantHealthDisplay {
                     side: self.side,
                     index: i,
                 })
-                .case(0.0)
+                .case(0.0, Motion::Full)
                 .expect("should find a case")
             })
             .flatten();
-        let trapezoid = HealthTrapezoid { side: self.side };
+        let trapezoid = HealthTrapezoid {
+            side: self.side,
+            theme: self.theme,
+        };
 
         trapezoid.render(()).into_iter().chain(hearts).collect()
     }
 }
 
-pub struct FadingHealthDisplay {
+pub struct FadingHealthDisplay<'a> {
     pub side: Side,
     pub starting_health: u8,
+    pub theme: &'a Theme,
 }
 
-impl LerpInto<Vec<Component>> for FadingHealthDisplay {
+impl<'a> LerpInto<Vec<Component>> for FadingHealthDisplay<'a> {
     fn lerp_into(self, lerper: &Lerper) -> Vec<Component> {
         let sublerper = lerper.sub_lerper(0.0..colors::PORTION_OF_DURATION_SPENT_POPPING);
         let hearts = (0..self.starting_health)
@@ -55,11 +61,14 @@ impl LerpInto<Vec<Component>> for FadingHealthDisplay {
                     side: self.side,
                     index: i as usize,
                 })
-                .case(completion_factor)
+                .case(completion_factor, Motion::Full)
                 .expect("should find a case")
             })
             .flatten();
-        let trapezoid = HealthTrapezoid { side: self.side };
+        let trapezoid = HealthTrapezoid {
+            side: self.side,
+            theme: self.theme,
+        };
 
         trapezoid.render(()).into_iter().chain(hearts).collect()
     }
@@ -141,11 +150,12 @@ struct HeartPosition {
 }
 
 #[derive(Debug, Clone, Copy)]
-struct HealthTrapezoid {
+struct HealthTrapezoid<'a> {
     side: Side,
+    theme: &'a Theme,
 }
 
-impl Render<()> for HealthTrapezoid {
+impl<'a> Render<()> for HealthTrapezoid<'a> {
     fn render(&self, _: ()) -> Vec<Component> {
         let dx = match self.side {
             Side::Left => 20.0,
@@ -162,9 +172,9 @@ impl Render<()> for HealthTrapezoid {
                     PathCommand::ArcTo(440.0, 0.0, 435.0, 0.0, 3.0),
                 ],
             },
-            fill_color: Some(colors::TRAPEZOID_FILL),
+            fill_color: Some(self.theme.trapezoid_fill),
             stroke: Some(Stroke {
-                color: colors::TRAPEZOID_OUTCOME_SCREEN_BORDER,
+                color: self.theme.trapezoid_outcome_screen_border,
                 width: colors::TRAPEZOID_BORDER_WIDTH,
             }),
         }
@@ -0,0 +1,94 @@
+use crate::analysis::Choice;
+use crate::canvas_dimensions;
+use crate::click::Action;
+use crate::description;
+use crate::focus;
+use crate::hint::Hint;
+use crate::i18n::Messages;
+use crate::paint::{Component, Path, Stroke};
+use crate::render::{self, move_inspector_buttons::HintButton, Render};
+use crate::shapes::Rect;
+use crate::text::TextAlign;
+use crate::theme::Theme;
+
+pub struct HintOverlay<'a> {
+    pub hint: Option<&'a Hint>,
+    pub remaining: u8,
+    pub theme: &'a Theme,
+    pub messages: Messages,
+}
+
+impl<'a> HintOverlay<'a> {
+    pub fn is_choosing(components: &[Component]) -> bool {
+        components.iter().any(|component| {
+            matches!(
+                component.on_click(),
+                Some(Action::ChooseDequeue(_)) | Some(Action::ChooseAction(_))
+            )
+        })
+    }
+
+    fn highlights(&self, hint: &Hint, components: &[Component]) -> Vec<Component> {
+        let hinted_action = match hint.choice {
+            Choice::Dequeue(dequeue) => Action::ChooseDequeue(dequeue),
+            Choice::Action(action) => Action::ChooseAction(action),
+        };
+        let rings = focus::focusables(components)
+            .into_iter()
+            .filter(|focusable| focusable.action == hinted_action)
+            .map(|focusable| {
+                let bounds = &focusable.bounds;
+
+                Component::UnclickablePath {
+                    path: Path::rounded_rect(
+                        &Rect {
+                            x: bounds.x - RING_PADDING,
+                            y: bounds.y - RING_PADDING,
+                            width: bounds.width + 2.0 * RING_PADDING,
+                            height: bounds.height + 2.0 * RING_PADDING,
+                        },
+                        RING_CORNER_RADIUS,
+                    ),
+                    fill_color: None,
+                    stroke: Some(Stroke {
+                        color: self.theme.inspector_victory,
+                        width: RING_WIDTH,
+                    }),
+                }
+            });
+        let reason = render::aligned_label(
+            description::describe_hint(hint, self.messages),
+            (canvas_dimensions::CENTER_X, HintButton::REASON_Y),
+            REASON_FONT_SIZE,
+            TextAlign::Center,
+            self.theme.screen_text,
+            Some(REASON_MAX_WIDTH),
+        );
+
+        rings.chain(vec![reason]).collect()
+    }
+}
+
+impl<'a> Render<&'a [Component]> for HintOverlay<'a> {
+    fn render(&self, components: &'a [Component]) -> Vec<Component> {
+        let button = HintButton {
+            remaining: self.remaining,
+            enabled: self.remaining > 0 && self.hint.is_none(),
+        }
+        .render((self.theme, self.messages));
+
+        match self.hint {
+            Some(hint) => button
+                .into_iter()
+                .chain(self.highlights(hint, components))
+                .collect(),
+            None => button,
+        }
+    }
+}
+
+const RING_PADDING: f64 = 10.0;
+const RING_CORNER_RADIUS: f64 = 16.0;
+const RING_WIDTH: f64 = 8.0;
+const REASON_FONT_SIZE: f64 = 26.0;
+const REASON_MAX_WIDTH: f64 = 560.0;
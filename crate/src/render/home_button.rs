@@ -1,15 +1,15 @@
 use crate::canvas_dimensions;
 use crate::click::Action;
-use crate::colors;
 use crate::paint::{Component, Path, PathCommand};
 use crate::render::lerp::Lerper;
 use crate::shapes::{Circle, Rect};
+use crate::theme::Theme;
 use crate::transform::Translate;
 
-pub fn home_button(lerper: &Lerper) -> Vec<Component> {
+pub fn home_button(lerper: &Lerper, theme: &Theme) -> Vec<Component> {
     vec![
         Component::Circle {
-            fill_color: colors::HOME_BUTTON_BACKGROUND,
+            fill_color: theme.home_button_background,
             shape: Circle {
                 x: CENTER_X,
                 y: CENTER_Y,
@@ -29,11 +29,11 @@ pub fn home_button(lerper: &Lerper) -> Vec<Component> {
                     PathCommand::LineTo(980.0, 490.0),
                 ],
             },
-            fill_color: Some(colors::HOME_BUTTON_FOREGROUND),
+            fill_color: Some(theme.home_button_foreground),
             stroke: None,
         },
         Component::Circle {
-            fill_color: colors::HOME_BUTTON_BACKGROUND,
+            fill_color: theme.home_button_background,
             shape: Circle {
                 x: 900.0,
                 y: 520.0,
@@ -42,7 +42,7 @@ pub fn home_button(lerper: &Lerper) -> Vec<Component> {
             on_click: None,
         },
         Component::Rect {
-            fill_color: colors::HOME_BUTTON_BACKGROUND,
+            fill_color: theme.home_button_background,
             shape: Rect {
                 x: 885.0,
                 y: 520.0,
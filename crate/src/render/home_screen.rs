@@ -1,12 +1,12 @@
 use crate::click::Action;
-use crate::colors;
 use crate::paint::{Component, ImageType};
-use crate::shapes::Rect;
+use crate::shapes::{Circle, Rect};
+use crate::theme::Theme;
 
-pub fn home_screen() -> Vec<Component> {
+pub fn home_screen(theme: &Theme) -> Vec<Component> {
     vec![
         Component::Background {
-            color: colors::HOME_SCREEN_BACKGROUND,
+            color: theme.home_screen_background,
         },
         Component::Image {
             image_type: ImageType::Homescreen,
@@ -53,4 +53,104 @@ pub fn home_screen() -> Vec<Component> {
             on_click: Some(Action::NavigateToSettingsScreen),
         },
     ]
+    .into_iter()
+    .chain(stats_button(theme))
+    .chain(encyclopedia_button(theme))
+    .chain(sandbox_button(theme))
+    .collect()
 }
+
+fn stats_button(theme: &Theme) -> Vec<Component> {
+    let background = Component::Circle {
+        fill_color: theme.home_button_background,
+        shape: Circle {
+            x: STATS_BUTTON_CENTER_X,
+            y: STATS_BUTTON_CENTER_Y,
+            radius: STATS_BUTTON_RADIUS,
+        },
+        on_click: Some(Action::NavigateToStatsScreen),
+    };
+    let bars = [0.5, 1.0, 0.75].iter().enumerate().map(|(i, height)| {
+        let height = height * STATS_BUTTON_GLYPH_SIZE;
+
+        Component::Rect {
+            fill_color: theme.home_button_foreground,
+            shape: Rect {
+                x: STATS_BUTTON_CENTER_X - 0.5 * STATS_BUTTON_GLYPH_SIZE
+                    + (STATS_BUTTON_GLYPH_SIZE / 3.0) * i as f64,
+                y: STATS_BUTTON_CENTER_Y + 0.5 * STATS_BUTTON_GLYPH_SIZE - height,
+                width: 0.8 * STATS_BUTTON_GLYPH_SIZE / 3.0,
+                height,
+            },
+            on_click: None,
+        }
+    });
+
+    vec![background].into_iter().chain(bars).collect()
+}
+
+fn encyclopedia_button(theme: &Theme) -> Vec<Component> {
+    let background = Component::Circle {
+        fill_color: theme.home_button_background,
+        shape: Circle {
+            x: ENCYCLOPEDIA_BUTTON_CENTER_X,
+            y: STATS_BUTTON_CENTER_Y,
+            radius: STATS_BUTTON_RADIUS,
+        },
+        on_click: Some(Action::NavigateToEncyclopedia),
+    };
+    let pages = [-1.0, 1.0].iter().map(|direction| {
+        let page_width = 0.5 * (STATS_BUTTON_GLYPH_SIZE - BOOK_SPINE_WIDTH);
+
+        Component::Rect {
+            fill_color: theme.home_button_foreground,
+            shape: Rect {
+                x: ENCYCLOPEDIA_BUTTON_CENTER_X
+                    + if *direction < 0.0 {
+                        -0.5 * STATS_BUTTON_GLYPH_SIZE
+                    } else {
+                        0.5 * BOOK_SPINE_WIDTH
+                    },
+                y: STATS_BUTTON_CENTER_Y - 0.4 * STATS_BUTTON_GLYPH_SIZE,
+                width: page_width,
+                height: 0.8 * STATS_BUTTON_GLYPH_SIZE,
+            },
+            on_click: None,
+        }
+    });
+
+    vec![background].into_iter().chain(pages).collect()
+}
+
+fn sandbox_button(theme: &Theme) -> Vec<Component> {
+    let background = Component::Circle {
+        fill_color: theme.home_button_background,
+        shape: Circle {
+            x: SANDBOX_BUTTON_CENTER_X,
+            y: STATS_BUTTON_CENTER_Y,
+            radius: STATS_BUTTON_RADIUS,
+        },
+        on_click: Some(Action::StartSandbox),
+    };
+    let players = [-1.0, 1.0].iter().map(|direction| Component::Circle {
+        fill_color: theme.home_button_foreground,
+        shape: Circle {
+            x: SANDBOX_BUTTON_CENTER_X + direction * SANDBOX_PLAYER_OFFSET,
+            y: STATS_BUTTON_CENTER_Y,
+            radius: SANDBOX_PLAYER_RADIUS,
+        },
+        on_click: None,
+    });
+
+    vec![background].into_iter().chain(players).collect()
+}
+
+const STATS_BUTTON_RADIUS: f64 = 40.0;
+const STATS_BUTTON_CENTER_X: f64 = 1800.0 - 30.0 - STATS_BUTTON_RADIUS;
+const STATS_BUTTON_CENTER_Y: f64 = 30.0 + STATS_BUTTON_RADIUS;
+const STATS_BUTTON_GLYPH_SIZE: f64 = 40.0;
+const ENCYCLOPEDIA_BUTTON_CENTER_X: f64 = STATS_BUTTON_CENTER_X - 3.0 * STATS_BUTTON_RADIUS;
+const BOOK_SPINE_WIDTH: f64 = 4.0;
+const SANDBOX_BUTTON_CENTER_X: f64 = ENCYCLOPEDIA_BUTTON_CENTER_X - 3.0 * STATS_BUTTON_RADIUS;
+const SANDBOX_PLAYER_OFFSET: f64 = 10.0;
+const SANDBOX_PLAYER_RADIUS: f64 = 9.0;
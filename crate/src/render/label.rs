@@ -0,0 +1,40 @@
+use crate::canvas_dimensions;
+use crate::colors::Rgba;
+use crate::paint::Component;
+use crate::text::{Font, FontFamily, FontWeight, TextAlign};
+
+pub fn label(text: String, anchor: (f64, f64), size: f64, color: Rgba) -> Component {
+    let max_width = canvas_dimensions::WIDTH - MARGIN - anchor.0;
+
+    aligned_label(text, anchor, size, TextAlign::Left, color, Some(max_width))
+}
+
+pub fn aligned_label(
+    text: String,
+    anchor: (f64, f64),
+    size: f64,
+    align: TextAlign,
+    color: Rgba,
+    max_width: Option<f64>,
+) -> Component {
+    Component::Text {
+        text,
+        font: label_font(size),
+        align,
+        color,
+        anchor,
+        max_width,
+        measured_width: None,
+        on_click: None,
+    }
+}
+
+pub fn label_font(size: f64) -> Font {
+    Font {
+        family: FontFamily::SansSerif,
+        weight: FontWeight::Bold,
+        size,
+    }
+}
+
+const MARGIN: f64 = 30.0;
@@ -3,6 +3,7 @@ use crate::{
     colors::Rgba,
     paint::{Component, ImageType},
     shapes::{Circle, Rect},
+    text::{Font, TextAlign},
 };
 
 use std::f64;
@@ -114,6 +115,17 @@ impl LerpInto<Circle> for (Circle, Circle) {
     }
 }
 
+impl LerpInto<Font> for (Font, Font) {
+    fn lerp_into(self, lerper: &Lerper) -> Font {
+        let (start, end) = self;
+
+        Font {
+            size: lerper.lerp(start.size, end.size),
+            ..start
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum LerpableComponent {
     Rect {
@@ -138,6 +150,18 @@ pub enum LerpableComponent {
         end_shape: Rect,
         on_click: Option<Action>,
     },
+    Text {
+        text: String,
+        align: TextAlign,
+        start_font: Font,
+        end_font: Font,
+        start_color: Rgba,
+        end_color: Rgba,
+        start_anchor: (f64, f64),
+        end_anchor: (f64, f64),
+        max_width: Option<f64>,
+        on_click: Option<Action>,
+    },
 }
 
 impl LerpInto<Component> for LerpableComponent {
@@ -180,6 +204,94 @@ impl LerpInto<Component> for LerpableComponent {
                 shape: lerper.lerp(start_shape, end_shape),
                 on_click,
             },
+
+            LerpableComponent::Text {
+                text,
+                align,
+                start_font,
+                end_font,
+                start_color,
+                end_color,
+                start_anchor,
+                end_anchor,
+                max_width,
+                on_click,
+            } => Component::Text {
+                text,
+                font: lerper.lerp(start_font, end_font),
+                align,
+                color: lerper.lerp(start_color, end_color),
+                anchor: (
+                    lerper.lerp(start_anchor.0, end_anchor.0),
+                    lerper.lerp(start_anchor.1, end_anchor.1),
+                ),
+                max_width,
+                measured_width: None,
+                on_click,
+            },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::{FontFamily, FontWeight};
+
+    fn font(size: f64) -> Font {
+        Font {
+            family: FontFamily::SansSerif,
+            weight: FontWeight::Bold,
+            size,
+        }
+    }
+
+    fn lerped_text(completion_factor: f64) -> Component {
+        Lerper::from_completion_factor(completion_factor).lerp1(LerpableComponent::Text {
+            text: "5 - 3".to_string(),
+            align: TextAlign::Center,
+            start_font: font(0.0),
+            end_font: font(100.0),
+            start_color: Rgba(0, 0, 0, 0),
+            end_color: Rgba(200, 100, 50, 250),
+            start_anchor: (0.0, 400.0),
+            end_anchor: (200.0, 0.0),
+            max_width: Some(300.0),
+            on_click: None,
+        })
+    }
+
+    fn text_parts(component: Component) -> (Font, Rgba, (f64, f64)) {
+        match component {
+            Component::Text {
+                font,
+                color,
+                anchor,
+                max_width,
+                measured_width,
+                ..
+            } => {
+                assert_eq!(Some(300.0), max_width);
+                assert_eq!(None, measured_width);
+                (font, color, anchor)
+            }
+            other => panic!("expected text, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn text_lerps_size_color_and_position() {
+        assert_eq!(
+            (font(0.0), Rgba(0, 0, 0, 0), (0.0, 400.0)),
+            text_parts(lerped_text(0.0))
+        );
+        assert_eq!(
+            (font(50.0), Rgba(100, 50, 25, 125), (100.0, 200.0)),
+            text_parts(lerped_text(0.5))
+        );
+        assert_eq!(
+            (font(100.0), Rgba(200, 100, 50, 250), (200.0, 0.0)),
+            text_parts(lerped_text(1.0))
+        );
+    }
+}
@@ -0,0 +1,461 @@
+use crate::canvas_dimensions;
+use crate::click::Action;
+use crate::colors::Rgba;
+use crate::context::Context;
+use crate::encyclopedia::{self, Interaction, MoveSource};
+use crate::i18n::Messages;
+use crate::matchup_matrix::{self, MatrixView};
+use crate::paint::{Component, ImageType, Path, Stroke};
+use crate::render::{self, encyclopedia_screen::interaction_name, lerp::Lerper};
+use crate::shapes::Rect;
+use crate::text::TextAlign;
+use crate::theme::Theme;
+use crate::transform::{Scale, Translate};
+
+use nzscq::choices::Move;
+
+use std::ops::Range;
+
+pub fn matchup_matrix_screen(view: &MatrixView, context: &Context) -> Vec<Component> {
+    let theme = context.theme();
+    let messages = context.messages();
+
+    vec![
+        vec![Component::Background {
+            color: theme.settings_screen_background,
+        }],
+        cells(view, theme),
+        masks(view, theme),
+        headers(view, theme),
+        render::home_button(&Lerper::from_completion_factor(1.0), theme)
+            .translate(-canvas_dimensions::CENTER_X, -canvas_dimensions::CENTER_Y)
+            .scale(TARGET_RADIUS / ORIGINAL_RADIUS)
+            .translate(MARGIN + TARGET_RADIUS, MARGIN + TARGET_RADIUS),
+        filter_chips(
+            messages.text("matrix.rows"),
+            ROW_FILTER_Y,
+            view.row_filter,
+            Action::SetMatrixRowFilter,
+            theme,
+            messages,
+        ),
+        filter_chips(
+            messages.text("matrix.columns"),
+            COLUMN_FILTER_Y,
+            view.column_filter,
+            Action::SetMatrixColumnFilter,
+            theme,
+            messages,
+        ),
+        render::text_button(
+            messages.text("action.encyclopedia"),
+            Rect {
+                x: PANEL_X,
+                y: MARGIN,
+                width: PANEL_WIDTH,
+                height: BUTTON_HEIGHT,
+            },
+            Some(Action::NavigateToEncyclopedia),
+            theme,
+        ),
+        match view.selected_cell {
+            Some((row, column)) => comparison(row, column, theme, messages),
+            None => vec![render::label(
+                messages.text("matrix.hint"),
+                (PANEL_X, COMPARISON_Y),
+                LABEL_FONT_SIZE,
+                theme.screen_text,
+            )],
+        },
+        legend(theme, messages),
+        zoom_buttons(theme, messages),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn cells(view: &MatrixView, theme: &Theme) -> Vec<Component> {
+    let rows = view.rows();
+    let columns = view.columns();
+    let viewport = &view.viewport;
+    let unzoomed_cell_size = view.cell_size();
+    let cell_size = unzoomed_cell_size * viewport.zoom;
+    let visible_rows = visible_range(rows.len(), viewport.pan.1, viewport.frame.height, cell_size);
+    let visible_columns = visible_range(
+        columns.len(),
+        viewport.pan.0,
+        viewport.frame.width,
+        cell_size,
+    );
+    let mut components = vec![];
+
+    for i in visible_rows {
+        for j in visible_columns.clone() {
+            let (row, column) = (rows[i], columns[j]);
+            let (x, y) =
+                viewport.to_canvas((unzoomed_cell_size * j as f64, unzoomed_cell_size * i as f64));
+            let shape = Rect {
+                x,
+                y,
+                width: cell_size - CELL_GAP,
+                height: cell_size - CELL_GAP,
+            };
+
+            components.push(Component::Rect {
+                fill_color: interaction_color(
+                    Interaction::from(matchup_matrix::cell_points(row, column)),
+                    theme,
+                ),
+                shape: shape.clone(),
+                on_click: Some(Action::SelectMatrixCell(row, column)),
+            });
+            if view.selected_cell == Some((row, column)) {
+                components.push(Component::UnclickablePath {
+                    path: Path::rounded_rect(&shape, 0.0),
+                    fill_color: None,
+                    stroke: Some(Stroke {
+                        color: theme.screen_text,
+                        width: SELECTION_STROKE_WIDTH,
+                    }),
+                });
+            }
+        }
+    }
+
+    components
+}
+
+fn masks(view: &MatrixView, theme: &Theme) -> Vec<Component> {
+    let frame = &view.viewport.frame;
+    let right = frame.x + frame.width;
+    let bottom = frame.y + frame.height;
+
+    vec![
+        Rect {
+            x: 0.0,
+            y: 0.0,
+            width: canvas_dimensions::WIDTH,
+            height: frame.y,
+        },
+        Rect {
+            x: 0.0,
+            y: bottom,
+            width: canvas_dimensions::WIDTH,
+            height: canvas_dimensions::HEIGHT - bottom,
+        },
+        Rect {
+            x: 0.0,
+            y: frame.y,
+            width: frame.x,
+            height: frame.height,
+        },
+        Rect {
+            x: right,
+            y: frame.y,
+            width: canvas_dimensions::WIDTH - right,
+            height: frame.height,
+        },
+    ]
+    .into_iter()
+    .map(|shape| Component::Rect {
+        fill_color: theme.settings_screen_background,
+        shape,
+        on_click: Some(Action::StopPropagation),
+    })
+    .collect()
+}
+
+fn headers(view: &MatrixView, theme: &Theme) -> Vec<Component> {
+    let viewport = &view.viewport;
+    let frame = &viewport.frame;
+    let unzoomed_cell_size = view.cell_size();
+    let cell_size = unzoomed_cell_size * viewport.zoom;
+    let icon_size = (cell_size - CELL_GAP).min(HEADER_ICON_SIZE);
+
+    let row_headers = view.rows().into_iter().enumerate().filter_map(|(i, m)| {
+        let (_, y) = viewport.to_canvas((0.0, unzoomed_cell_size * i as f64));
+        if y < frame.y || y + icon_size > frame.y + frame.height {
+            return None;
+        }
+        Some(header_icon(
+            m,
+            frame.x - HEADER_GAP - icon_size,
+            y,
+            icon_size,
+            theme,
+        ))
+    });
+    let column_headers = view.columns().into_iter().enumerate().filter_map(|(j, m)| {
+        let (x, _) = viewport.to_canvas((unzoomed_cell_size * j as f64, 0.0));
+        if x < frame.x || x + icon_size > frame.x + frame.width {
+            return None;
+        }
+        Some(header_icon(
+            m,
+            x,
+            frame.y - HEADER_GAP - icon_size,
+            icon_size,
+            theme,
+        ))
+    });
+
+    row_headers.chain(column_headers).flatten().collect()
+}
+
+fn header_icon(m: Move, x: f64, y: f64, size: f64, theme: &Theme) -> Vec<Component> {
+    let shape = Rect {
+        x,
+        y,
+        width: size,
+        height: size,
+    };
+
+    vec![
+        Component::Rect {
+            fill_color: theme.move_color(m),
+            shape: shape.clone(),
+            on_click: None,
+        },
+        Component::Image {
+            image_type: ImageType::Move(m),
+            alpha: 1.0,
+            shape,
+            on_click: None,
+        },
+    ]
+}
+
+fn filter_chips(
+    label: String,
+    y: f64,
+    selected: Option<MoveSource>,
+    to_action: fn(Option<MoveSource>) -> Action,
+    theme: &Theme,
+    messages: Messages,
+) -> Vec<Component> {
+    let sources = std::iter::once(None).chain(
+        encyclopedia::move_groups()
+            .into_iter()
+            .map(|group| Some(group.source)),
+    );
+    let chips = sources.enumerate().flat_map(|(i, source)| {
+        let shape = Rect {
+            x: CHIPS_X + (CHIP_SIZE + CHIP_GAP) * i as f64,
+            y,
+            width: CHIP_SIZE,
+            height: CHIP_SIZE,
+        };
+        let background = Component::Rect {
+            fill_color: if source == selected {
+                theme.stats_bar_fill
+            } else {
+                theme.stats_bar_track
+            },
+            shape: shape.clone(),
+            on_click: Some(to_action(source)),
+        };
+        let foreground = match source {
+            Some(source) => Component::Image {
+                image_type: ImageType::Move(source.logo_move()),
+                alpha: 1.0,
+                shape: Rect {
+                    x: shape.x + CHIP_PADDING,
+                    y: shape.y + CHIP_PADDING,
+                    width: CHIP_SIZE - 2.0 * CHIP_PADDING,
+                    height: CHIP_SIZE - 2.0 * CHIP_PADDING,
+                },
+                on_click: None,
+            },
+            None => render::aligned_label(
+                messages.text("matrix.all"),
+                (shape.x + 0.5 * CHIP_SIZE, shape.y + 0.5 * CHIP_SIZE),
+                CHIP_FONT_SIZE,
+                TextAlign::Center,
+                theme.screen_text,
+                Some(CHIP_SIZE - 2.0 * CHIP_PADDING),
+            ),
+        };
+
+        vec![background, foreground]
+    });
+
+    std::iter::once(render::label(
+        label,
+        (FILTER_LABEL_X, y + 0.5 * CHIP_SIZE),
+        LABEL_FONT_SIZE,
+        theme.screen_text,
+    ))
+    .chain(chips)
+    .collect()
+}
+
+fn comparison(row: Move, column: Move, theme: &Theme, messages: Messages) -> Vec<Component> {
+    let points = matchup_matrix::cell_points(row, column);
+    let interaction = Interaction::from(points);
+    let column_x = PANEL_X + PANEL_WIDTH - COMPARISON_ICON_SIZE;
+    let names_y = COMPARISON_Y + COMPARISON_ICON_SIZE + LABEL_HEIGHT;
+    let points_y = names_y + LABEL_HEIGHT;
+
+    vec![
+        header_icon(row, PANEL_X, COMPARISON_Y, COMPARISON_ICON_SIZE, theme),
+        header_icon(column, column_x, COMPARISON_Y, COMPARISON_ICON_SIZE, theme),
+        vec![
+            render::aligned_label(
+                messages.text("matrix.versus"),
+                (
+                    PANEL_X + 0.5 * PANEL_WIDTH,
+                    COMPARISON_Y + 0.5 * COMPARISON_ICON_SIZE,
+                ),
+                HEADING_FONT_SIZE,
+                TextAlign::Center,
+                theme.screen_text,
+                Some(PANEL_WIDTH - 2.0 * COMPARISON_ICON_SIZE),
+            ),
+            render::label(
+                messages.move_name(row),
+                (PANEL_X, names_y),
+                LABEL_FONT_SIZE,
+                theme.screen_text,
+            ),
+            render::aligned_label(
+                messages.move_name(column),
+                (PANEL_X + PANEL_WIDTH, names_y),
+                LABEL_FONT_SIZE,
+                TextAlign::Right,
+                theme.screen_text,
+                Some(0.5 * PANEL_WIDTH),
+            ),
+            render::label(
+                messages.format("matrix.points", &[&points[0], &points[1]]),
+                (PANEL_X, points_y),
+                HEADING_FONT_SIZE,
+                interaction_color(interaction, theme),
+            ),
+            render::label(
+                messages.format(
+                    "matrix.row_result",
+                    &[
+                        &messages.move_name(row),
+                        &interaction_name(interaction, messages),
+                    ],
+                ),
+                (PANEL_X, points_y + LABEL_HEIGHT + 0.5 * HEADING_FONT_SIZE),
+                LABEL_FONT_SIZE,
+                theme.screen_text,
+            ),
+        ],
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn legend(theme: &Theme, messages: Messages) -> Vec<Component> {
+    vec![
+        (Interaction::Win, "matrix.legend_win"),
+        (Interaction::Loss, "matrix.legend_loss"),
+        (Interaction::Trade, "matrix.legend_trade"),
+        (Interaction::Neutral, "matrix.legend_neutral"),
+    ]
+    .into_iter()
+    .enumerate()
+    .flat_map(|(i, (interaction, key))| {
+        let y = LEGEND_Y + LABEL_HEIGHT * i as f64;
+
+        vec![
+            Component::Rect {
+                fill_color: interaction_color(interaction, theme),
+                shape: Rect {
+                    x: PANEL_X,
+                    y,
+                    width: LEGEND_SWATCH_SIZE,
+                    height: LEGEND_SWATCH_SIZE,
+                },
+                on_click: None,
+            },
+            render::label(
+                messages.text(key),
+                (
+                    PANEL_X + LEGEND_SWATCH_SIZE + CHIP_GAP,
+                    y + 0.5 * LEGEND_SWATCH_SIZE,
+                ),
+                LABEL_FONT_SIZE,
+                theme.screen_text,
+            ),
+        ]
+    })
+    .collect()
+}
+
+fn zoom_buttons(theme: &Theme, messages: Messages) -> Vec<Component> {
+    let width = (PANEL_WIDTH - 2.0 * CHIP_GAP) / 3.0;
+
+    vec![
+        ("+".to_string(), Action::ZoomMatrixIn),
+        ("\u{2212}".to_string(), Action::ZoomMatrixOut),
+        (messages.text("button.reset_zoom"), Action::ResetMatrixView),
+    ]
+    .into_iter()
+    .enumerate()
+    .flat_map(|(i, (label, action))| {
+        render::text_button(
+            label,
+            Rect {
+                x: PANEL_X + (width + CHIP_GAP) * i as f64,
+                y: ZOOM_BUTTONS_Y,
+                width,
+                height: BUTTON_HEIGHT,
+            },
+            Some(action),
+            theme,
+        )
+    })
+    .collect()
+}
+
+fn visible_range(len: usize, pan: f64, frame_length: f64, cell_size: f64) -> Range<usize> {
+    let start = (pan / cell_size).floor().max(0.0) as usize;
+    let end = ((pan + frame_length) / cell_size).ceil() as usize;
+
+    start.min(len)..end.min(len)
+}
+
+fn interaction_color(interaction: Interaction, theme: &Theme) -> Rgba {
+    match interaction {
+        Interaction::Win => theme.inspector_victory,
+        Interaction::Loss => theme.inspector_defeat,
+        Interaction::Trade => theme.inspector_special_tie,
+        Interaction::Neutral => theme.stats_bar_track,
+    }
+}
+
+const ORIGINAL_RADIUS: f64 = 120.0;
+const MARGIN: f64 = 30.0;
+const TARGET_RADIUS: f64 = 40.0;
+
+const FILTER_LABEL_X: f64 = 140.0;
+const CHIPS_X: f64 = 300.0;
+const ROW_FILTER_Y: f64 = 30.0;
+const COLUMN_FILTER_Y: f64 = 100.0;
+const CHIP_SIZE: f64 = 56.0;
+const CHIP_GAP: f64 = 12.0;
+const CHIP_PADDING: f64 = 6.0;
+const CHIP_FONT_SIZE: f64 = 20.0;
+
+const CELL_GAP: f64 = 1.0;
+const HEADER_GAP: f64 = 4.0;
+const HEADER_ICON_SIZE: f64 = 56.0;
+const SELECTION_STROKE_WIDTH: f64 = 3.0;
+
+const PANEL_X: f64 = 1260.0;
+const PANEL_WIDTH: f64 = 510.0;
+const BUTTON_HEIGHT: f64 = 90.0;
+const COMPARISON_Y: f64 = 260.0;
+const COMPARISON_ICON_SIZE: f64 = 160.0;
+const HEADING_FONT_SIZE: f64 = 36.0;
+const LABEL_FONT_SIZE: f64 = 24.0;
+const LABEL_HEIGHT: f64 = 40.0;
+const LEGEND_Y: f64 = 660.0;
+const LEGEND_SWATCH_SIZE: f64 = 28.0;
+const ZOOM_BUTTONS_Y: f64 = 880.0;
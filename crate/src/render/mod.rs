@@ -4,17 +4,43 @@ pub use arsenal_item_display::arsenal_item_display;
 mod health_display;
 mod home_button;
 pub use home_button::home_button;
+mod label;
+pub use label::{aligned_label, label, label_font};
 mod lerp;
 pub mod phase_renderers;
 mod pill;
 mod switch;
+mod text_button;
+pub use switch::Motion;
+pub use text_button::text_button;
 
+mod hint;
+pub use hint::HintOverlay;
 mod home_screen;
 pub use home_screen::home_screen;
 mod settings_screen;
 pub use settings_screen::settings_screen;
+mod stats_screen;
+pub use stats_screen::stats_screen;
+mod encyclopedia_screen;
+pub use encyclopedia_screen::encyclopedia_screen;
+mod matchup_matrix_screen;
+pub use matchup_matrix_screen::matchup_matrix_screen;
+mod analysis_panel;
+pub use analysis_panel::AnalysisPanel;
+mod game_summary;
+pub use game_summary::analyze_button;
 mod move_inspector;
 pub mod move_inspector_buttons;
+mod queue_forecast;
+mod round_log;
+pub use round_log::is_over_round_log;
+mod replay_button;
+pub use replay_button::replay_button;
+mod replay_controls;
+pub use replay_controls::ReplayControls;
+mod sandbox_controls;
+pub use sandbox_controls::{undo_button, SandboxControls};
 
 use crate::paint::Component;
 
@@ -1,5 +1,5 @@
 use crate::click::Action;
-use crate::colors::{self, Rgba};
+use crate::colors::Rgba;
 use crate::helpers::{self, QueueArsenal};
 use crate::paint::{Component, ImageType};
 use crate::render::{arsenal_item_display, pill::Pill, Render};
@@ -8,6 +8,7 @@ use crate::shapes::{
     move_inspector_highlighter,
 };
 use crate::side::Side;
+use crate::theme::Theme;
 
 use nzscq::choices::{ArsenalItem, Move, PointsAgainst};
 use nzscq::scoreboard::Queue;
@@ -17,6 +18,7 @@ pub struct MoveInspector<'a> {
     queue: &'a Queue,
     arsenal: &'a Vec<ArsenalItem>,
     inspected_move: Option<Move>,
+    theme: &'a Theme,
 }
 
 impl<'a> MoveInspector<'a> {
@@ -26,6 +28,7 @@ impl<'a> MoveInspector<'a> {
             queue: args.player.queue(),
             arsenal: args.player.arsenal(),
             inspected_move: args.inspected_move,
+            theme: args.theme,
         }
     }
 
@@ -39,6 +42,7 @@ impl<'a> MoveInspector<'a> {
             width_in_columns: 3,
             height_in_rows: helpers::height_in_rows(&self.queue.pool, 3),
             enabled: true,
+            theme: self.theme,
         };
 
         let pool = self
@@ -62,6 +66,7 @@ impl<'a> MoveInspector<'a> {
                         true,
                         inspection_handler(arsenal_item),
                         position,
+                        self.theme,
                     ))
             });
 
@@ -83,6 +88,7 @@ impl<'a> MoveInspector<'a> {
             width_in_columns: 3,
             height_in_rows: 1,
             enabled: true,
+            theme: self.theme,
         };
 
         vec![
@@ -101,6 +107,7 @@ impl<'a> MoveInspector<'a> {
                         true,
                         inspection_handler(entering_item),
                         position,
+                        self.theme,
                     ))
                     .collect()
             }),
@@ -118,6 +125,7 @@ impl<'a> MoveInspector<'a> {
                         true,
                         inspection_handler(exiting_item),
                         position,
+                        self.theme,
                     ))
                     .collect()
             }),
@@ -140,6 +148,7 @@ impl<'a> MoveInspector<'a> {
             width_in_columns: 3,
             height_in_rows: helpers::height_in_rows(&self.arsenal, 3).max(1),
             enabled: true,
+            theme: self.theme,
         };
         let arsenal_items = self
             .arsenal
@@ -162,6 +171,7 @@ impl<'a> MoveInspector<'a> {
                         true,
                         inspection_handler(arsenal_item),
                         position,
+                        self.theme,
                     ))
             });
 
@@ -187,11 +197,12 @@ impl<'a> MoveInspector<'a> {
 
     fn highlighter_color(&self, opposing_move: Move) -> Option<Rgba> {
         if self.inspected_move == Some(opposing_move) {
-            Some(colors::INSPECTED_MOVE_HIGHLIGHT_COLOR)
+            Some(self.theme.inspected_move_highlight)
         } else if let Some(inspected_move) = self.inspected_move {
             let points = PointsAgainst::points_of(&[inspected_move, opposing_move]);
 
-            colors::move_inspector_highlighter_color(points[0], points[1])
+            self.theme
+                .move_inspector_highlighter_color(points[0], points[1])
         } else {
             None
         }
@@ -215,6 +226,7 @@ pub struct MoveInspectorArgs<'a> {
     pub side: Side,
     pub player: &'a QueueArsenal,
     pub inspected_move: Option<Move>,
+    pub theme: &'a Theme,
 }
 
 fn inspection_handler(arsenal_item: ArsenalItem) -> Option<Action> {
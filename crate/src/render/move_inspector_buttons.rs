@@ -1,9 +1,12 @@
 use crate::canvas_dimensions;
 use crate::click::Action;
-use crate::paint::{Component, ImageType};
+use crate::i18n::Messages;
+use crate::paint::Component;
 use crate::phase::MoveInspectorState;
-use crate::render::Render;
+use crate::render::{self, Render};
 use crate::shapes::{dequeue_circle, Rect};
+use crate::side::Side;
+use crate::theme::Theme;
 
 pub struct InspectMoveButton {
     pub enabled: bool,
@@ -15,25 +18,25 @@ impl InspectMoveButton {
     const HEIGHT: f64 = 180.0;
 }
 
-impl Render<()> for InspectMoveButton {
-    fn render(&self, _: ()) -> Vec<Component> {
+impl<'a> Render<(&'a Theme, Messages)> for InspectMoveButton {
+    fn render(&self, (theme, messages): (&'a Theme, Messages)) -> Vec<Component> {
         let on_click = if self.enabled {
             Some(Action::WaitForUserToChooseMoveToInspect)
         } else {
             None
         };
 
-        vec![Component::Image {
-            image_type: ImageType::InspectMoveButton,
-            alpha: 1.0,
-            shape: Rect {
+        render::text_button(
+            messages.text("button.inspect_move"),
+            Rect {
                 x: canvas_dimensions::CENTER_X - 0.5 * Self::WIDTH,
                 y: Self::Y,
                 width: Self::WIDTH,
                 height: Self::HEIGHT,
             },
             on_click,
-        }]
+            theme,
+        )
     }
 }
 
@@ -47,38 +50,107 @@ impl StopInspectingButton {
     const HEIGHT: f64 = 180.0;
 }
 
-impl Render<()> for StopInspectingButton {
-    fn render(&self, _: ()) -> Vec<Component> {
+impl<'a> Render<(&'a Theme, Messages)> for StopInspectingButton {
+    fn render(&self, (theme, messages): (&'a Theme, Messages)) -> Vec<Component> {
         let on_click = if self.enabled {
             Some(Action::StopInspectingMove)
         } else {
             None
         };
 
-        vec![Component::Image {
-            image_type: ImageType::StopInspectingButton,
-            alpha: 1.0,
-            shape: Rect {
+        render::text_button(
+            messages.text("button.stop_inspecting"),
+            Rect {
                 x: canvas_dimensions::CENTER_X - 0.5 * Self::WIDTH,
                 y: Self::Y,
                 width: Self::WIDTH,
                 height: Self::HEIGHT,
             },
             on_click,
-        }]
+            theme,
+        )
+    }
+}
+
+pub struct ForecastQueueButton;
+
+impl ForecastQueueButton {
+    const Y: f64 = StopInspectingButton::Y + StopInspectingButton::HEIGHT + Self::MARGIN;
+    const WIDTH: f64 = 291.0;
+    const HEIGHT: f64 = 120.0;
+    const MARGIN: f64 = 40.0;
+}
+
+impl<'a> Render<(&'a Theme, Messages)> for ForecastQueueButton {
+    fn render(&self, (theme, messages): (&'a Theme, Messages)) -> Vec<Component> {
+        render::text_button(
+            messages.text("button.forecast_queue"),
+            Rect {
+                x: canvas_dimensions::CENTER_X - 0.5 * Self::WIDTH,
+                y: Self::Y,
+                width: Self::WIDTH,
+                height: Self::HEIGHT,
+            },
+            Some(Action::ForecastQueue(Side::Left)),
+            theme,
+        )
+    }
+}
+
+pub struct HintButton {
+    pub remaining: u8,
+    pub enabled: bool,
+}
+
+impl HintButton {
+    pub const REASON_Y: f64 = Self::Y + Self::HEIGHT + ForecastQueueButton::MARGIN;
+    const Y: f64 =
+        ForecastQueueButton::Y + ForecastQueueButton::HEIGHT + ForecastQueueButton::MARGIN;
+    const WIDTH: f64 = 291.0;
+    const HEIGHT: f64 = 120.0;
+    const DISABLED_OPACITY: f64 = 0.5;
+}
+
+impl<'a> Render<(&'a Theme, Messages)> for HintButton {
+    fn render(&self, (theme, messages): (&'a Theme, Messages)) -> Vec<Component> {
+        let on_click = if self.enabled {
+            Some(Action::ShowHint)
+        } else {
+            None
+        };
+        let button = render::text_button(
+            messages.format("button.hint", &[&self.remaining]),
+            Rect {
+                x: canvas_dimensions::CENTER_X - 0.5 * Self::WIDTH,
+                y: Self::Y,
+                width: Self::WIDTH,
+                height: Self::HEIGHT,
+            },
+            on_click,
+            theme,
+        );
+
+        if self.enabled {
+            button
+        } else {
+            button
+                .into_iter()
+                .map(|component| component.faded(Self::DISABLED_OPACITY))
+                .collect()
+        }
     }
 }
 
 pub trait RenderButton {
-    fn render_button(&self, enabled: bool) -> Vec<Component>;
+    fn render_button(&self, enabled: bool, theme: &Theme, messages: Messages) -> Vec<Component>;
 }
 
 impl RenderButton for MoveInspectorState {
-    fn render_button(&self, enabled: bool) -> Vec<Component> {
+    fn render_button(&self, enabled: bool, theme: &Theme, messages: Messages) -> Vec<Component> {
         if MoveInspectorState::NotInspecting == *self {
-            InspectMoveButton { enabled }.render(())
+            InspectMoveButton { enabled }.render((theme, messages))
         } else {
-            StopInspectingButton { enabled: true }.render(())
+            StopInspectingButton { enabled: true }.render((theme, messages))
         }
     }
 }
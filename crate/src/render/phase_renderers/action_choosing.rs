@@ -1,6 +1,7 @@
 use crate::{
     click::Action,
-    colors, helpers,
+    helpers,
+    i18n::Messages,
     paint::{Component, ImageType},
     phase::{ChooseActionPhase, MoveInspectorState},
     render::{
@@ -10,7 +11,7 @@ use crate::{
         move_inspector::{MoveInspector, MoveInspectorArgs},
         move_inspector_buttons::RenderButton,
         pill::Pill,
-        switch::{Switch, Switch5},
+        switch::{Motion, Switch, Switch5},
         Render,
     },
     shapes::{
@@ -18,6 +19,7 @@ use crate::{
         dequeue_foci,
     },
     side::Side,
+    theme::Theme,
 };
 
 use nzscq::{
@@ -32,10 +34,20 @@ pub struct ActionChoosingPhaseRenderer<'a> {
     scoreboard: &'a [ActionlessPlayer; 2],
     available_actions: &'a [Vec<NzscAction>; 2],
     inspector_state: MoveInspectorState,
+    theme: &'a Theme,
+    motion: Motion,
+    messages: Messages,
+    seat: Side,
 }
 
 impl<'a> ActionChoosingPhaseRenderer<'a> {
-    pub fn new(phase: &'a ChooseActionPhase) -> ActionChoosingPhaseRenderer<'a> {
+    pub fn new(
+        phase: &'a ChooseActionPhase,
+        theme: &'a Theme,
+        motion: Motion,
+        messages: Messages,
+        seat: Side,
+    ) -> ActionChoosingPhaseRenderer<'a> {
         ActionChoosingPhaseRenderer {
             previous_scoreboard: &phase.previous_scoreboard,
             previously_available_dequeues: &phase.previously_available_dequeues,
@@ -43,26 +55,37 @@ impl<'a> ActionChoosingPhaseRenderer<'a> {
             scoreboard: &phase.scoreboard,
             available_actions: &phase.available_actions,
             inspector_state: phase.inspector_state,
+            theme,
+            motion,
+            messages,
+            seat,
         }
     }
 
-    fn human_entrance(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn human_entrance(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             vec![
                 vec![Component::Background {
-                    color: colors::BACKGROUND,
+                    color: self.theme.background,
                 }],
                 self.health_displays(),
                 dequeueing_scoreboard_without_dequeued_items(
                     self.dequeueing_human_args(),
                     self.human_dequeue_displacements(),
+                    self.theme,
                 ),
-                dequeueing_scoreboard(self.dequeueing_computer_args()),
-                self.inspector_state.render_button(false),
+                dequeueing_scoreboard(self.dequeueing_computer_args(), self.theme),
+                self.inspector_state
+                    .render_button(false, self.theme, self.messages),
                 vec![Component::Background {
-                    color: colors::OVERLAY,
+                    color: self.theme.overlay,
                 }],
-                entering_dequeue_choice(Side::Left, self.human_dequeue_displacements(), &lerper),
+                entering_dequeue_choice(
+                    Side::Left,
+                    self.human_dequeue_displacements(),
+                    &lerper,
+                    self.theme,
+                ),
             ]
             .into_iter()
             .flatten()
@@ -99,30 +122,38 @@ impl<'a> ActionChoosingPhaseRenderer<'a> {
         DequeueDisplacements { drainee, exiter }
     }
 
-    fn computer_entrance(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn computer_entrance(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             vec![
                 vec![Component::Background {
-                    color: colors::BACKGROUND,
+                    color: self.theme.background,
                 }],
                 self.health_displays(),
                 dequeueing_scoreboard_without_dequeued_items(
                     self.dequeueing_human_args(),
                     self.human_dequeue_displacements(),
+                    self.theme,
                 ),
                 dequeueing_scoreboard_without_dequeued_items(
                     self.dequeueing_computer_args(),
                     self.computer_dequeue_displacements(),
+                    self.theme,
                 ),
-                self.inspector_state.render_button(false),
+                self.inspector_state
+                    .render_button(false, self.theme, self.messages),
                 vec![Component::Background {
-                    color: colors::OVERLAY,
+                    color: self.theme.overlay,
                 }],
-                stationary_dequeue_choice(Side::Left, self.human_dequeue_displacements()),
+                stationary_dequeue_choice(
+                    Side::Left,
+                    self.human_dequeue_displacements(),
+                    self.theme,
+                ),
                 entering_dequeue_choice(
                     Side::Right,
                     self.computer_dequeue_displacements(),
                     &lerper,
+                    self.theme,
                 ),
             ]
             .into_iter()
@@ -161,27 +192,38 @@ impl<'a> ActionChoosingPhaseRenderer<'a> {
         DequeueDisplacements { drainee, exiter }
     }
 
-    fn pause(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn pause(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |_lerper| {
             vec![
                 vec![Component::Background {
-                    color: colors::BACKGROUND,
+                    color: self.theme.background,
                 }],
                 self.health_displays(),
                 dequeueing_scoreboard_without_dequeued_items(
                     self.dequeueing_human_args(),
                     self.human_dequeue_displacements(),
+                    self.theme,
                 ),
                 dequeueing_scoreboard_without_dequeued_items(
                     self.dequeueing_computer_args(),
                     self.computer_dequeue_displacements(),
+                    self.theme,
                 ),
-                self.inspector_state.render_button(false),
+                self.inspector_state
+                    .render_button(false, self.theme, self.messages),
                 vec![Component::Background {
-                    color: colors::OVERLAY,
+                    color: self.theme.overlay,
                 }],
-                stationary_dequeue_choice(Side::Left, self.human_dequeue_displacements()),
-                stationary_dequeue_choice(Side::Right, self.computer_dequeue_displacements()),
+                stationary_dequeue_choice(
+                    Side::Left,
+                    self.human_dequeue_displacements(),
+                    self.theme,
+                ),
+                stationary_dequeue_choice(
+                    Side::Right,
+                    self.computer_dequeue_displacements(),
+                    self.theme,
+                ),
             ]
             .into_iter()
             .flatten()
@@ -189,27 +231,40 @@ impl<'a> ActionChoosingPhaseRenderer<'a> {
         }
     }
 
-    fn exit(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn exit(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             vec![
                 vec![Component::Background {
-                    color: colors::BACKGROUND,
+                    color: self.theme.background,
                 }],
                 self.health_displays(),
                 dequeueing_scoreboard_without_dequeued_items(
                     self.dequeueing_human_args(),
                     self.human_dequeue_displacements(),
+                    self.theme,
                 ),
                 dequeueing_scoreboard_without_dequeued_items(
                     self.dequeueing_computer_args(),
                     self.computer_dequeue_displacements(),
+                    self.theme,
                 ),
-                self.inspector_state.render_button(false),
+                self.inspector_state
+                    .render_button(false, self.theme, self.messages),
                 vec![Component::Background {
-                    color: colors::OVERLAY,
+                    color: self.theme.overlay,
                 }],
-                exiting_dequeue_choice(Side::Left, self.human_dequeue_displacements(), &lerper),
-                exiting_dequeue_choice(Side::Right, self.computer_dequeue_displacements(), &lerper),
+                exiting_dequeue_choice(
+                    Side::Left,
+                    self.human_dequeue_displacements(),
+                    &lerper,
+                    self.theme,
+                ),
+                exiting_dequeue_choice(
+                    Side::Right,
+                    self.computer_dequeue_displacements(),
+                    &lerper,
+                    self.theme,
+                ),
             ]
             .into_iter()
             .flatten()
@@ -217,7 +272,7 @@ impl<'a> ActionChoosingPhaseRenderer<'a> {
         }
     }
 
-    fn actions_or_inspector(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn actions_or_inspector(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |_| {
             if self.inspector_state == MoveInspectorState::NotInspecting {
                 self.actions()
@@ -230,12 +285,13 @@ impl<'a> ActionChoosingPhaseRenderer<'a> {
     fn actions(&self) -> Vec<Component> {
         vec![
             vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }],
             self.health_displays(),
-            action_choosing_scoreboard(self.actionless_human_args()),
-            action_choosing_scoreboard(self.actionless_computer_args()),
-            self.inspector_state.render_button(true),
+            action_choosing_scoreboard(self.actionless_human_args(), self.theme),
+            action_choosing_scoreboard(self.actionless_computer_args(), self.theme),
+            self.inspector_state
+                .render_button(true, self.theme, self.messages),
         ]
         .into_iter()
         .flatten()
@@ -247,6 +303,7 @@ impl<'a> ActionChoosingPhaseRenderer<'a> {
             player: &self.previous_scoreboard[HUMAN],
             side: Side::Left,
             dequeues: &self.previously_available_dequeues[HUMAN],
+            theme: self.theme,
         }
     }
 
@@ -255,6 +312,7 @@ impl<'a> ActionChoosingPhaseRenderer<'a> {
             player: &self.previous_scoreboard[COMPUTER],
             side: Side::Right,
             dequeues: &self.previously_available_dequeues[COMPUTER],
+            theme: self.theme,
         }
     }
 
@@ -262,7 +320,9 @@ impl<'a> ActionChoosingPhaseRenderer<'a> {
         ActionChoosingRenderArgs {
             player: &self.scoreboard[HUMAN],
             side: Side::Left,
+            seat: self.seat,
             actions: &self.available_actions[HUMAN],
+            theme: self.theme,
         }
     }
 
@@ -270,7 +330,9 @@ impl<'a> ActionChoosingPhaseRenderer<'a> {
         ActionChoosingRenderArgs {
             player: &self.scoreboard[COMPUTER],
             side: Side::Right,
+            seat: self.seat,
             actions: &self.available_actions[COMPUTER],
+            theme: self.theme,
         }
     }
 
@@ -278,10 +340,12 @@ impl<'a> ActionChoosingPhaseRenderer<'a> {
         let human_display = ConstantHealthDisplay {
             side: Side::Left,
             health: helpers::opponent_points_to_own_health(self.computer_points()),
+            theme: self.theme,
         };
         let computer_display = ConstantHealthDisplay {
             side: Side::Right,
             health: helpers::opponent_points_to_own_health(self.human_points()),
+            theme: self.theme,
         };
 
         vec![human_display, computer_display]
@@ -302,12 +366,13 @@ impl<'a> ActionChoosingPhaseRenderer<'a> {
     fn move_inspector(&self) -> Vec<Component> {
         vec![
             vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }],
             self.health_displays(),
             self.human_move_inspector(),
             self.computer_move_inspector(),
-            self.inspector_state.render_button(true),
+            self.inspector_state
+                .render_button(true, self.theme, self.messages),
         ]
         .into_iter()
         .flatten()
@@ -323,6 +388,7 @@ impl<'a> ActionChoosingPhaseRenderer<'a> {
             side: Side::Left,
             player: self.actionless_human_args().player,
             inspected_move: self.inspector_state.move_(),
+            theme: self.theme,
         }
     }
 
@@ -335,6 +401,7 @@ impl<'a> ActionChoosingPhaseRenderer<'a> {
             side: Side::Right,
             player: self.actionless_computer_args().player,
             inspected_move: self.inspector_state.move_(),
+            theme: self.theme,
         }
     }
 }
@@ -342,6 +409,7 @@ impl<'a> ActionChoosingPhaseRenderer<'a> {
 fn dequeueing_scoreboard_without_dequeued_items(
     args: DequeueingRenderArgs,
     displacements: DequeueDisplacements,
+    theme: &Theme,
 ) -> Vec<Component> {
     let drainee = displacements.drainee.map(|displacement| displacement.item);
 
@@ -349,19 +417,19 @@ fn dequeueing_scoreboard_without_dequeued_items(
         dequeueing_pool_display_without_drainee(&args, drainee),
         dequeueing_entrance_and_decline_display(&args),
         dequeueing_arsenal_display(&args),
-        arrows(&args),
+        arrows(&args, theme),
     ]
     .into_iter()
     .flatten()
     .collect()
 }
 
-fn dequeueing_scoreboard(args: DequeueingRenderArgs) -> Vec<Component> {
+fn dequeueing_scoreboard(args: DequeueingRenderArgs, theme: &Theme) -> Vec<Component> {
     vec![
         dequeueing_pool_display(&args),
         dequeueing_entrance_decline_and_exit_display(&args),
         dequeueing_arsenal_display(&args),
-        arrows(&args),
+        arrows(&args, theme),
     ]
     .into_iter()
     .flatten()
@@ -372,6 +440,7 @@ fn entering_dequeue_choice(
     side: Side,
     displacements: DequeueDisplacements,
     lerper: &Lerper,
+    theme: &Theme,
 ) -> Vec<Component> {
     vec![
         displacements.drainee.map(|displacement| {
@@ -380,8 +449,8 @@ fn entering_dequeue_choice(
 
             vec![
                 LerpableComponent::Circle {
-                    start_color: colors::arsenal_item_color(drainee),
-                    end_color: colors::arsenal_item_color(drainee),
+                    start_color: theme.arsenal_item_color(drainee),
+                    end_color: theme.arsenal_item_color(drainee),
                     start_shape: dequeue_circle::background_at(
                         position.side,
                         position.row,
@@ -410,8 +479,8 @@ fn entering_dequeue_choice(
 
             vec![
                 LerpableComponent::Circle {
-                    start_color: colors::arsenal_item_color(exiter),
-                    end_color: colors::arsenal_item_color(exiter),
+                    start_color: theme.arsenal_item_color(exiter),
+                    end_color: theme.arsenal_item_color(exiter),
                     start_shape: dequeue_circle::background_at(
                         position.side,
                         position.row,
@@ -442,14 +511,18 @@ fn entering_dequeue_choice(
     .collect()
 }
 
-fn stationary_dequeue_choice(side: Side, displacements: DequeueDisplacements) -> Vec<Component> {
+fn stationary_dequeue_choice(
+    side: Side,
+    displacements: DequeueDisplacements,
+    theme: &Theme,
+) -> Vec<Component> {
     vec![
         displacements.drainee.map(|displacement| {
             let drainee = displacement.item;
 
             vec![
                 Component::Circle {
-                    fill_color: colors::arsenal_item_color(drainee),
+                    fill_color: theme.arsenal_item_color(drainee),
                     shape: dequeue_foci::top_background(side),
                     on_click: None,
                 },
@@ -466,7 +539,7 @@ fn stationary_dequeue_choice(side: Side, displacements: DequeueDisplacements) ->
 
             vec![
                 Component::Circle {
-                    fill_color: colors::arsenal_item_color(exiter),
+                    fill_color: theme.arsenal_item_color(exiter),
                     shape: dequeue_foci::bottom_background(side),
                     on_click: None,
                 },
@@ -489,6 +562,7 @@ fn exiting_dequeue_choice(
     side: Side,
     displacements: DequeueDisplacements,
     lerper: &Lerper,
+    theme: &Theme,
 ) -> Vec<Component> {
     vec![
         displacements.drainee.map(|displacement| {
@@ -497,8 +571,8 @@ fn exiting_dequeue_choice(
 
             vec![
                 LerpableComponent::Circle {
-                    start_color: colors::arsenal_item_color(drainee),
-                    end_color: colors::arsenal_item_color(drainee),
+                    start_color: theme.arsenal_item_color(drainee),
+                    end_color: theme.arsenal_item_color(drainee),
                     start_shape: dequeue_foci::top_background(side),
                     end_shape: dequeue_circle::background_at(
                         position.side,
@@ -527,8 +601,8 @@ fn exiting_dequeue_choice(
 
             vec![
                 LerpableComponent::Circle {
-                    start_color: colors::arsenal_item_color(exiter),
-                    end_color: colors::arsenal_item_color(exiter),
+                    start_color: theme.arsenal_item_color(exiter),
+                    end_color: theme.arsenal_item_color(exiter),
                     start_shape: dequeue_foci::bottom_background(side),
                     end_shape: dequeue_circle::background_at(
                         position.side,
@@ -574,17 +648,17 @@ impl<'a> Render<f64> for ActionChoosingPhaseRenderer<'a> {
             (0.85..1.00, exit),
             (1.00..=1.00, actions_or_inspector),
         )
-        .case(completion_factor)
+        .case(completion_factor, self.motion)
         .expect("should have legal completion range")
     }
 }
 
-fn action_choosing_scoreboard(args: ActionChoosingRenderArgs) -> Vec<Component> {
+fn action_choosing_scoreboard(args: ActionChoosingRenderArgs, theme: &Theme) -> Vec<Component> {
     vec![
         action_choosing_pool_display(&args),
         action_choosing_entrance_and_exit_display(&args),
         action_choosing_arsenal_display(&args),
-        arrows(&args),
+        arrows(&args, theme),
     ]
     .into_iter()
     .flatten()
@@ -599,6 +673,7 @@ fn dequeueing_pool_display_without_drainee(
         player,
         side,
         dequeues,
+        theme,
     } = args;
     let side = *side;
 
@@ -619,6 +694,7 @@ fn dequeueing_pool_display_without_drainee(
         width_in_columns: 3,
         height_in_rows: helpers::height_in_rows(&player.queue.pool, 3),
         enabled: drain_and_exit_enabled,
+        theme,
     };
 
     let pool = player
@@ -639,6 +715,7 @@ fn dequeueing_pool_display_without_drainee(
                         column: i % 3,
                         row: i / 3,
                     },
+                    theme,
                 )
             }
         });
@@ -666,6 +743,7 @@ fn dequeueing_entrance_decline_and_exit_display_with_hidable_exit(
         player,
         side,
         dequeues,
+        theme,
     } = args;
     let side = *side;
 
@@ -686,6 +764,7 @@ fn dequeueing_entrance_decline_and_exit_display_with_hidable_exit(
         width_in_columns: 3,
         height_in_rows: 1,
         enabled: false,
+        theme,
     };
     let decline_and_exit_pill = Pill {
         position: CirclePosition {
@@ -696,6 +775,7 @@ fn dequeueing_entrance_decline_and_exit_display_with_hidable_exit(
         width_in_columns: 2,
         height_in_rows: 1,
         enabled: true,
+        theme,
     };
 
     vec![
@@ -711,11 +791,12 @@ fn dequeueing_entrance_decline_and_exit_display_with_hidable_exit(
                     column: 0,
                     row,
                 },
+                theme,
             )
         }),
         Some(vec![
             Component::Circle {
-                fill_color: colors::DECLINE_DEQUEUE_COLOR,
+                fill_color: theme.decline_dequeue,
                 shape: dequeue_circle::background_at(side, row, 1),
                 on_click: None,
             },
@@ -739,6 +820,7 @@ fn dequeueing_entrance_decline_and_exit_display_with_hidable_exit(
                         column: 2,
                         row,
                     },
+                    theme,
                 )
             })
         },
@@ -750,7 +832,12 @@ fn dequeueing_entrance_decline_and_exit_display_with_hidable_exit(
 }
 
 fn dequeueing_arsenal_display(args: &DequeueingRenderArgs) -> Vec<Component> {
-    let DequeueingRenderArgs { player, side, .. } = args;
+    let DequeueingRenderArgs {
+        player,
+        side,
+        theme,
+        ..
+    } = args;
     let side = *side;
 
     let row_offset = helpers::height_in_rows(&player.queue.pool, 3) + 1;
@@ -764,6 +851,7 @@ fn dequeueing_arsenal_display(args: &DequeueingRenderArgs) -> Vec<Component> {
         width_in_columns: 3,
         height_in_rows: helpers::height_in_rows(&player.arsenal, 3),
         enabled: false,
+        theme,
     };
 
     let arsenal_items = player
@@ -780,13 +868,14 @@ fn dequeueing_arsenal_display(args: &DequeueingRenderArgs) -> Vec<Component> {
                 false,
                 None,
                 CirclePosition { side, column, row },
+                theme,
             )
         });
 
     pill.render(()).into_iter().chain(arsenal_items).collect()
 }
 
-fn arrows<T: ArrowRenderArgs>(args: &T) -> Vec<Component> {
+fn arrows<T: ArrowRenderArgs>(args: &T, theme: &Theme) -> Vec<Component> {
     let side = args.side();
     let pool = args.pool();
 
@@ -795,13 +884,13 @@ fn arrows<T: ArrowRenderArgs>(args: &T) -> Vec<Component> {
         vec![]
     } else {
         vec![
-            arrow::up_arrow_above(side, pool_height_in_rows, 0),
-            arrow::down_arrow_above(side, pool_height_in_rows, 2),
+            arrow::up_arrow_above(side, pool_height_in_rows, 0, theme),
+            arrow::down_arrow_above(side, pool_height_in_rows, 2, theme),
         ]
     };
     let arsenal_to_entrance_and_exit = vec![
-        arrow::up_arrow_above(side, pool_height_in_rows + 1, 0),
-        arrow::down_arrow_above(side, pool_height_in_rows + 1, 2),
+        arrow::up_arrow_above(side, pool_height_in_rows + 1, 0, theme),
+        arrow::down_arrow_above(side, pool_height_in_rows + 1, 2, theme),
     ];
 
     entrance_and_exit_to_pool
@@ -814,7 +903,9 @@ fn action_choosing_pool_display(args: &ActionChoosingRenderArgs) -> Vec<Componen
     let ActionChoosingRenderArgs {
         player,
         side,
+        seat,
         actions,
+        theme,
     } = args;
     let side = *side;
 
@@ -835,6 +926,7 @@ fn action_choosing_pool_display(args: &ActionChoosingRenderArgs) -> Vec<Componen
         width_in_columns: 3,
         height_in_rows: helpers::height_in_rows(&player.queue.pool, 3),
         enabled: mirror_enabled,
+        theme,
     };
 
     let pool = player
@@ -856,9 +948,10 @@ fn action_choosing_pool_display(args: &ActionChoosingRenderArgs) -> Vec<Componen
 
                 vec![
                     Component::Circle {
-                        fill_color: colors::arsenal_item_color(arsenal_item),
+                        fill_color: theme.arsenal_item_color(arsenal_item),
                         shape: dequeue_circle::background_at(side, row, column),
-                        on_click: side.if_left(Action::ChooseAction(NzscAction::Mirror(move_))),
+                        on_click: side
+                            .if_seated(*seat, Action::ChooseAction(NzscAction::Mirror(move_))),
                     },
                     Component::Image {
                         image_type: ImageType::Mirror,
@@ -880,6 +973,7 @@ fn action_choosing_pool_display(args: &ActionChoosingRenderArgs) -> Vec<Componen
                     false,
                     None,
                     CirclePosition { side, column, row },
+                    theme,
                 )
             }
         });
@@ -888,7 +982,12 @@ fn action_choosing_pool_display(args: &ActionChoosingRenderArgs) -> Vec<Componen
 }
 
 fn action_choosing_entrance_and_exit_display(args: &ActionChoosingRenderArgs) -> Vec<Component> {
-    let ActionChoosingRenderArgs { player, side, .. } = args;
+    let ActionChoosingRenderArgs {
+        player,
+        side,
+        theme,
+        ..
+    } = args;
     let side = *side;
 
     let entrance = player.queue.entrance;
@@ -905,6 +1004,7 @@ fn action_choosing_entrance_and_exit_display(args: &ActionChoosingRenderArgs) ->
         width_in_columns: 3,
         height_in_rows: 1,
         enabled: false,
+        theme,
     };
 
     vec![
@@ -919,6 +1019,7 @@ fn action_choosing_entrance_and_exit_display(args: &ActionChoosingRenderArgs) ->
                     column: 0,
                     row,
                 },
+                theme,
             )
         }),
         exit.map(|exiting_item| {
@@ -931,6 +1032,7 @@ fn action_choosing_entrance_and_exit_display(args: &ActionChoosingRenderArgs) ->
                     column: 2,
                     row,
                 },
+                theme,
             )
         }),
     ]
@@ -941,7 +1043,13 @@ fn action_choosing_entrance_and_exit_display(args: &ActionChoosingRenderArgs) ->
 }
 
 fn action_choosing_arsenal_display(args: &ActionChoosingRenderArgs) -> Vec<Component> {
-    let ActionChoosingRenderArgs { player, side, .. } = args;
+    let ActionChoosingRenderArgs {
+        player,
+        side,
+        seat,
+        theme,
+        ..
+    } = args;
     let side = *side;
 
     let row_offset = helpers::height_in_rows(&player.queue.pool, 3) + 1;
@@ -955,6 +1063,7 @@ fn action_choosing_arsenal_display(args: &ActionChoosingRenderArgs) -> Vec<Compo
         width_in_columns: 3,
         height_in_rows: helpers::height_in_rows(&player.arsenal, 3).max(1),
         enabled: true,
+        theme,
     };
     let arsenal_items = player
         .arsenal
@@ -973,10 +1082,11 @@ fn action_choosing_arsenal_display(args: &ActionChoosingRenderArgs) -> Vec<Compo
             arsenal_item_display(
                 arsenal_item,
                 true,
-                side.if_left(())
+                side.if_seated(*seat, ())
                     .and(opt_move)
                     .map(|m| Action::ChooseAction(NzscAction::Move(m))),
                 CirclePosition { side, column, row },
+                theme,
             )
         });
 
@@ -985,7 +1095,7 @@ fn action_choosing_arsenal_display(args: &ActionChoosingRenderArgs) -> Vec<Compo
     {
         let concede_button = vec![
             Component::Circle {
-                fill_color: colors::booster_color(Booster::None),
+                fill_color: theme.booster_color(Booster::None),
                 shape: dequeue_circle::background_at(side, row_offset, player.arsenal.len()),
                 on_click: Some(Action::ChooseAction(NzscAction::Concede)),
             },
@@ -1098,12 +1208,15 @@ struct DequeueingRenderArgs<'a> {
     pub player: &'a DequeueingPlayer,
     pub side: Side,
     pub dequeues: &'a Vec<DequeueChoice>,
+    pub theme: &'a Theme,
 }
 
 struct ActionChoosingRenderArgs<'a> {
     pub player: &'a ActionlessPlayer,
     pub side: Side,
+    pub seat: Side,
     pub actions: &'a Vec<NzscAction>,
+    pub theme: &'a Theme,
 }
 
 trait ArrowRenderArgs {
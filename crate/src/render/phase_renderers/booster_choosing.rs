@@ -6,11 +6,12 @@ use crate::{
     render::{
         health_display::{ConstantHealthDisplay, FadingHealthDisplay},
         lerp::{LerpableComponent, Lerper},
-        switch::{Switch, Switch5},
+        switch::{Motion, Switch, Switch5},
         Render,
     },
     shapes::{rect_button, rect_focus},
     side::Side,
+    theme::Theme,
 };
 
 use nzscq::{
@@ -22,18 +23,26 @@ pub struct BoosterChoosingPhaseRenderer<'a> {
     previously_available_characters: &'a Vec<Character>,
     previous_outcome: &'a Vec<CharacterHeadstart>,
     available_boosters: &'a Vec<Booster>,
+    theme: &'a Theme,
+    motion: Motion,
 }
 
 impl<'a> BoosterChoosingPhaseRenderer<'a> {
-    pub fn new(phase: &'a ChooseBoosterPhase) -> BoosterChoosingPhaseRenderer<'a> {
+    pub fn new(
+        phase: &'a ChooseBoosterPhase,
+        theme: &'a Theme,
+        motion: Motion,
+    ) -> BoosterChoosingPhaseRenderer<'a> {
         BoosterChoosingPhaseRenderer {
             previously_available_characters: &phase.previously_available_characters,
             previous_outcome: &phase.previous_outcome,
             available_boosters: &phase.available_boosters,
+            theme,
+            motion,
         }
     }
 
-    fn human_entrance(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn human_entrance(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             let index_of_chosen_character = self
                 .previously_available_characters
@@ -42,15 +51,15 @@ impl<'a> BoosterChoosingPhaseRenderer<'a> {
                 .expect("human should have chosen character");
 
             let mut components = vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }];
             let overlay = Component::Background {
-                color: colors::OVERLAY,
+                color: self.theme.overlay,
             };
             let components_displaying_human_character: Vec<Component> = vec![
                 LerpableComponent::Rect {
-                    start_color: colors::character_color(self.human_character()),
-                    end_color: colors::character_color(self.human_character()),
+                    start_color: self.theme.character_color(self.human_character()),
+                    end_color: self.theme.character_color(self.human_character()),
                     start_shape: rect_button::background_at(index_of_chosen_character),
                     end_shape: rect_focus::left_background(),
                     on_click: None,
@@ -77,17 +86,17 @@ impl<'a> BoosterChoosingPhaseRenderer<'a> {
         }
     }
 
-    fn computer_entrance(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn computer_entrance(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             let mut components = vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }];
             let overlay = Component::Background {
-                color: colors::OVERLAY,
+                color: self.theme.overlay,
             };
             let components_displaying_human_character = vec![
                 Component::Rect {
-                    fill_color: colors::character_color(self.human_character()),
+                    fill_color: self.theme.character_color(self.human_character()),
                     shape: rect_focus::left_background(),
                     on_click: None,
                 },
@@ -100,8 +109,8 @@ impl<'a> BoosterChoosingPhaseRenderer<'a> {
             ];
             let components_displaying_computer_character: Vec<Component> = vec![
                 LerpableComponent::Rect {
-                    start_color: colors::character_color(self.computer_character()),
-                    end_color: colors::character_color(self.computer_character()),
+                    start_color: self.theme.character_color(self.computer_character()),
+                    end_color: self.theme.character_color(self.computer_character()),
                     start_shape: rect_focus::far_right_background(),
                     end_shape: rect_focus::right_background(),
                     on_click: None,
@@ -129,13 +138,13 @@ impl<'a> BoosterChoosingPhaseRenderer<'a> {
         }
     }
 
-    fn fade(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn fade(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             let mut components = vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }];
             let overlay = Component::Background {
-                color: colors::OVERLAY,
+                color: self.theme.overlay,
             };
             let components_displaying_human_character: Vec<Component> = {
                 let lerper = lerper.sub_lerper(0.0..colors::PORTION_OF_DURATION_SPENT_FADING);
@@ -144,12 +153,14 @@ impl<'a> BoosterChoosingPhaseRenderer<'a> {
                 } else {
                     1.0
                 };
-                let end_color = colors::character_color(self.human_character())
+                let end_color = self
+                    .theme
+                    .character_color(self.human_character())
                     .with_alpha((end_alpha * 255.0) as u8);
 
                 vec![
                     LerpableComponent::Rect {
-                        start_color: colors::character_color(self.human_character()),
+                        start_color: self.theme.character_color(self.human_character()),
                         end_color,
                         start_shape: rect_focus::left_background(),
                         end_shape: rect_focus::left_background(),
@@ -171,12 +182,14 @@ impl<'a> BoosterChoosingPhaseRenderer<'a> {
             let components_displaying_computer_character: Vec<Component> = {
                 let lerper = lerper.sub_lerper(0.0..colors::PORTION_OF_DURATION_SPENT_FADING);
                 let end_alpha = if self.did_human_get_point() { 0.0 } else { 1.0 };
-                let end_color = colors::character_color(self.computer_character())
+                let end_color = self
+                    .theme
+                    .character_color(self.computer_character())
                     .with_alpha((end_alpha * 255.0) as u8);
 
                 vec![
                     LerpableComponent::Rect {
-                        start_color: colors::character_color(self.computer_character()),
+                        start_color: self.theme.character_color(self.computer_character()),
                         end_color,
                         start_shape: rect_focus::right_background(),
                         end_shape: rect_focus::right_background(),
@@ -207,13 +220,13 @@ impl<'a> BoosterChoosingPhaseRenderer<'a> {
         }
     }
 
-    fn exit(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn exit(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             let mut components = vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }];
             let overlay = Component::Background {
-                color: colors::OVERLAY,
+                color: self.theme.overlay,
             };
             let components_displaying_human_character: Vec<Component> =
                 if self.did_computer_get_point() {
@@ -221,8 +234,8 @@ impl<'a> BoosterChoosingPhaseRenderer<'a> {
                 } else {
                     vec![
                         LerpableComponent::Rect {
-                            start_color: colors::character_color(self.human_character()),
-                            end_color: colors::character_color(self.human_character()),
+                            start_color: self.theme.character_color(self.human_character()),
+                            end_color: self.theme.character_color(self.human_character()),
                             start_shape: rect_focus::left_background(),
                             end_shape: rect_focus::far_left_background(),
                             on_click: None,
@@ -246,8 +259,8 @@ impl<'a> BoosterChoosingPhaseRenderer<'a> {
                 } else {
                     vec![
                         LerpableComponent::Rect {
-                            start_color: colors::character_color(self.computer_character()),
-                            end_color: colors::character_color(self.computer_character()),
+                            start_color: self.theme.character_color(self.computer_character()),
+                            end_color: self.theme.character_color(self.computer_character()),
                             start_shape: rect_focus::right_background(),
                             end_shape: rect_focus::far_right_background(),
                             on_click: None,
@@ -276,12 +289,12 @@ impl<'a> BoosterChoosingPhaseRenderer<'a> {
         }
     }
 
-    fn boosters(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn boosters(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         use crate::transform::Translate;
 
         move |lerper| {
             let mut components = vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }];
             let booster_buttons: Vec<Component> = self
                 .available_boosters
@@ -290,8 +303,8 @@ impl<'a> BoosterChoosingPhaseRenderer<'a> {
                 .map(|(i, &booster)| {
                     vec![
                         LerpableComponent::Rect {
-                            start_color: colors::booster_color(booster),
-                            end_color: colors::booster_color(booster),
+                            start_color: self.theme.booster_color(booster),
+                            end_color: self.theme.booster_color(booster),
                             start_shape: rect_button::background_at(i).translate(1800.0, 0.0),
                             end_shape: rect_button::background_at(i),
                             on_click: Some(Action::ChooseBooster(booster)),
@@ -327,7 +340,7 @@ impl<'a> BoosterChoosingPhaseRenderer<'a> {
             .map(|(i, &character)| {
                 vec![
                     Component::Rect {
-                        fill_color: colors::character_color(character),
+                        fill_color: self.theme.character_color(character),
                         shape: rect_button::background_at(i),
                         on_click: None,
                     },
@@ -356,10 +369,12 @@ impl<'a> BoosterChoosingPhaseRenderer<'a> {
         let human_display = ConstantHealthDisplay {
             side: Side::Left,
             health: 5,
+            theme: self.theme,
         };
         let computer_display = ConstantHealthDisplay {
             side: Side::Right,
             health: 5,
+            theme: self.theme,
         };
 
         vec![human_display, computer_display]
@@ -374,6 +389,7 @@ impl<'a> BoosterChoosingPhaseRenderer<'a> {
             lerper.lerp1(FadingHealthDisplay {
                 side: Side::Left,
                 starting_health: 5,
+                theme: self.theme,
             })
         } else {
             vec![]
@@ -382,6 +398,7 @@ impl<'a> BoosterChoosingPhaseRenderer<'a> {
             lerper.lerp1(FadingHealthDisplay {
                 side: Side::Right,
                 starting_health: 5,
+                theme: self.theme,
             })
         } else {
             vec![]
@@ -400,6 +417,7 @@ impl<'a> BoosterChoosingPhaseRenderer<'a> {
             ConstantHealthDisplay {
                 side: Side::Left,
                 health: 5,
+                theme: self.theme,
             }
             .render(())
         };
@@ -409,6 +427,7 @@ impl<'a> BoosterChoosingPhaseRenderer<'a> {
             ConstantHealthDisplay {
                 side: Side::Right,
                 health: 5,
+                theme: self.theme,
             }
             .render(())
         };
@@ -423,10 +442,12 @@ impl<'a> BoosterChoosingPhaseRenderer<'a> {
         let human_display = ConstantHealthDisplay {
             side: Side::Left,
             health: self.human_health(),
+            theme: self.theme,
         };
         let computer_display = ConstantHealthDisplay {
             side: Side::Right,
             health: self.computer_health(),
+            theme: self.theme,
         };
 
         vec![human_display, computer_display]
@@ -476,7 +497,7 @@ impl<'a> Render<f64> for BoosterChoosingPhaseRenderer<'a> {
             (0.68..0.80, exit),
             (0.80..=1.00, boosters),
         )
-        .case(completion_factor)
+        .case(completion_factor, self.motion)
         .expect("should have legal completion range")
     }
 }
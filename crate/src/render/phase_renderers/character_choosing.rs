@@ -1,15 +1,16 @@
 use crate::{
     click::Action,
-    colors,
     paint::{Component, ImageType},
     phase::ChooseCharacterPhase,
     render::{
         health_display::ConstantHealthDisplay,
         lerp::{LerpableComponent, Lerper},
+        switch::Motion,
         Render,
     },
     shapes::rect_button,
     side::Side,
+    theme::Theme,
     transform::Translate,
 };
 
@@ -17,12 +18,20 @@ use nzscq::choices::Character;
 
 pub struct CharacterChoosingPhaseRenderer<'a> {
     available_characters: &'a Vec<Character>,
+    theme: &'a Theme,
+    motion: Motion,
 }
 
 impl<'a> CharacterChoosingPhaseRenderer<'a> {
-    pub fn new(phase: &'a ChooseCharacterPhase) -> CharacterChoosingPhaseRenderer<'a> {
+    pub fn new(
+        phase: &'a ChooseCharacterPhase,
+        theme: &'a Theme,
+        motion: Motion,
+    ) -> CharacterChoosingPhaseRenderer<'a> {
         CharacterChoosingPhaseRenderer {
             available_characters: &phase.available_characters,
+            theme,
+            motion,
         }
     }
 
@@ -30,10 +39,12 @@ impl<'a> CharacterChoosingPhaseRenderer<'a> {
         let human_display = ConstantHealthDisplay {
             side: Side::Left,
             health: 5,
+            theme: self.theme,
         };
         let computer_display = ConstantHealthDisplay {
             side: Side::Right,
             health: 5,
+            theme: self.theme,
         };
 
         vec![human_display, computer_display]
@@ -46,9 +57,12 @@ impl<'a> CharacterChoosingPhaseRenderer<'a> {
 
 impl<'a> Render<f64> for CharacterChoosingPhaseRenderer<'a> {
     fn render(&self, completion_factor: f64) -> Vec<Component> {
-        let lerper = Lerper::from_completion_factor(completion_factor);
+        let lerper = match self.motion {
+            Motion::Full => Lerper::from_completion_factor(completion_factor),
+            Motion::Reduced => Lerper::from_completion_factor(1.0),
+        };
         let mut components = vec![Component::Background {
-            color: colors::BACKGROUND,
+            color: self.theme.background,
         }];
         let character_buttons: Vec<Component> = self
             .available_characters
@@ -57,8 +71,8 @@ impl<'a> Render<f64> for CharacterChoosingPhaseRenderer<'a> {
             .map(|(i, &character)| {
                 vec![
                     LerpableComponent::Rect {
-                        start_color: colors::character_color(character),
-                        end_color: colors::character_color(character),
+                        start_color: self.theme.character_color(character),
+                        end_color: self.theme.character_color(character),
                         start_shape: rect_button::background_at(i).translate(1800.0, 0.0),
                         end_shape: rect_button::background_at(i),
                         on_click: Some(Action::ChooseCharacter(character)),
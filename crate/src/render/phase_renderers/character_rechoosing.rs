@@ -1,16 +1,16 @@
 use crate::{
     click::Action,
-    colors,
     paint::{Component, ImageType},
     phase::RechooseCharacterPhase,
     render::{
         health_display::ConstantHealthDisplay,
         lerp::{LerpableComponent, Lerper},
-        switch::{Switch, Switch5},
+        switch::{Motion, Switch, Switch5},
         Render,
     },
     shapes::{rect_button, rect_focus},
     side::Side,
+    theme::Theme,
 };
 
 use nzscq::choices::Character;
@@ -19,18 +19,26 @@ pub struct CharacterRechoosingPhaseRenderer<'a> {
     previously_available_characters: &'a Vec<Character>,
     previously_mutually_chosen_character: Character,
     available_characters: &'a Vec<Character>,
+    theme: &'a Theme,
+    motion: Motion,
 }
 
 impl<'a> CharacterRechoosingPhaseRenderer<'a> {
-    pub fn new(phase: &'a RechooseCharacterPhase) -> CharacterRechoosingPhaseRenderer<'a> {
+    pub fn new(
+        phase: &'a RechooseCharacterPhase,
+        theme: &'a Theme,
+        motion: Motion,
+    ) -> CharacterRechoosingPhaseRenderer<'a> {
         CharacterRechoosingPhaseRenderer {
             previously_available_characters: &phase.previously_available_characters,
             previously_mutually_chosen_character: phase.previously_mutually_chosen_character,
             available_characters: &phase.available_characters,
+            theme,
+            motion,
         }
     }
 
-    fn human_entrance(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn human_entrance(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             let index_of_chosen_character = self
                 .previously_available_characters
@@ -39,15 +47,19 @@ impl<'a> CharacterRechoosingPhaseRenderer<'a> {
                 .expect("human should have chosen character");
 
             let mut components = vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }];
             let overlay = Component::Background {
-                color: colors::OVERLAY,
+                color: self.theme.overlay,
             };
             let components_displaying_human_character: Vec<Component> = vec![
                 LerpableComponent::Rect {
-                    start_color: colors::character_color(self.previously_mutually_chosen_character),
-                    end_color: colors::character_color(self.previously_mutually_chosen_character),
+                    start_color: self
+                        .theme
+                        .character_color(self.previously_mutually_chosen_character),
+                    end_color: self
+                        .theme
+                        .character_color(self.previously_mutually_chosen_character),
                     start_shape: rect_button::background_at(index_of_chosen_character),
                     end_shape: rect_focus::left_background(),
                     on_click: None,
@@ -74,17 +86,19 @@ impl<'a> CharacterRechoosingPhaseRenderer<'a> {
         }
     }
 
-    fn computer_entrance(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn computer_entrance(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             let mut components = vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }];
             let overlay = Component::Background {
-                color: colors::OVERLAY,
+                color: self.theme.overlay,
             };
             let components_displaying_human_character: Vec<Component> = vec![
                 Component::Rect {
-                    fill_color: colors::character_color(self.previously_mutually_chosen_character),
+                    fill_color: self
+                        .theme
+                        .character_color(self.previously_mutually_chosen_character),
                     shape: rect_focus::left_background(),
                     on_click: None,
                 },
@@ -97,8 +111,12 @@ impl<'a> CharacterRechoosingPhaseRenderer<'a> {
             ];
             let components_displaying_computer_character: Vec<Component> = vec![
                 LerpableComponent::Rect {
-                    start_color: colors::character_color(self.previously_mutually_chosen_character),
-                    end_color: colors::character_color(self.previously_mutually_chosen_character),
+                    start_color: self
+                        .theme
+                        .character_color(self.previously_mutually_chosen_character),
+                    end_color: self
+                        .theme
+                        .character_color(self.previously_mutually_chosen_character),
                     start_shape: rect_focus::far_right_background(),
                     end_shape: rect_focus::right_background(),
                     on_click: None,
@@ -126,17 +144,19 @@ impl<'a> CharacterRechoosingPhaseRenderer<'a> {
         }
     }
 
-    fn pause(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn pause(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |_| {
             let mut components = vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }];
             let overlay = Component::Background {
-                color: colors::OVERLAY,
+                color: self.theme.overlay,
             };
             let components_displaying_human_character: Vec<Component> = vec![
                 Component::Rect {
-                    fill_color: colors::character_color(self.previously_mutually_chosen_character),
+                    fill_color: self
+                        .theme
+                        .character_color(self.previously_mutually_chosen_character),
                     shape: rect_focus::left_background(),
                     on_click: None,
                 },
@@ -149,7 +169,9 @@ impl<'a> CharacterRechoosingPhaseRenderer<'a> {
             ];
             let components_displaying_computer_character: Vec<Component> = vec![
                 Component::Rect {
-                    fill_color: colors::character_color(self.previously_mutually_chosen_character),
+                    fill_color: self
+                        .theme
+                        .character_color(self.previously_mutually_chosen_character),
                     shape: rect_focus::right_background(),
                     on_click: None,
                 },
@@ -171,18 +193,22 @@ impl<'a> CharacterRechoosingPhaseRenderer<'a> {
         }
     }
 
-    fn exit(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn exit(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             let mut components = vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }];
             let overlay = Component::Background {
-                color: colors::OVERLAY,
+                color: self.theme.overlay,
             };
             let components_displaying_human_character: Vec<Component> = vec![
                 LerpableComponent::Rect {
-                    start_color: colors::character_color(self.previously_mutually_chosen_character),
-                    end_color: colors::character_color(self.previously_mutually_chosen_character),
+                    start_color: self
+                        .theme
+                        .character_color(self.previously_mutually_chosen_character),
+                    end_color: self
+                        .theme
+                        .character_color(self.previously_mutually_chosen_character),
                     start_shape: rect_focus::left_background(),
                     end_shape: rect_focus::far_left_background(),
                     on_click: None,
@@ -201,8 +227,12 @@ impl<'a> CharacterRechoosingPhaseRenderer<'a> {
             .collect();
             let components_displaying_computer_character: Vec<Component> = vec![
                 LerpableComponent::Rect {
-                    start_color: colors::character_color(self.previously_mutually_chosen_character),
-                    end_color: colors::character_color(self.previously_mutually_chosen_character),
+                    start_color: self
+                        .theme
+                        .character_color(self.previously_mutually_chosen_character),
+                    end_color: self
+                        .theme
+                        .character_color(self.previously_mutually_chosen_character),
                     start_shape: rect_focus::right_background(),
                     end_shape: rect_focus::far_right_background(),
                     on_click: None,
@@ -230,10 +260,10 @@ impl<'a> CharacterRechoosingPhaseRenderer<'a> {
         }
     }
 
-    fn rechoose_characters(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn rechoose_characters(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |_| {
             let mut components = vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }];
             let character_buttons: Vec<Component> = self
                 .available_characters
@@ -242,7 +272,7 @@ impl<'a> CharacterRechoosingPhaseRenderer<'a> {
                 .map(|(i, &character)| {
                     vec![
                         Component::Rect {
-                            fill_color: colors::character_color(character),
+                            fill_color: self.theme.character_color(character),
                             shape: rect_button::background_at(i),
                             on_click: Some(Action::ChooseCharacter(character)),
                         },
@@ -274,7 +304,7 @@ impl<'a> CharacterRechoosingPhaseRenderer<'a> {
             .map(|(i, &character)| {
                 vec![
                     Component::Rect {
-                        fill_color: colors::character_color(character),
+                        fill_color: self.theme.character_color(character),
                         shape: rect_button::background_at(i),
                         on_click: None,
                     },
@@ -295,10 +325,12 @@ impl<'a> CharacterRechoosingPhaseRenderer<'a> {
         let human_display = ConstantHealthDisplay {
             side: Side::Left,
             health: 5,
+            theme: self.theme,
         };
         let computer_display = ConstantHealthDisplay {
             side: Side::Right,
             health: 5,
+            theme: self.theme,
         };
 
         vec![human_display, computer_display]
@@ -324,7 +356,7 @@ impl<'a> Render<f64> for CharacterRechoosingPhaseRenderer<'a> {
             (0.85..1.00, exit),
             (1.00..=1.00, rechoose_characters),
         )
-        .case(completion_factor)
+        .case(completion_factor, self.motion)
         .expect("should have legal completion range")
     }
 }
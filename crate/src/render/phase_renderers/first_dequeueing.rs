@@ -1,7 +1,10 @@
 use crate::{
     canvas_dimensions,
     click::Action,
-    colors, helpers,
+    colors,
+    forecast::QueueForecast,
+    helpers,
+    i18n::Messages,
     paint::{Component, ImageType},
     phase::{ChooseFirstDequeuePhase, MoveInspectorState},
     render::{
@@ -9,9 +12,10 @@ use crate::{
         health_display::ConstantHealthDisplay,
         lerp::{LerpableComponent, Lerper},
         move_inspector::{MoveInspector, MoveInspectorArgs},
-        move_inspector_buttons::{InspectMoveButton, RenderButton},
+        move_inspector_buttons::{ForecastQueueButton, InspectMoveButton, RenderButton},
         pill::Pill,
-        switch::{Switch, Switch5},
+        queue_forecast::QueueForecastDisplay,
+        switch::{Motion, Switch, Switch5},
         Render,
     },
     shapes::{
@@ -19,6 +23,7 @@ use crate::{
         rect_button, rect_focus,
     },
     side::Side,
+    theme::Theme,
     transform::Translate,
 };
 
@@ -33,20 +38,36 @@ pub struct FirstDequeueingPhaseRenderer<'a> {
     previously_available_boosters: &'a Vec<Booster>,
     scoreboard: &'a [DequeueingPlayer; 2],
     available_dequeues: &'a [Vec<DequeueChoice>; 2],
+    forecasts: &'a [Vec<QueueForecast>; 2],
     inspector_state: MoveInspectorState,
+    theme: &'a Theme,
+    motion: Motion,
+    messages: Messages,
+    seat: Side,
 }
 
 impl<'a> FirstDequeueingPhaseRenderer<'a> {
-    pub fn new(phase: &'a ChooseFirstDequeuePhase) -> FirstDequeueingPhaseRenderer<'a> {
+    pub fn new(
+        phase: &'a ChooseFirstDequeuePhase,
+        theme: &'a Theme,
+        motion: Motion,
+        messages: Messages,
+        seat: Side,
+    ) -> FirstDequeueingPhaseRenderer<'a> {
         FirstDequeueingPhaseRenderer {
             previously_available_boosters: &phase.previously_available_boosters,
             scoreboard: &phase.scoreboard,
             available_dequeues: &phase.available_dequeues,
+            forecasts: &phase.forecasts,
             inspector_state: phase.inspector_state,
+            theme,
+            motion,
+            messages,
+            seat,
         }
     }
 
-    fn human_entrance(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn human_entrance(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             let index_of_chosen_booster = self
                 .previously_available_boosters
@@ -55,15 +76,15 @@ impl<'a> FirstDequeueingPhaseRenderer<'a> {
                 .expect("human should have chosen booster");
 
             let mut components = vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }];
             let overlay = Component::Background {
-                color: colors::OVERLAY,
+                color: self.theme.overlay,
             };
             let components_displaying_human_booster: Vec<Component> = vec![
                 LerpableComponent::Rect {
-                    start_color: colors::booster_color(self.human_booster()),
-                    end_color: colors::booster_color(self.human_booster()),
+                    start_color: self.theme.booster_color(self.human_booster()),
+                    end_color: self.theme.booster_color(self.human_booster()),
                     start_shape: rect_button::background_at(index_of_chosen_booster),
                     end_shape: rect_focus::left_background(),
                     on_click: None,
@@ -90,17 +111,17 @@ impl<'a> FirstDequeueingPhaseRenderer<'a> {
         }
     }
 
-    fn computer_entrance(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn computer_entrance(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             let mut components = vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }];
             let overlay = Component::Background {
-                color: colors::OVERLAY,
+                color: self.theme.overlay,
             };
             let components_displaying_human_booster = vec![
                 Component::Rect {
-                    fill_color: colors::booster_color(self.human_booster()),
+                    fill_color: self.theme.booster_color(self.human_booster()),
                     shape: rect_focus::left_background(),
                     on_click: None,
                 },
@@ -113,8 +134,8 @@ impl<'a> FirstDequeueingPhaseRenderer<'a> {
             ];
             let components_displaying_computer_booster: Vec<Component> = vec![
                 LerpableComponent::Rect {
-                    start_color: colors::booster_color(self.computer_booster()),
-                    end_color: colors::booster_color(self.computer_booster()),
+                    start_color: self.theme.booster_color(self.computer_booster()),
+                    end_color: self.theme.booster_color(self.computer_booster()),
                     start_shape: rect_focus::far_right_background(),
                     end_shape: rect_focus::right_background(),
                     on_click: None,
@@ -142,17 +163,17 @@ impl<'a> FirstDequeueingPhaseRenderer<'a> {
         }
     }
 
-    fn pause(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn pause(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |_| {
             let mut components = vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }];
             let overlay = Component::Background {
-                color: colors::OVERLAY,
+                color: self.theme.overlay,
             };
             let components_displaying_human_booster = vec![
                 Component::Rect {
-                    fill_color: colors::booster_color(self.human_booster()),
+                    fill_color: self.theme.booster_color(self.human_booster()),
                     shape: rect_focus::left_background(),
                     on_click: None,
                 },
@@ -165,7 +186,7 @@ impl<'a> FirstDequeueingPhaseRenderer<'a> {
             ];
             let components_displaying_computer_booster = vec![
                 Component::Rect {
-                    fill_color: colors::booster_color(self.computer_booster()),
+                    fill_color: self.theme.booster_color(self.computer_booster()),
                     shape: rect_focus::right_background(),
                     on_click: None,
                 },
@@ -187,18 +208,18 @@ impl<'a> FirstDequeueingPhaseRenderer<'a> {
         }
     }
 
-    fn exit(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn exit(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             let mut components = vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }];
             let overlay = Component::Background {
-                color: colors::OVERLAY,
+                color: self.theme.overlay,
             };
             let components_displaying_human_booster: Vec<Component> = vec![
                 LerpableComponent::Rect {
-                    start_color: colors::booster_color(self.human_booster()),
-                    end_color: colors::booster_color(self.human_booster()),
+                    start_color: self.theme.booster_color(self.human_booster()),
+                    end_color: self.theme.booster_color(self.human_booster()),
                     start_shape: rect_focus::left_background(),
                     end_shape: rect_focus::far_left_background(),
                     on_click: None,
@@ -217,8 +238,8 @@ impl<'a> FirstDequeueingPhaseRenderer<'a> {
             .collect();
             let components_displaying_computer_booster: Vec<Component> = vec![
                 LerpableComponent::Rect {
-                    start_color: colors::booster_color(self.computer_booster()),
-                    end_color: colors::booster_color(self.computer_booster()),
+                    start_color: self.theme.booster_color(self.computer_booster()),
+                    end_color: self.theme.booster_color(self.computer_booster()),
                     start_shape: rect_focus::right_background(),
                     end_shape: rect_focus::far_right_background(),
                     on_click: None,
@@ -257,7 +278,7 @@ impl<'a> FirstDequeueingPhaseRenderer<'a> {
             .map(|(i, &booster)| {
                 vec![
                     Component::Rect {
-                        fill_color: colors::booster_color(booster),
+                        fill_color: self.theme.booster_color(booster),
                         shape: rect_button::background_at(i),
                         on_click: None,
                     },
@@ -282,20 +303,18 @@ impl<'a> FirstDequeueingPhaseRenderer<'a> {
         self.scoreboard[COMPUTER].booster
     }
 
-    fn dequeues_or_inspector(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
-        move |lerper| {
-            if self.inspector_state == MoveInspectorState::NotInspecting {
-                self.dequeues(lerper)
-            } else {
-                self.move_inspector()
-            }
+    fn dequeues_or_inspector(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
+        move |lerper| match self.inspector_state {
+            MoveInspectorState::NotInspecting => self.dequeues(lerper),
+            MoveInspectorState::Forecasting(side) => self.queue_forecast(side),
+            _ => self.move_inspector(),
         }
     }
 
     fn dequeues(&self, lerper: Lerper) -> Vec<Component> {
         vec![
             vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }],
             self.health_displays(),
             self.human_scoreboard_display()
@@ -306,10 +325,12 @@ impl<'a> FirstDequeueingPhaseRenderer<'a> {
                 .into_iter()
                 .map(|component| component.translate(lerper.lerp(553.2, 0.0), 0.0))
                 .collect(),
-            self.inspector_state.render_button(true).translate(
-                0.0,
-                lerper.lerp(canvas_dimensions::HEIGHT - InspectMoveButton::Y, 0.0),
-            ),
+            self.inspector_state
+                .render_button(true, self.theme, self.messages)
+                .translate(
+                    0.0,
+                    lerper.lerp(canvas_dimensions::HEIGHT - InspectMoveButton::Y, 0.0),
+                ),
         ]
         .into_iter()
         .flatten()
@@ -344,10 +365,12 @@ impl<'a> FirstDequeueingPhaseRenderer<'a> {
         let human_display = ConstantHealthDisplay {
             side: Side::Left,
             health: helpers::opponent_points_to_own_health(self.computer_points()),
+            theme: self.theme,
         };
         let computer_display = ConstantHealthDisplay {
             side: Side::Right,
             health: helpers::opponent_points_to_own_health(self.human_points()),
+            theme: self.theme,
         };
 
         vec![human_display, computer_display]
@@ -369,7 +392,9 @@ impl<'a> FirstDequeueingPhaseRenderer<'a> {
         ScoreboardRenderArgs {
             player: &self.scoreboard[HUMAN],
             side: Side::Left,
+            seat: self.seat,
             dequeues: &self.available_dequeues[HUMAN],
+            theme: self.theme,
         }
     }
 
@@ -377,25 +402,39 @@ impl<'a> FirstDequeueingPhaseRenderer<'a> {
         ScoreboardRenderArgs {
             player: &self.scoreboard[COMPUTER],
             side: Side::Right,
+            seat: self.seat,
             dequeues: &self.available_dequeues[COMPUTER],
+            theme: self.theme,
         }
     }
 
     fn move_inspector(&self) -> Vec<Component> {
         vec![
             vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }],
             self.health_displays(),
             self.human_move_inspector(),
             self.computer_move_inspector(),
-            self.inspector_state.render_button(true),
+            self.inspector_state
+                .render_button(true, self.theme, self.messages),
+            ForecastQueueButton.render((self.theme, self.messages)),
         ]
         .into_iter()
         .flatten()
         .collect()
     }
 
+    fn queue_forecast(&self, side: Side) -> Vec<Component> {
+        QueueForecastDisplay {
+            side,
+            forecasts: &self.forecasts[side as usize],
+            theme: self.theme,
+            messages: self.messages,
+        }
+        .render(())
+    }
+
     fn human_move_inspector(&self) -> Vec<Component> {
         MoveInspector::new(self.human_move_inspector_args()).render(())
     }
@@ -405,6 +444,7 @@ impl<'a> FirstDequeueingPhaseRenderer<'a> {
             side: Side::Left,
             player: self.human().player,
             inspected_move: self.inspector_state.move_(),
+            theme: self.theme,
         }
     }
 
@@ -417,6 +457,7 @@ impl<'a> FirstDequeueingPhaseRenderer<'a> {
             side: Side::Right,
             player: self.computer().player,
             inspected_move: self.inspector_state.move_(),
+            theme: self.theme,
         }
     }
 }
@@ -436,7 +477,7 @@ impl<'a> Render<f64> for FirstDequeueingPhaseRenderer<'a> {
             (0.68..0.80, exit),
             (0.80..=1.00, dequeues_or_inspector),
         )
-        .case(completion_factor)
+        .case(completion_factor, self.motion)
         .expect("should have legal completion range")
     }
 }
@@ -445,7 +486,9 @@ fn pool_display(args: ScoreboardRenderArgs) -> Vec<Component> {
     let ScoreboardRenderArgs {
         player,
         side,
+        seat,
         dequeues,
+        theme,
     } = args;
 
     let drain_and_exit_enabled = dequeues.iter().any(|dequeue| {
@@ -465,6 +508,7 @@ fn pool_display(args: ScoreboardRenderArgs) -> Vec<Component> {
         width_in_columns: 3,
         height_in_rows: helpers::height_in_rows(&player.queue.pool, 3),
         enabled: drain_and_exit_enabled,
+        theme,
     };
 
     let pool = player
@@ -479,11 +523,12 @@ fn pool_display(args: ScoreboardRenderArgs) -> Vec<Component> {
             if drain_and_exit_enabled {
                 vec![
                     Component::Circle {
-                        fill_color: colors::arsenal_item_color(arsenal_item),
+                        fill_color: theme.arsenal_item_color(arsenal_item),
                         shape: dequeue_circle::background_at(side, row, column),
-                        on_click: side.if_left(Action::ChooseDequeue(DequeueChoice::DrainAndExit(
-                            arsenal_item,
-                        ))),
+                        on_click: side.if_seated(
+                            seat,
+                            Action::ChooseDequeue(DequeueChoice::DrainAndExit(arsenal_item)),
+                        ),
                     },
                     Component::Image {
                         image_type: ImageType::from(arsenal_item),
@@ -495,7 +540,8 @@ fn pool_display(args: ScoreboardRenderArgs) -> Vec<Component> {
             } else {
                 vec![
                     Component::Circle {
-                        fill_color: colors::arsenal_item_color(arsenal_item)
+                        fill_color: theme
+                            .arsenal_item_color(arsenal_item)
                             .with_alpha(colors::DISABLED_DEQUEUE_ARSENAL_ITEM_ALPHA),
                         shape: dequeue_circle::background_at(side, row, column),
                         on_click: None,
@@ -507,7 +553,7 @@ fn pool_display(args: ScoreboardRenderArgs) -> Vec<Component> {
                         on_click: None,
                     },
                     Component::Circle {
-                        fill_color: colors::OVERLAY,
+                        fill_color: theme.overlay,
                         shape: dequeue_circle::background_at(side, row, column),
                         on_click: None,
                     },
@@ -522,7 +568,9 @@ fn entrance_and_exit_display(args: ScoreboardRenderArgs) -> Vec<Component> {
     let ScoreboardRenderArgs {
         player,
         side,
+        seat,
         dequeues,
+        theme,
     } = args;
 
     let entrance = player.queue.entrance;
@@ -542,6 +590,7 @@ fn entrance_and_exit_display(args: ScoreboardRenderArgs) -> Vec<Component> {
         width_in_columns: 3,
         height_in_rows: 1,
         enabled: false,
+        theme,
     };
     let decline_and_exit_pill = Pill {
         position: CirclePosition {
@@ -552,6 +601,7 @@ fn entrance_and_exit_display(args: ScoreboardRenderArgs) -> Vec<Component> {
         width_in_columns: 2,
         height_in_rows: 1,
         enabled: true,
+        theme,
     };
 
     vec![
@@ -560,7 +610,8 @@ fn entrance_and_exit_display(args: ScoreboardRenderArgs) -> Vec<Component> {
         entrance.map(|entering_item| {
             vec![
                 Component::Circle {
-                    fill_color: colors::arsenal_item_color(entering_item)
+                    fill_color: theme
+                        .arsenal_item_color(entering_item)
                         .with_alpha(colors::DISABLED_DEQUEUE_ARSENAL_ITEM_ALPHA),
                     shape: dequeue_circle::background_at(side, row, 0),
                     on_click: None,
@@ -572,7 +623,7 @@ fn entrance_and_exit_display(args: ScoreboardRenderArgs) -> Vec<Component> {
                     on_click: None,
                 },
                 Component::Circle {
-                    fill_color: colors::OVERLAY,
+                    fill_color: theme.overlay,
                     shape: dequeue_circle::background_at(side, row, 0),
                     on_click: None,
                 },
@@ -580,9 +631,9 @@ fn entrance_and_exit_display(args: ScoreboardRenderArgs) -> Vec<Component> {
         }),
         Some(vec![
             Component::Circle {
-                fill_color: colors::DECLINE_DEQUEUE_COLOR,
+                fill_color: theme.decline_dequeue,
                 shape: dequeue_circle::background_at(side, row, 1),
-                on_click: side.if_left(Action::ChooseDequeue(DequeueChoice::Decline)),
+                on_click: side.if_seated(seat, Action::ChooseDequeue(DequeueChoice::Decline)),
             },
             Component::Image {
                 image_type: ImageType::DeclineDequeue,
@@ -595,9 +646,10 @@ fn entrance_and_exit_display(args: ScoreboardRenderArgs) -> Vec<Component> {
             exit.map(|exiting_item| {
                 vec![
                     Component::Circle {
-                        fill_color: colors::arsenal_item_color(exiting_item),
+                        fill_color: theme.arsenal_item_color(exiting_item),
                         shape: dequeue_circle::background_at(side, row, 2),
-                        on_click: side.if_left(Action::ChooseDequeue(DequeueChoice::JustExit)),
+                        on_click: side
+                            .if_seated(seat, Action::ChooseDequeue(DequeueChoice::JustExit)),
                     },
                     Component::Image {
                         image_type: ImageType::from(exiting_item),
@@ -611,7 +663,8 @@ fn entrance_and_exit_display(args: ScoreboardRenderArgs) -> Vec<Component> {
             exit.map(|exiting_item| {
                 vec![
                     Component::Circle {
-                        fill_color: colors::arsenal_item_color(exiting_item)
+                        fill_color: theme
+                            .arsenal_item_color(exiting_item)
                             .with_alpha(colors::DISABLED_DEQUEUE_ARSENAL_ITEM_ALPHA),
                         shape: dequeue_circle::background_at(side, row, 2),
                         on_click: None,
@@ -623,7 +676,7 @@ fn entrance_and_exit_display(args: ScoreboardRenderArgs) -> Vec<Component> {
                         on_click: None,
                     },
                     Component::Circle {
-                        fill_color: colors::OVERLAY,
+                        fill_color: theme.overlay,
                         shape: dequeue_circle::background_at(side, row, 2),
                         on_click: None,
                     },
@@ -638,7 +691,12 @@ fn entrance_and_exit_display(args: ScoreboardRenderArgs) -> Vec<Component> {
 }
 
 fn arsenal_display(args: ScoreboardRenderArgs) -> Vec<Component> {
-    let ScoreboardRenderArgs { player, side, .. } = args;
+    let ScoreboardRenderArgs {
+        player,
+        side,
+        theme,
+        ..
+    } = args;
 
     let row_offset = helpers::height_in_rows(&player.queue.pool, 3) + 1;
 
@@ -651,6 +709,7 @@ fn arsenal_display(args: ScoreboardRenderArgs) -> Vec<Component> {
         width_in_columns: 3,
         height_in_rows: helpers::height_in_rows(&player.arsenal, 3),
         enabled: false,
+        theme,
     };
 
     let arsenal_items = player
@@ -664,7 +723,8 @@ fn arsenal_display(args: ScoreboardRenderArgs) -> Vec<Component> {
 
             vec![
                 Component::Circle {
-                    fill_color: colors::arsenal_item_color(arsenal_item)
+                    fill_color: theme
+                        .arsenal_item_color(arsenal_item)
                         .with_alpha(colors::DISABLED_DEQUEUE_ARSENAL_ITEM_ALPHA),
                     shape: dequeue_circle::background_at(side, row, column),
                     on_click: None,
@@ -676,7 +736,7 @@ fn arsenal_display(args: ScoreboardRenderArgs) -> Vec<Component> {
                     on_click: None,
                 },
                 Component::Circle {
-                    fill_color: colors::OVERLAY,
+                    fill_color: theme.overlay,
                     shape: dequeue_circle::background_at(side, row, column),
                     on_click: None,
                 },
@@ -687,20 +747,25 @@ fn arsenal_display(args: ScoreboardRenderArgs) -> Vec<Component> {
 }
 
 fn arrows(args: ScoreboardRenderArgs) -> Vec<Component> {
-    let ScoreboardRenderArgs { player, side, .. } = args;
+    let ScoreboardRenderArgs {
+        player,
+        side,
+        theme,
+        ..
+    } = args;
 
     let pool_height_in_rows = helpers::height_in_rows(&player.queue.pool, 3);
     let entrance_and_exit_to_pool = if pool_height_in_rows == 0 {
         vec![]
     } else {
         vec![
-            arrow::up_arrow_above(side, pool_height_in_rows, 0),
-            arrow::down_arrow_above(side, pool_height_in_rows, 2),
+            arrow::up_arrow_above(side, pool_height_in_rows, 0, theme),
+            arrow::down_arrow_above(side, pool_height_in_rows, 2, theme),
         ]
     };
     let arsenal_to_entrance_and_exit = vec![
-        arrow::up_arrow_above(side, pool_height_in_rows + 1, 0),
-        arrow::down_arrow_above(side, pool_height_in_rows + 1, 2),
+        arrow::up_arrow_above(side, pool_height_in_rows + 1, 0, theme),
+        arrow::down_arrow_above(side, pool_height_in_rows + 1, 2, theme),
     ];
 
     entrance_and_exit_to_pool
@@ -712,7 +777,9 @@ fn arrows(args: ScoreboardRenderArgs) -> Vec<Component> {
 struct ScoreboardRenderArgs<'a> {
     pub player: &'a DequeueingPlayer,
     pub side: Side,
+    pub seat: Side,
     pub dequeues: &'a Vec<DequeueChoice>,
+    pub theme: &'a Theme,
 }
 
 const HUMAN: usize = 0;
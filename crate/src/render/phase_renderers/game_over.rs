@@ -1,14 +1,15 @@
 use crate::{
-    colors, helpers,
+    canvas_dimensions, colors, helpers,
     paint::{Component, ImageType},
     phase::GameOverPhase,
     render::{
-        arrow, arsenal_item_display,
+        self, arrow, arsenal_item_display,
         health_display::{ConstantHealthDisplay, FadingHealthDisplay},
         home_button,
         lerp::{LerpableComponent, Lerper},
         pill::Pill,
-        switch::{Switch, Switch5},
+        replay_button,
+        switch::{Motion, Switch, Switch5},
         Render,
     },
     shapes::{
@@ -16,6 +17,8 @@ use crate::{
         dequeue_circle::{self, CirclePosition},
     },
     side::Side,
+    text::{Font, TextAlign},
+    theme::Theme,
 };
 
 use nzscq::{
@@ -29,35 +32,49 @@ pub struct GameOverPhaseRenderer<'a> {
     previously_available_actions: &'a [Vec<NzscAction>; 2],
     previous_outcome: &'a [ActionPointsDestroyed; 2],
     scoreboard: &'a [FinishedPlayer; 2],
+    theme: &'a Theme,
+    motion: Motion,
 }
 
 impl<'a> GameOverPhaseRenderer<'a> {
-    pub fn new(phase: &'a GameOverPhase) -> GameOverPhaseRenderer<'a> {
+    pub fn new(
+        phase: &'a GameOverPhase,
+        theme: &'a Theme,
+        motion: Motion,
+    ) -> GameOverPhaseRenderer<'a> {
         GameOverPhaseRenderer {
             previous_scoreboard: &phase.previous_scoreboard,
             previously_available_actions: &phase.previously_available_actions,
             previous_outcome: &phase.previous_outcome,
             scoreboard: &phase.scoreboard,
+            theme,
+            motion,
         }
     }
 
-    fn human_entrance(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn human_entrance(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             vec![
                 vec![Component::Background {
-                    color: colors::BACKGROUND,
+                    color: self.theme.background,
                 }],
                 self.previous_health_displays(),
                 action_choosing_scoreboard_without_used_item(
                     self.action_choosing_human_args(),
                     self.human_action_displacement()
                         .and_then(|displacement| displacement.action.into()),
+                    self.theme,
                 ),
-                action_choosing_scoreboard(self.action_choosing_computer_args()),
+                action_choosing_scoreboard(self.action_choosing_computer_args(), self.theme),
                 vec![Component::Background {
-                    color: colors::OVERLAY,
+                    color: self.theme.overlay,
                 }],
-                entering_action(Side::Left, self.human_action_displacement(), &lerper),
+                entering_action(
+                    Side::Left,
+                    self.human_action_displacement(),
+                    &lerper,
+                    self.theme,
+                ),
             ]
             .into_iter()
             .flatten()
@@ -80,28 +97,35 @@ impl<'a> GameOverPhaseRenderer<'a> {
         })
     }
 
-    fn computer_entrance(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn computer_entrance(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             vec![
                 vec![Component::Background {
-                    color: colors::BACKGROUND,
+                    color: self.theme.background,
                 }],
                 self.previous_health_displays(),
                 action_choosing_scoreboard_without_used_item(
                     self.action_choosing_human_args(),
                     self.human_action_displacement()
                         .and_then(|displacement| displacement.action.into()),
+                    self.theme,
                 ),
                 action_choosing_scoreboard_without_used_item(
                     self.action_choosing_computer_args(),
                     self.computer_action_displacement()
                         .and_then(|displacement| displacement.action.into()),
+                    self.theme,
                 ),
                 vec![Component::Background {
-                    color: colors::OVERLAY,
+                    color: self.theme.overlay,
                 }],
-                stationary_action(Side::Left, self.human_action_displacement()),
-                entering_action(Side::Right, self.computer_action_displacement(), &lerper),
+                stationary_action(Side::Left, self.human_action_displacement(), self.theme),
+                entering_action(
+                    Side::Right,
+                    self.computer_action_displacement(),
+                    &lerper,
+                    self.theme,
+                ),
             ]
             .into_iter()
             .flatten()
@@ -124,36 +148,48 @@ impl<'a> GameOverPhaseRenderer<'a> {
         })
     }
 
-    fn fade(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn fade(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             vec![
                 vec![Component::Background {
-                    color: colors::BACKGROUND,
+                    color: self.theme.background,
                 }],
                 action_choosing_scoreboard_without_used_item(
                     self.action_choosing_human_args(),
                     self.human_action_displacement()
                         .and_then(|displacement| displacement.action.into()),
+                    self.theme,
                 ),
                 action_choosing_scoreboard_without_used_item(
                     self.action_choosing_computer_args(),
                     self.computer_action_displacement()
                         .and_then(|displacement| displacement.action.into()),
+                    self.theme,
                 ),
                 self.fade_case_non_fading_health_displays(),
                 vec![Component::Background {
-                    color: colors::OVERLAY,
+                    color: self.theme.overlay,
                 }],
                 self.fade_case_fading_health_displays(&lerper),
                 if self.did_computer_get_point() {
-                    fading_action(Side::Left, self.human_action_displacement(), &lerper)
+                    fading_action(
+                        Side::Left,
+                        self.human_action_displacement(),
+                        &lerper,
+                        self.theme,
+                    )
                 } else {
-                    stationary_action(Side::Left, self.human_action_displacement())
+                    stationary_action(Side::Left, self.human_action_displacement(), self.theme)
                 },
                 if self.did_human_get_point() {
-                    fading_action(Side::Right, self.computer_action_displacement(), &lerper)
+                    fading_action(
+                        Side::Right,
+                        self.computer_action_displacement(),
+                        &lerper,
+                        self.theme,
+                    )
                 } else {
-                    stationary_action(Side::Right, self.computer_action_displacement())
+                    stationary_action(Side::Right, self.computer_action_displacement(), self.theme)
                 },
             ]
             .into_iter()
@@ -162,35 +198,47 @@ impl<'a> GameOverPhaseRenderer<'a> {
         }
     }
 
-    fn exit(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn exit(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             vec![
                 vec![Component::Background {
-                    color: colors::BACKGROUND,
+                    color: self.theme.background,
                 }],
                 self.current_health_displays(),
                 action_choosing_scoreboard_without_used_item(
                     self.action_choosing_human_args(),
                     self.human_action_displacement()
                         .and_then(|displacement| displacement.action.into()),
+                    self.theme,
                 ),
                 action_choosing_scoreboard_without_used_item(
                     self.action_choosing_computer_args(),
                     self.computer_action_displacement()
                         .and_then(|displacement| displacement.action.into()),
+                    self.theme,
                 ),
                 vec![Component::Background {
-                    color: colors::OVERLAY,
+                    color: self.theme.overlay,
                 }],
                 if self.did_computer_get_point() {
                     vec![]
                 } else {
-                    exiting_action(Side::Left, self.human_action_displacement(), &lerper)
+                    exiting_action(
+                        Side::Left,
+                        self.human_action_displacement(),
+                        &lerper,
+                        self.theme,
+                    )
                 },
                 if self.did_human_get_point() {
                     vec![]
                 } else {
-                    exiting_action(Side::Right, self.computer_action_displacement(), &lerper)
+                    exiting_action(
+                        Side::Right,
+                        self.computer_action_displacement(),
+                        &lerper,
+                        self.theme,
+                    )
                 },
             ]
             .into_iter()
@@ -199,20 +247,22 @@ impl<'a> GameOverPhaseRenderer<'a> {
         }
     }
 
-    fn results(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn results(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             vec![
                 vec![Component::Background {
-                    color: colors::BACKGROUND,
+                    color: self.theme.background,
                 }],
                 self.loser_health_display(),
-                action_choosing_scoreboard(self.finished_human_args()),
-                action_choosing_scoreboard(self.finished_computer_args()),
+                action_choosing_scoreboard(self.finished_human_args(), self.theme),
+                action_choosing_scoreboard(self.finished_computer_args(), self.theme),
                 vec![Component::Background {
-                    color: colors::OVERLAY,
+                    color: self.theme.overlay,
                 }],
                 self.winner_health_display(),
-                home_button(&lerper),
+                vec![self.final_score(&lerper)],
+                home_button(&lerper, self.theme),
+                replay_button(&lerper, self.theme),
             ]
             .into_iter()
             .flatten()
@@ -225,6 +275,7 @@ impl<'a> GameOverPhaseRenderer<'a> {
             player: &self.previous_scoreboard[HUMAN],
             side: Side::Left,
             actions: &self.previously_available_actions[HUMAN],
+            theme: self.theme,
         }
     }
 
@@ -233,6 +284,7 @@ impl<'a> GameOverPhaseRenderer<'a> {
             player: &self.previous_scoreboard[COMPUTER],
             side: Side::Right,
             actions: &self.previously_available_actions[COMPUTER],
+            theme: self.theme,
         }
     }
 
@@ -241,6 +293,7 @@ impl<'a> GameOverPhaseRenderer<'a> {
             player: &self.scoreboard[HUMAN],
             side: Side::Left,
             actions: &NO_ACTIONS,
+            theme: self.theme,
         }
     }
 
@@ -249,6 +302,7 @@ impl<'a> GameOverPhaseRenderer<'a> {
             player: &self.scoreboard[COMPUTER],
             side: Side::Right,
             actions: &NO_ACTIONS,
+            theme: self.theme,
         }
     }
 
@@ -256,10 +310,12 @@ impl<'a> GameOverPhaseRenderer<'a> {
         let human_display = ConstantHealthDisplay {
             side: Side::Left,
             health: self.previous_human_health(),
+            theme: self.theme,
         };
         let computer_display = ConstantHealthDisplay {
             side: Side::Right,
             health: self.previous_computer_health(),
+            theme: self.theme,
         };
 
         vec![human_display, computer_display]
@@ -274,6 +330,7 @@ impl<'a> GameOverPhaseRenderer<'a> {
             lerper.lerp1(FadingHealthDisplay {
                 side: Side::Left,
                 starting_health: self.previous_human_health(),
+                theme: self.theme,
             })
         } else {
             vec![]
@@ -282,6 +339,7 @@ impl<'a> GameOverPhaseRenderer<'a> {
             lerper.lerp1(FadingHealthDisplay {
                 side: Side::Right,
                 starting_health: self.previous_computer_health(),
+                theme: self.theme,
             })
         } else {
             vec![]
@@ -300,6 +358,7 @@ impl<'a> GameOverPhaseRenderer<'a> {
             ConstantHealthDisplay {
                 side: Side::Left,
                 health: self.previous_human_health(),
+                theme: self.theme,
             }
             .render(())
         };
@@ -309,6 +368,7 @@ impl<'a> GameOverPhaseRenderer<'a> {
             ConstantHealthDisplay {
                 side: Side::Right,
                 health: self.previous_computer_health(),
+                theme: self.theme,
             }
             .render(())
         };
@@ -324,11 +384,13 @@ impl<'a> GameOverPhaseRenderer<'a> {
             side: Side::Left,
             health: self.previous_human_health()
                 - if self.did_computer_get_point() { 1 } else { 0 },
+            theme: self.theme,
         };
         let computer_display = ConstantHealthDisplay {
             side: Side::Right,
             health: self.previous_computer_health()
                 - if self.did_human_get_point() { 1 } else { 0 },
+            theme: self.theme,
         };
 
         vec![human_display, computer_display]
@@ -338,17 +400,39 @@ impl<'a> GameOverPhaseRenderer<'a> {
             .collect()
     }
 
+    fn final_score(&self, lerper: &Lerper) -> Component {
+        let font = render::label_font(FINAL_SCORE_FONT_SIZE);
+
+        lerper.lerp1(LerpableComponent::Text {
+            text: format!(
+                "{} - {}",
+                self.scoreboard[HUMAN].points, self.scoreboard[COMPUTER].points
+            ),
+            align: TextAlign::Center,
+            start_font: Font { size: 0.0, ..font },
+            end_font: font,
+            start_color: self.theme.screen_text.with_alpha(0),
+            end_color: self.theme.screen_text,
+            start_anchor: (canvas_dimensions::CENTER_X, canvas_dimensions::CENTER_Y),
+            end_anchor: (canvas_dimensions::CENTER_X, FINAL_SCORE_Y),
+            max_width: None,
+            on_click: None,
+        })
+    }
+
     fn loser_health_display(&self) -> Vec<Component> {
         if self.scoreboard[HUMAN].points == 5 {
             ConstantHealthDisplay {
                 side: Side::Right,
                 health: 0,
+                theme: self.theme,
             }
             .render(())
         } else {
             ConstantHealthDisplay {
                 side: Side::Left,
                 health: 0,
+                theme: self.theme,
             }
             .render(())
         }
@@ -359,11 +443,13 @@ impl<'a> GameOverPhaseRenderer<'a> {
             ConstantHealthDisplay {
                 side: Side::Left,
                 health: helpers::opponent_points_to_own_health(self.scoreboard[COMPUTER].points),
+                theme: self.theme,
             }
         } else {
             ConstantHealthDisplay {
                 side: Side::Right,
                 health: helpers::opponent_points_to_own_health(self.scoreboard[HUMAN].points),
+                theme: self.theme,
             }
         }
         .render(())
@@ -402,10 +488,10 @@ impl<'a> Render<f64> for GameOverPhaseRenderer<'a> {
             (0.00..0.12, human_entrance),
             (0.12..0.24, computer_entrance),
             (0.24..0.68, fade),
-            (0.68..0.80, exit),
-            (0.80..=1.00, results),
+            (0.68..RESULTS_START, exit),
+            (RESULTS_START..=1.00, results),
         )
-        .case(completion_factor)
+        .case(completion_factor, self.motion)
         .expect("should have legal completion range")
     }
 }
@@ -413,12 +499,13 @@ impl<'a> Render<f64> for GameOverPhaseRenderer<'a> {
 fn action_choosing_scoreboard_without_used_item<T: QueueArsenal>(
     args: ActionChoosingRenderArgs<T>,
     used_item: Option<ArsenalItem>,
+    theme: &Theme,
 ) -> Vec<Component> {
     vec![
         action_choosing_pool_display(&args),
         action_choosing_entrance_and_exit_display(&args),
         action_choosing_arsenal_display_without_used_item(&args, used_item),
-        arrows(&args),
+        arrows(&args, theme),
     ]
     .into_iter()
     .flatten()
@@ -427,12 +514,13 @@ fn action_choosing_scoreboard_without_used_item<T: QueueArsenal>(
 
 fn action_choosing_scoreboard<T: QueueArsenal>(
     args: ActionChoosingRenderArgs<T>,
+    theme: &Theme,
 ) -> Vec<Component> {
     vec![
         action_choosing_pool_display(&args),
         action_choosing_entrance_and_exit_display(&args),
         action_choosing_arsenal_display(&args),
-        arrows(&args),
+        arrows(&args, theme),
     ]
     .into_iter()
     .flatten()
@@ -446,6 +534,7 @@ fn action_choosing_pool_display<T: QueueArsenal>(
         player,
         side,
         actions,
+        theme,
     } = args;
     let side = *side;
 
@@ -466,6 +555,7 @@ fn action_choosing_pool_display<T: QueueArsenal>(
         width_in_columns: 3,
         height_in_rows: helpers::height_in_rows(&player.queue().pool, 3),
         enabled: mirror_enabled,
+        theme,
     };
 
     let pool = player
@@ -487,7 +577,7 @@ fn action_choosing_pool_display<T: QueueArsenal>(
 
                 vec![
                     Component::Circle {
-                        fill_color: colors::move_color(move_),
+                        fill_color: theme.move_color(move_),
                         shape: dequeue_circle::background_at(side, row, column),
                         on_click: None,
                     },
@@ -511,6 +601,7 @@ fn action_choosing_pool_display<T: QueueArsenal>(
                     false,
                     None,
                     CirclePosition { side, column, row },
+                    theme,
                 )
             }
         });
@@ -521,7 +612,12 @@ fn action_choosing_pool_display<T: QueueArsenal>(
 fn action_choosing_entrance_and_exit_display<T: QueueArsenal>(
     args: &ActionChoosingRenderArgs<T>,
 ) -> Vec<Component> {
-    let ActionChoosingRenderArgs { player, side, .. } = args;
+    let ActionChoosingRenderArgs {
+        player,
+        side,
+        theme,
+        ..
+    } = args;
     let side = *side;
 
     let entrance = player.queue().entrance;
@@ -538,6 +634,7 @@ fn action_choosing_entrance_and_exit_display<T: QueueArsenal>(
         width_in_columns: 3,
         height_in_rows: 1,
         enabled: false,
+        theme,
     };
 
     vec![
@@ -552,6 +649,7 @@ fn action_choosing_entrance_and_exit_display<T: QueueArsenal>(
                     column: 0,
                     row,
                 },
+                theme,
             )
         }),
         exit.map(|exiting_item| {
@@ -564,6 +662,7 @@ fn action_choosing_entrance_and_exit_display<T: QueueArsenal>(
                     column: 2,
                     row,
                 },
+                theme,
             )
         }),
     ]
@@ -583,7 +682,12 @@ fn action_choosing_arsenal_display_without_used_item<T: QueueArsenal>(
     args: &ActionChoosingRenderArgs<T>,
     used_item: Option<ArsenalItem>,
 ) -> Vec<Component> {
-    let ActionChoosingRenderArgs { player, side, .. } = args;
+    let ActionChoosingRenderArgs {
+        player,
+        side,
+        theme,
+        ..
+    } = args;
     let side = *side;
 
     let row_offset = helpers::height_in_rows(&player.queue().pool, 3) + 1;
@@ -597,6 +701,7 @@ fn action_choosing_arsenal_display_without_used_item<T: QueueArsenal>(
         width_in_columns: 3,
         height_in_rows: helpers::height_in_rows(&player.arsenal(), 3),
         enabled: true,
+        theme,
     };
 
     let arsenal_items = player
@@ -613,7 +718,7 @@ fn action_choosing_arsenal_display_without_used_item<T: QueueArsenal>(
 
                 vec![
                     Component::Circle {
-                        fill_color: colors::arsenal_item_color(arsenal_item),
+                        fill_color: theme.arsenal_item_color(arsenal_item),
                         shape: dequeue_circle::background_at(side, row, column),
                         on_click: None,
                     },
@@ -630,7 +735,7 @@ fn action_choosing_arsenal_display_without_used_item<T: QueueArsenal>(
     pill.render(()).into_iter().chain(arsenal_items).collect()
 }
 
-fn arrows<T: ArrowRenderArgs>(args: &T) -> Vec<Component> {
+fn arrows<T: ArrowRenderArgs>(args: &T, theme: &Theme) -> Vec<Component> {
     let side = args.side();
     let pool = args.pool();
 
@@ -639,13 +744,13 @@ fn arrows<T: ArrowRenderArgs>(args: &T) -> Vec<Component> {
         vec![]
     } else {
         vec![
-            arrow::up_arrow_above(side, pool_height_in_rows, 0),
-            arrow::down_arrow_above(side, pool_height_in_rows, 2),
+            arrow::up_arrow_above(side, pool_height_in_rows, 0, theme),
+            arrow::down_arrow_above(side, pool_height_in_rows, 2, theme),
         ]
     };
     let arsenal_to_entrance_and_exit = vec![
-        arrow::up_arrow_above(side, pool_height_in_rows + 1, 0),
-        arrow::down_arrow_above(side, pool_height_in_rows + 1, 2),
+        arrow::up_arrow_above(side, pool_height_in_rows + 1, 0, theme),
+        arrow::down_arrow_above(side, pool_height_in_rows + 1, 2, theme),
     ];
 
     entrance_and_exit_to_pool
@@ -720,7 +825,12 @@ fn position_in_arsenal_of<T: QueueArsenal>(
     })
 }
 
-fn entering_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Vec<Component> {
+fn entering_action(
+    side: Side,
+    visit: Option<ActionVisit>,
+    lerper: &Lerper,
+    theme: &Theme,
+) -> Vec<Component> {
     visit
         .map(|visit| {
             let ActionVisit { action, start, .. } = visit;
@@ -730,8 +840,8 @@ fn entering_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> V
                 NzscAction::Mirror(move_) => {
                     vec![
                         LerpableComponent::Circle {
-                            start_color: colors::move_color(move_),
-                            end_color: colors::move_color(move_),
+                            start_color: theme.move_color(move_),
+                            end_color: theme.move_color(move_),
                             start_shape: dequeue_circle::background_at(
                                 start.side,
                                 start.row,
@@ -769,8 +879,8 @@ fn entering_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> V
                 }
                 NzscAction::Move(move_) => vec![
                     LerpableComponent::Circle {
-                        start_color: colors::move_color(move_),
-                        end_color: colors::move_color(move_),
+                        start_color: theme.move_color(move_),
+                        end_color: theme.move_color(move_),
                         start_shape: dequeue_circle::background_at(
                             start.side,
                             start.row,
@@ -800,7 +910,7 @@ fn entering_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> V
         .collect()
 }
 
-fn stationary_action(side: Side, visit: Option<ActionVisit>) -> Vec<Component> {
+fn stationary_action(side: Side, visit: Option<ActionVisit>, theme: &Theme) -> Vec<Component> {
     visit
         .map(|visit| {
             let ActionVisit { action, .. } = visit;
@@ -810,7 +920,7 @@ fn stationary_action(side: Side, visit: Option<ActionVisit>) -> Vec<Component> {
                 NzscAction::Mirror(move_) => {
                     vec![
                         Component::Circle {
-                            fill_color: colors::move_color(move_),
+                            fill_color: theme.move_color(move_),
                             shape: action_focus::background(side),
                             on_click: None,
                         },
@@ -831,7 +941,7 @@ fn stationary_action(side: Side, visit: Option<ActionVisit>) -> Vec<Component> {
                 }
                 NzscAction::Move(move_) => vec![
                     Component::Circle {
-                        fill_color: colors::move_color(move_),
+                        fill_color: theme.move_color(move_),
                         shape: action_focus::background(side),
                         on_click: None,
                     },
@@ -849,7 +959,12 @@ fn stationary_action(side: Side, visit: Option<ActionVisit>) -> Vec<Component> {
         .collect()
 }
 
-fn fading_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Vec<Component> {
+fn fading_action(
+    side: Side,
+    visit: Option<ActionVisit>,
+    lerper: &Lerper,
+    theme: &Theme,
+) -> Vec<Component> {
     let sublerper = lerper.sub_lerper(0.0..colors::PORTION_OF_DURATION_SPENT_FADING);
 
     visit
@@ -861,8 +976,8 @@ fn fading_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Vec
                 NzscAction::Mirror(move_) => {
                     vec![
                         LerpableComponent::Circle {
-                            start_color: colors::move_color(move_),
-                            end_color: colors::move_color(move_).with_alpha(0),
+                            start_color: theme.move_color(move_),
+                            end_color: theme.move_color(move_).with_alpha(0),
                             start_shape: action_focus::background(side),
                             end_shape: action_focus::background(side),
                             on_click: None,
@@ -888,8 +1003,8 @@ fn fading_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Vec
                 }
                 NzscAction::Move(move_) => vec![
                     LerpableComponent::Circle {
-                        start_color: colors::move_color(move_),
-                        end_color: colors::move_color(move_).with_alpha(0),
+                        start_color: theme.move_color(move_),
+                        end_color: theme.move_color(move_).with_alpha(0),
                         start_shape: action_focus::background(side),
                         end_shape: action_focus::background(side),
                         on_click: None,
@@ -911,7 +1026,12 @@ fn fading_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Vec
         .collect()
 }
 
-fn exiting_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Vec<Component> {
+fn exiting_action(
+    side: Side,
+    visit: Option<ActionVisit>,
+    lerper: &Lerper,
+    theme: &Theme,
+) -> Vec<Component> {
     if let Some(visit) = visit {
         let ActionVisit { action, end, .. } = visit;
         match action {
@@ -921,8 +1041,8 @@ fn exiting_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Ve
                 if let Some(end) = end {
                     vec![
                         LerpableComponent::Circle {
-                            start_color: colors::move_color(move_),
-                            end_color: colors::move_color(move_),
+                            start_color: theme.move_color(move_),
+                            end_color: theme.move_color(move_),
                             start_shape: action_focus::background(side),
                             end_shape: dequeue_circle::background_at(end.side, end.row, end.column),
                             on_click: None,
@@ -954,8 +1074,8 @@ fn exiting_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Ve
 
                     vec![
                         LerpableComponent::Circle {
-                            start_color: colors::move_color(move_),
-                            end_color: colors::move_color(move_).with_alpha(0),
+                            start_color: theme.move_color(move_),
+                            end_color: theme.move_color(move_).with_alpha(0),
                             start_shape: action_focus::background(side),
                             end_shape: action_focus::expanded_background(side),
                             on_click: None,
@@ -988,8 +1108,8 @@ fn exiting_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Ve
                 if let Some(end) = end {
                     vec![
                         LerpableComponent::Circle {
-                            start_color: colors::move_color(move_),
-                            end_color: colors::move_color(move_),
+                            start_color: theme.move_color(move_),
+                            end_color: theme.move_color(move_),
                             start_shape: action_focus::background(side),
                             end_shape: dequeue_circle::background_at(end.side, end.row, end.column),
                             on_click: None,
@@ -1012,8 +1132,8 @@ fn exiting_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Ve
 
                     vec![
                         LerpableComponent::Circle {
-                            start_color: colors::move_color(move_),
-                            end_color: colors::move_color(move_).with_alpha(0),
+                            start_color: theme.move_color(move_),
+                            end_color: theme.move_color(move_).with_alpha(0),
                             start_shape: action_focus::background(side),
                             end_shape: action_focus::expanded_background(side),
                             on_click: None,
@@ -1067,6 +1187,7 @@ struct ActionChoosingRenderArgs<'a, T: QueueArsenal> {
     pub player: &'a T,
     pub side: Side,
     pub actions: &'a [NzscAction],
+    pub theme: &'a Theme,
 }
 
 trait ArrowRenderArgs {
@@ -1093,3 +1214,8 @@ const HUMAN: usize = 0;
 const COMPUTER: usize = 1;
 
 const NO_ACTIONS: [NzscAction; 0] = [];
+
+pub const RESULTS_START: f64 = 0.80;
+
+const FINAL_SCORE_FONT_SIZE: f64 = 96.0;
+const FINAL_SCORE_Y: f64 = canvas_dimensions::CENTER_Y + 32.0;
@@ -11,4 +11,4 @@ pub use action_choosing::ActionChoosingPhaseRenderer;
 mod subsequent_dequeueing;
 pub use subsequent_dequeueing::SubsequentDequeueingPhaseRenderer;
 mod game_over;
-pub use game_over::GameOverPhaseRenderer;
+pub use game_over::{GameOverPhaseRenderer, RESULTS_START};
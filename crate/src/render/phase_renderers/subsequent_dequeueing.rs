@@ -1,6 +1,9 @@
 use crate::{
     click::Action,
-    colors, helpers,
+    colors,
+    forecast::QueueForecast,
+    helpers,
+    i18n::Messages,
     paint::{Component, ImageType},
     phase::{ChooseSubsequentDequeuePhase, MoveInspectorState},
     render::{
@@ -8,9 +11,10 @@ use crate::{
         health_display::{ConstantHealthDisplay, FadingHealthDisplay},
         lerp::{LerpableComponent, Lerper},
         move_inspector::{MoveInspector, MoveInspectorArgs},
-        move_inspector_buttons::RenderButton,
+        move_inspector_buttons::{ForecastQueueButton, RenderButton},
         pill::Pill,
-        switch::{Switch, Switch5},
+        queue_forecast::QueueForecastDisplay,
+        switch::{Motion, Switch, Switch5},
         Render,
     },
     shapes::{
@@ -18,6 +22,7 @@ use crate::{
         dequeue_circle::{self, CirclePosition},
     },
     side::Side,
+    theme::Theme,
 };
 
 use nzscq::{
@@ -32,39 +37,62 @@ pub struct SubsequentDequeueingPhaseRenderer<'a> {
     previous_outcome: &'a [ActionPointsDestroyed; 2],
     scoreboard: &'a [DequeueingPlayer; 2],
     available_dequeues: &'a [Vec<DequeueChoice>; 2],
+    forecasts: &'a [Vec<QueueForecast>; 2],
     inspector_state: MoveInspectorState,
+    theme: &'a Theme,
+    motion: Motion,
+    messages: Messages,
+    seat: Side,
 }
 
 impl<'a> SubsequentDequeueingPhaseRenderer<'a> {
-    pub fn new(phase: &'a ChooseSubsequentDequeuePhase) -> SubsequentDequeueingPhaseRenderer<'a> {
+    pub fn new(
+        phase: &'a ChooseSubsequentDequeuePhase,
+        theme: &'a Theme,
+        motion: Motion,
+        messages: Messages,
+        seat: Side,
+    ) -> SubsequentDequeueingPhaseRenderer<'a> {
         SubsequentDequeueingPhaseRenderer {
             previous_scoreboard: &phase.previous_scoreboard,
             previously_available_actions: &phase.previously_available_actions,
             previous_outcome: &phase.previous_outcome,
             scoreboard: &phase.scoreboard,
             available_dequeues: &phase.available_dequeues,
+            forecasts: &phase.forecasts,
             inspector_state: phase.inspector_state,
+            theme,
+            motion,
+            messages,
+            seat,
         }
     }
 
-    fn human_entrance(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn human_entrance(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             vec![
                 vec![Component::Background {
-                    color: colors::BACKGROUND,
+                    color: self.theme.background,
                 }],
                 self.previous_health_displays(),
                 action_choosing_scoreboard_without_used_item(
                     self.action_choosing_human_args(),
                     self.human_action_displacement()
                         .and_then(|displacement| displacement.action.into()),
+                    self.theme,
                 ),
-                action_choosing_scoreboard(self.action_choosing_computer_args()),
-                self.inspector_state.render_button(false),
+                action_choosing_scoreboard(self.action_choosing_computer_args(), self.theme),
+                self.inspector_state
+                    .render_button(false, self.theme, self.messages),
                 vec![Component::Background {
-                    color: colors::OVERLAY,
+                    color: self.theme.overlay,
                 }],
-                entering_action(Side::Left, self.human_action_displacement(), &lerper),
+                entering_action(
+                    Side::Left,
+                    self.human_action_displacement(),
+                    &lerper,
+                    self.theme,
+                ),
             ]
             .into_iter()
             .flatten()
@@ -87,29 +115,37 @@ impl<'a> SubsequentDequeueingPhaseRenderer<'a> {
         })
     }
 
-    fn computer_entrance(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn computer_entrance(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             vec![
                 vec![Component::Background {
-                    color: colors::BACKGROUND,
+                    color: self.theme.background,
                 }],
                 self.previous_health_displays(),
                 action_choosing_scoreboard_without_used_item(
                     self.action_choosing_human_args(),
                     self.human_action_displacement()
                         .and_then(|displacement| displacement.action.into()),
+                    self.theme,
                 ),
                 action_choosing_scoreboard_without_used_item(
                     self.action_choosing_computer_args(),
                     self.computer_action_displacement()
                         .and_then(|displacement| displacement.action.into()),
+                    self.theme,
                 ),
-                self.inspector_state.render_button(false),
+                self.inspector_state
+                    .render_button(false, self.theme, self.messages),
                 vec![Component::Background {
-                    color: colors::OVERLAY,
+                    color: self.theme.overlay,
                 }],
-                stationary_action(Side::Left, self.human_action_displacement()),
-                entering_action(Side::Right, self.computer_action_displacement(), &lerper),
+                stationary_action(Side::Left, self.human_action_displacement(), self.theme),
+                entering_action(
+                    Side::Right,
+                    self.computer_action_displacement(),
+                    &lerper,
+                    self.theme,
+                ),
             ]
             .into_iter()
             .flatten()
@@ -132,37 +168,50 @@ impl<'a> SubsequentDequeueingPhaseRenderer<'a> {
         })
     }
 
-    fn fade(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn fade(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             vec![
                 vec![Component::Background {
-                    color: colors::BACKGROUND,
+                    color: self.theme.background,
                 }],
                 action_choosing_scoreboard_without_used_item(
                     self.action_choosing_human_args(),
                     self.human_action_displacement()
                         .and_then(|displacement| displacement.action.into()),
+                    self.theme,
                 ),
                 action_choosing_scoreboard_without_used_item(
                     self.action_choosing_computer_args(),
                     self.computer_action_displacement()
                         .and_then(|displacement| displacement.action.into()),
+                    self.theme,
                 ),
-                self.inspector_state.render_button(false),
+                self.inspector_state
+                    .render_button(false, self.theme, self.messages),
                 self.fade_case_non_fading_health_displays(),
                 vec![Component::Background {
-                    color: colors::OVERLAY,
+                    color: self.theme.overlay,
                 }],
                 self.fade_case_fading_health_displays(&lerper),
                 if self.did_computer_get_point() {
-                    fading_action(Side::Left, self.human_action_displacement(), &lerper)
+                    fading_action(
+                        Side::Left,
+                        self.human_action_displacement(),
+                        &lerper,
+                        self.theme,
+                    )
                 } else {
-                    stationary_action(Side::Left, self.human_action_displacement())
+                    stationary_action(Side::Left, self.human_action_displacement(), self.theme)
                 },
                 if self.did_human_get_point() {
-                    fading_action(Side::Right, self.computer_action_displacement(), &lerper)
+                    fading_action(
+                        Side::Right,
+                        self.computer_action_displacement(),
+                        &lerper,
+                        self.theme,
+                    )
                 } else {
-                    stationary_action(Side::Right, self.computer_action_displacement())
+                    stationary_action(Side::Right, self.computer_action_displacement(), self.theme)
                 },
             ]
             .into_iter()
@@ -171,36 +220,49 @@ impl<'a> SubsequentDequeueingPhaseRenderer<'a> {
         }
     }
 
-    fn exit(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
+    fn exit(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
         move |lerper| {
             vec![
                 vec![Component::Background {
-                    color: colors::BACKGROUND,
+                    color: self.theme.background,
                 }],
                 self.current_health_displays(),
                 action_choosing_scoreboard_without_used_item(
                     self.action_choosing_human_args(),
                     self.human_action_displacement()
                         .and_then(|displacement| displacement.action.into()),
+                    self.theme,
                 ),
                 action_choosing_scoreboard_without_used_item(
                     self.action_choosing_computer_args(),
                     self.computer_action_displacement()
                         .and_then(|displacement| displacement.action.into()),
+                    self.theme,
                 ),
-                self.inspector_state.render_button(false),
+                self.inspector_state
+                    .render_button(false, self.theme, self.messages),
                 vec![Component::Background {
-                    color: colors::OVERLAY,
+                    color: self.theme.overlay,
                 }],
                 if self.did_computer_get_point() {
                     vec![]
                 } else {
-                    exiting_action(Side::Left, self.human_action_displacement(), &lerper)
+                    exiting_action(
+                        Side::Left,
+                        self.human_action_displacement(),
+                        &lerper,
+                        self.theme,
+                    )
                 },
                 if self.did_human_get_point() {
                     vec![]
                 } else {
-                    exiting_action(Side::Right, self.computer_action_displacement(), &lerper)
+                    exiting_action(
+                        Side::Right,
+                        self.computer_action_displacement(),
+                        &lerper,
+                        self.theme,
+                    )
                 },
             ]
             .into_iter()
@@ -209,25 +271,24 @@ impl<'a> SubsequentDequeueingPhaseRenderer<'a> {
         }
     }
 
-    fn dequeues_or_inspector(&'a self) -> impl 'a + FnOnce(Lerper) -> Vec<Component> {
-        move |_| {
-            if self.inspector_state == MoveInspectorState::NotInspecting {
-                self.dequeues()
-            } else {
-                self.move_inspector()
-            }
+    fn dequeues_or_inspector(&'a self) -> impl 'a + Fn(Lerper) -> Vec<Component> {
+        move |_| match self.inspector_state {
+            MoveInspectorState::NotInspecting => self.dequeues(),
+            MoveInspectorState::Forecasting(side) => self.queue_forecast(side),
+            _ => self.move_inspector(),
         }
     }
 
     fn dequeues(&self) -> Vec<Component> {
         vec![
             vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }],
             self.current_health_displays(),
-            dequeueing_scoreboard(self.dequeueing_human_args()),
-            dequeueing_scoreboard(self.dequeueing_computer_args()),
-            self.inspector_state.render_button(true),
+            dequeueing_scoreboard(self.dequeueing_human_args(), self.theme),
+            dequeueing_scoreboard(self.dequeueing_computer_args(), self.theme),
+            self.inspector_state
+                .render_button(true, self.theme, self.messages),
         ]
         .into_iter()
         .flatten()
@@ -239,6 +300,7 @@ impl<'a> SubsequentDequeueingPhaseRenderer<'a> {
             player: &self.previous_scoreboard[HUMAN],
             side: Side::Left,
             actions: &self.previously_available_actions[HUMAN],
+            theme: self.theme,
         }
     }
 
@@ -247,6 +309,7 @@ impl<'a> SubsequentDequeueingPhaseRenderer<'a> {
             player: &self.previous_scoreboard[COMPUTER],
             side: Side::Right,
             actions: &self.previously_available_actions[COMPUTER],
+            theme: self.theme,
         }
     }
 
@@ -254,7 +317,9 @@ impl<'a> SubsequentDequeueingPhaseRenderer<'a> {
         DequeueingRenderArgs {
             player: &self.scoreboard[HUMAN],
             side: Side::Left,
+            seat: self.seat,
             dequeues: &self.available_dequeues[HUMAN],
+            theme: self.theme,
         }
     }
 
@@ -262,7 +327,9 @@ impl<'a> SubsequentDequeueingPhaseRenderer<'a> {
         DequeueingRenderArgs {
             player: &self.scoreboard[COMPUTER],
             side: Side::Right,
+            seat: self.seat,
             dequeues: &self.available_dequeues[COMPUTER],
+            theme: self.theme,
         }
     }
 
@@ -270,10 +337,12 @@ impl<'a> SubsequentDequeueingPhaseRenderer<'a> {
         let human_display = ConstantHealthDisplay {
             side: Side::Left,
             health: self.previous_human_health(),
+            theme: self.theme,
         };
         let computer_display = ConstantHealthDisplay {
             side: Side::Right,
             health: self.previous_computer_health(),
+            theme: self.theme,
         };
 
         vec![human_display, computer_display]
@@ -288,6 +357,7 @@ impl<'a> SubsequentDequeueingPhaseRenderer<'a> {
             lerper.lerp1(FadingHealthDisplay {
                 side: Side::Left,
                 starting_health: self.previous_human_health(),
+                theme: self.theme,
             })
         } else {
             vec![]
@@ -296,6 +366,7 @@ impl<'a> SubsequentDequeueingPhaseRenderer<'a> {
             lerper.lerp1(FadingHealthDisplay {
                 side: Side::Right,
                 starting_health: self.previous_computer_health(),
+                theme: self.theme,
             })
         } else {
             vec![]
@@ -314,6 +385,7 @@ impl<'a> SubsequentDequeueingPhaseRenderer<'a> {
             ConstantHealthDisplay {
                 side: Side::Left,
                 health: self.previous_human_health(),
+                theme: self.theme,
             }
             .render(())
         };
@@ -323,6 +395,7 @@ impl<'a> SubsequentDequeueingPhaseRenderer<'a> {
             ConstantHealthDisplay {
                 side: Side::Right,
                 health: self.previous_computer_health(),
+                theme: self.theme,
             }
             .render(())
         };
@@ -338,11 +411,13 @@ impl<'a> SubsequentDequeueingPhaseRenderer<'a> {
             side: Side::Left,
             health: self.previous_human_health()
                 - if self.did_computer_get_point() { 1 } else { 0 },
+            theme: self.theme,
         };
         let computer_display = ConstantHealthDisplay {
             side: Side::Right,
             health: self.previous_computer_health()
                 - if self.did_human_get_point() { 1 } else { 0 },
+            theme: self.theme,
         };
 
         vec![human_display, computer_display]
@@ -375,18 +450,30 @@ impl<'a> SubsequentDequeueingPhaseRenderer<'a> {
     fn move_inspector(&self) -> Vec<Component> {
         vec![
             vec![Component::Background {
-                color: colors::BACKGROUND,
+                color: self.theme.background,
             }],
             self.current_health_displays(),
             self.human_move_inspector(),
             self.computer_move_inspector(),
-            self.inspector_state.render_button(true),
+            self.inspector_state
+                .render_button(true, self.theme, self.messages),
+            ForecastQueueButton.render((self.theme, self.messages)),
         ]
         .into_iter()
         .flatten()
         .collect()
     }
 
+    fn queue_forecast(&self, side: Side) -> Vec<Component> {
+        QueueForecastDisplay {
+            side,
+            forecasts: &self.forecasts[side as usize],
+            theme: self.theme,
+            messages: self.messages,
+        }
+        .render(())
+    }
+
     fn human_move_inspector(&self) -> Vec<Component> {
         MoveInspector::new(self.human_move_inspector_args()).render(())
     }
@@ -396,6 +483,7 @@ impl<'a> SubsequentDequeueingPhaseRenderer<'a> {
             side: Side::Left,
             player: self.dequeueing_human_args().player,
             inspected_move: self.inspector_state.move_(),
+            theme: self.theme,
         }
     }
 
@@ -408,6 +496,7 @@ impl<'a> SubsequentDequeueingPhaseRenderer<'a> {
             side: Side::Right,
             player: self.dequeueing_computer_args().player,
             inspected_move: self.inspector_state.move_(),
+            theme: self.theme,
         }
     }
 }
@@ -427,24 +516,29 @@ impl<'a> Render<f64> for SubsequentDequeueingPhaseRenderer<'a> {
             (0.85..1.00, exit),
             (1.00..=1.00, dequeues_or_inspector),
         )
-        .case(completion_factor)
+        .case(completion_factor, self.motion)
         .expect("should have legal completion range")
     }
 }
 
-fn dequeueing_scoreboard(args: DequeueingRenderArgs) -> Vec<Component> {
+fn dequeueing_scoreboard(args: DequeueingRenderArgs, theme: &Theme) -> Vec<Component> {
     vec![
         dequeueing_pool_display(&args),
         dequeueing_entrance_decline_and_exit_display(&args),
         dequeueing_arsenal_display(&args),
-        arrows(&args),
+        arrows(&args, theme),
     ]
     .into_iter()
     .flatten()
     .collect()
 }
 
-fn entering_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Vec<Component> {
+fn entering_action(
+    side: Side,
+    visit: Option<ActionVisit>,
+    lerper: &Lerper,
+    theme: &Theme,
+) -> Vec<Component> {
     visit
         .map(|visit| {
             let ActionVisit { action, start, .. } = visit;
@@ -454,8 +548,8 @@ fn entering_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> V
                 NzscAction::Mirror(move_) => {
                     vec![
                         LerpableComponent::Circle {
-                            start_color: colors::move_color(move_),
-                            end_color: colors::move_color(move_),
+                            start_color: theme.move_color(move_),
+                            end_color: theme.move_color(move_),
                             start_shape: dequeue_circle::background_at(
                                 start.side,
                                 start.row,
@@ -493,8 +587,8 @@ fn entering_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> V
                 }
                 NzscAction::Move(move_) => vec![
                     LerpableComponent::Circle {
-                        start_color: colors::move_color(move_),
-                        end_color: colors::move_color(move_),
+                        start_color: theme.move_color(move_),
+                        end_color: theme.move_color(move_),
                         start_shape: dequeue_circle::background_at(
                             start.side,
                             start.row,
@@ -524,7 +618,7 @@ fn entering_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> V
         .collect()
 }
 
-fn stationary_action(side: Side, visit: Option<ActionVisit>) -> Vec<Component> {
+fn stationary_action(side: Side, visit: Option<ActionVisit>, theme: &Theme) -> Vec<Component> {
     visit
         .map(|visit| {
             let ActionVisit { action, .. } = visit;
@@ -534,7 +628,7 @@ fn stationary_action(side: Side, visit: Option<ActionVisit>) -> Vec<Component> {
                 NzscAction::Mirror(move_) => {
                     vec![
                         Component::Circle {
-                            fill_color: colors::move_color(move_),
+                            fill_color: theme.move_color(move_),
                             shape: action_focus::background(side),
                             on_click: None,
                         },
@@ -555,7 +649,7 @@ fn stationary_action(side: Side, visit: Option<ActionVisit>) -> Vec<Component> {
                 }
                 NzscAction::Move(move_) => vec![
                     Component::Circle {
-                        fill_color: colors::move_color(move_),
+                        fill_color: theme.move_color(move_),
                         shape: action_focus::background(side),
                         on_click: None,
                     },
@@ -573,7 +667,12 @@ fn stationary_action(side: Side, visit: Option<ActionVisit>) -> Vec<Component> {
         .collect()
 }
 
-fn fading_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Vec<Component> {
+fn fading_action(
+    side: Side,
+    visit: Option<ActionVisit>,
+    lerper: &Lerper,
+    theme: &Theme,
+) -> Vec<Component> {
     let sublerper = lerper.sub_lerper(0.0..colors::PORTION_OF_DURATION_SPENT_FADING);
 
     visit
@@ -585,8 +684,8 @@ fn fading_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Vec
                 NzscAction::Mirror(move_) => {
                     vec![
                         LerpableComponent::Circle {
-                            start_color: colors::move_color(move_),
-                            end_color: colors::move_color(move_).with_alpha(0),
+                            start_color: theme.move_color(move_),
+                            end_color: theme.move_color(move_).with_alpha(0),
                             start_shape: action_focus::background(side),
                             end_shape: action_focus::background(side),
                             on_click: None,
@@ -612,8 +711,8 @@ fn fading_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Vec
                 }
                 NzscAction::Move(move_) => vec![
                     LerpableComponent::Circle {
-                        start_color: colors::move_color(move_),
-                        end_color: colors::move_color(move_).with_alpha(0),
+                        start_color: theme.move_color(move_),
+                        end_color: theme.move_color(move_).with_alpha(0),
                         start_shape: action_focus::background(side),
                         end_shape: action_focus::background(side),
                         on_click: None,
@@ -635,7 +734,12 @@ fn fading_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Vec
         .collect()
 }
 
-fn exiting_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Vec<Component> {
+fn exiting_action(
+    side: Side,
+    visit: Option<ActionVisit>,
+    lerper: &Lerper,
+    theme: &Theme,
+) -> Vec<Component> {
     if let Some(visit) = visit {
         let ActionVisit { action, end, .. } = visit;
         match action {
@@ -645,8 +749,8 @@ fn exiting_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Ve
                 if let Some(end) = end {
                     vec![
                         LerpableComponent::Circle {
-                            start_color: colors::move_color(move_),
-                            end_color: colors::move_color(move_),
+                            start_color: theme.move_color(move_),
+                            end_color: theme.move_color(move_),
                             start_shape: action_focus::background(side),
                             end_shape: dequeue_circle::background_at(end.side, end.row, end.column),
                             on_click: None,
@@ -678,8 +782,8 @@ fn exiting_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Ve
 
                     vec![
                         LerpableComponent::Circle {
-                            start_color: colors::move_color(move_),
-                            end_color: colors::move_color(move_).with_alpha(0),
+                            start_color: theme.move_color(move_),
+                            end_color: theme.move_color(move_).with_alpha(0),
                             start_shape: action_focus::background(side),
                             end_shape: action_focus::expanded_background(side),
                             on_click: None,
@@ -712,8 +816,8 @@ fn exiting_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Ve
                 if let Some(end) = end {
                     vec![
                         LerpableComponent::Circle {
-                            start_color: colors::move_color(move_),
-                            end_color: colors::move_color(move_),
+                            start_color: theme.move_color(move_),
+                            end_color: theme.move_color(move_),
                             start_shape: action_focus::background(side),
                             end_shape: dequeue_circle::background_at(end.side, end.row, end.column),
                             on_click: None,
@@ -736,8 +840,8 @@ fn exiting_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Ve
 
                     vec![
                         LerpableComponent::Circle {
-                            start_color: colors::move_color(move_),
-                            end_color: colors::move_color(move_).with_alpha(0),
+                            start_color: theme.move_color(move_),
+                            end_color: theme.move_color(move_).with_alpha(0),
                             start_shape: action_focus::background(side),
                             end_shape: action_focus::expanded_background(side),
                             on_click: None,
@@ -765,24 +869,25 @@ fn exiting_action(side: Side, visit: Option<ActionVisit>, lerper: &Lerper) -> Ve
 fn action_choosing_scoreboard_without_used_item(
     args: ActionChoosingRenderArgs,
     used_item: Option<ArsenalItem>,
+    theme: &Theme,
 ) -> Vec<Component> {
     vec![
         action_choosing_pool_display(&args),
         action_choosing_entrance_and_exit_display(&args),
         action_choosing_arsenal_display_without_used_item(&args, used_item),
-        arrows(&args),
+        arrows(&args, theme),
     ]
     .into_iter()
     .flatten()
     .collect()
 }
 
-fn action_choosing_scoreboard(args: ActionChoosingRenderArgs) -> Vec<Component> {
+fn action_choosing_scoreboard(args: ActionChoosingRenderArgs, theme: &Theme) -> Vec<Component> {
     vec![
         action_choosing_pool_display(&args),
         action_choosing_entrance_and_exit_display(&args),
         action_choosing_arsenal_display(&args),
-        arrows(&args),
+        arrows(&args, theme),
     ]
     .into_iter()
     .flatten()
@@ -793,7 +898,9 @@ fn dequeueing_pool_display(args: &DequeueingRenderArgs) -> Vec<Component> {
     let DequeueingRenderArgs {
         player,
         side,
+        seat,
         dequeues,
+        theme,
     } = args;
     let side = *side;
 
@@ -814,6 +921,7 @@ fn dequeueing_pool_display(args: &DequeueingRenderArgs) -> Vec<Component> {
         width_in_columns: 3,
         height_in_rows: helpers::height_in_rows(&player.queue.pool, 3),
         enabled: drain_and_exit_enabled,
+        theme,
     };
 
     let pool = player
@@ -825,14 +933,16 @@ fn dequeueing_pool_display(args: &DequeueingRenderArgs) -> Vec<Component> {
             arsenal_item_display(
                 arsenal_item,
                 drain_and_exit_enabled,
-                side.if_left(Action::ChooseDequeue(DequeueChoice::DrainAndExit(
-                    arsenal_item,
-                ))),
+                side.if_seated(
+                    *seat,
+                    Action::ChooseDequeue(DequeueChoice::DrainAndExit(arsenal_item)),
+                ),
                 CirclePosition {
                     side,
                     column: i % 3,
                     row: i / 3,
                 },
+                theme,
             )
         });
 
@@ -843,7 +953,9 @@ fn dequeueing_entrance_decline_and_exit_display(args: &DequeueingRenderArgs) ->
     let DequeueingRenderArgs {
         player,
         side,
+        seat,
         dequeues,
+        theme,
     } = args;
     let side = *side;
 
@@ -864,6 +976,7 @@ fn dequeueing_entrance_decline_and_exit_display(args: &DequeueingRenderArgs) ->
         width_in_columns: 3,
         height_in_rows: 1,
         enabled: false,
+        theme,
     };
     let decline_and_exit_pill = Pill {
         position: CirclePosition {
@@ -874,6 +987,7 @@ fn dequeueing_entrance_decline_and_exit_display(args: &DequeueingRenderArgs) ->
         width_in_columns: 2,
         height_in_rows: 1,
         enabled: true,
+        theme,
     };
     let exit_pill = Pill {
         position: CirclePosition {
@@ -884,6 +998,7 @@ fn dequeueing_entrance_decline_and_exit_display(args: &DequeueingRenderArgs) ->
         width_in_columns: 1,
         height_in_rows: 1,
         enabled: just_exit_enabled,
+        theme,
     };
 
     vec![
@@ -900,13 +1015,14 @@ fn dequeueing_entrance_decline_and_exit_display(args: &DequeueingRenderArgs) ->
                     column: 0,
                     row,
                 },
+                theme,
             )
         }),
         Some(vec![
             Component::Circle {
-                fill_color: colors::DECLINE_DEQUEUE_COLOR,
+                fill_color: theme.decline_dequeue,
                 shape: dequeue_circle::background_at(side, row, 1),
-                on_click: side.if_left(Action::ChooseDequeue(DequeueChoice::Decline)),
+                on_click: side.if_seated(*seat, Action::ChooseDequeue(DequeueChoice::Decline)),
             },
             Component::Image {
                 image_type: ImageType::DeclineDequeue,
@@ -919,12 +1035,13 @@ fn dequeueing_entrance_decline_and_exit_display(args: &DequeueingRenderArgs) ->
             arsenal_item_display(
                 exiting_item,
                 just_exit_enabled,
-                side.if_left(Action::ChooseDequeue(DequeueChoice::JustExit)),
+                side.if_seated(*seat, Action::ChooseDequeue(DequeueChoice::JustExit)),
                 CirclePosition {
                     side,
                     column: 2,
                     row,
                 },
+                theme,
             )
         }),
     ]
@@ -935,7 +1052,12 @@ fn dequeueing_entrance_decline_and_exit_display(args: &DequeueingRenderArgs) ->
 }
 
 fn dequeueing_arsenal_display(args: &DequeueingRenderArgs) -> Vec<Component> {
-    let DequeueingRenderArgs { player, side, .. } = args;
+    let DequeueingRenderArgs {
+        player,
+        side,
+        theme,
+        ..
+    } = args;
     let side = *side;
 
     let row_offset = helpers::height_in_rows(&player.queue.pool, 3) + 1;
@@ -949,6 +1071,7 @@ fn dequeueing_arsenal_display(args: &DequeueingRenderArgs) -> Vec<Component> {
         width_in_columns: 3,
         height_in_rows: helpers::height_in_rows(&player.arsenal, 3),
         enabled: false,
+        theme,
     };
 
     let arsenal_items = player
@@ -965,13 +1088,14 @@ fn dequeueing_arsenal_display(args: &DequeueingRenderArgs) -> Vec<Component> {
                 false,
                 None,
                 CirclePosition { side, column, row },
+                theme,
             )
         });
 
     pill.render(()).into_iter().chain(arsenal_items).collect()
 }
 
-fn arrows<T: ArrowRenderArgs>(args: &T) -> Vec<Component> {
+fn arrows<T: ArrowRenderArgs>(args: &T, theme: &Theme) -> Vec<Component> {
     let side = args.side();
     let pool = args.pool();
 
@@ -980,13 +1104,13 @@ fn arrows<T: ArrowRenderArgs>(args: &T) -> Vec<Component> {
         vec![]
     } else {
         vec![
-            arrow::up_arrow_above(side, pool_height_in_rows, 0),
-            arrow::down_arrow_above(side, pool_height_in_rows, 2),
+            arrow::up_arrow_above(side, pool_height_in_rows, 0, theme),
+            arrow::down_arrow_above(side, pool_height_in_rows, 2, theme),
         ]
     };
     let arsenal_to_entrance_and_exit = vec![
-        arrow::up_arrow_above(side, pool_height_in_rows + 1, 0),
-        arrow::down_arrow_above(side, pool_height_in_rows + 1, 2),
+        arrow::up_arrow_above(side, pool_height_in_rows + 1, 0, theme),
+        arrow::down_arrow_above(side, pool_height_in_rows + 1, 2, theme),
     ];
 
     entrance_and_exit_to_pool
@@ -1000,6 +1124,7 @@ fn action_choosing_pool_display(args: &ActionChoosingRenderArgs) -> Vec<Componen
         player,
         side,
         actions,
+        theme,
     } = args;
     let side = *side;
 
@@ -1020,6 +1145,7 @@ fn action_choosing_pool_display(args: &ActionChoosingRenderArgs) -> Vec<Componen
         width_in_columns: 3,
         height_in_rows: helpers::height_in_rows(&player.queue.pool, 3),
         enabled: mirror_enabled,
+        theme,
     };
 
     let pool = player
@@ -1041,7 +1167,7 @@ fn action_choosing_pool_display(args: &ActionChoosingRenderArgs) -> Vec<Componen
 
                 vec![
                     Component::Circle {
-                        fill_color: colors::move_color(move_),
+                        fill_color: theme.move_color(move_),
                         shape: dequeue_circle::background_at(side, row, column),
                         on_click: None,
                     },
@@ -1065,6 +1191,7 @@ fn action_choosing_pool_display(args: &ActionChoosingRenderArgs) -> Vec<Componen
                     false,
                     None,
                     CirclePosition { side, column, row },
+                    theme,
                 )
             }
         });
@@ -1073,7 +1200,12 @@ fn action_choosing_pool_display(args: &ActionChoosingRenderArgs) -> Vec<Componen
 }
 
 fn action_choosing_entrance_and_exit_display(args: &ActionChoosingRenderArgs) -> Vec<Component> {
-    let ActionChoosingRenderArgs { player, side, .. } = args;
+    let ActionChoosingRenderArgs {
+        player,
+        side,
+        theme,
+        ..
+    } = args;
     let side = *side;
 
     let entrance = player.queue.entrance;
@@ -1090,6 +1222,7 @@ fn action_choosing_entrance_and_exit_display(args: &ActionChoosingRenderArgs) ->
         width_in_columns: 3,
         height_in_rows: 1,
         enabled: false,
+        theme,
     };
 
     vec![
@@ -1104,6 +1237,7 @@ fn action_choosing_entrance_and_exit_display(args: &ActionChoosingRenderArgs) ->
                     column: 0,
                     row,
                 },
+                theme,
             )
         }),
         exit.map(|exiting_item| {
@@ -1116,6 +1250,7 @@ fn action_choosing_entrance_and_exit_display(args: &ActionChoosingRenderArgs) ->
                     column: 2,
                     row,
                 },
+                theme,
             )
         }),
     ]
@@ -1133,7 +1268,12 @@ fn action_choosing_arsenal_display_without_used_item(
     args: &ActionChoosingRenderArgs,
     used_item: Option<ArsenalItem>,
 ) -> Vec<Component> {
-    let ActionChoosingRenderArgs { player, side, .. } = args;
+    let ActionChoosingRenderArgs {
+        player,
+        side,
+        theme,
+        ..
+    } = args;
     let side = *side;
 
     let row_offset = helpers::height_in_rows(&player.queue.pool, 3) + 1;
@@ -1147,6 +1287,7 @@ fn action_choosing_arsenal_display_without_used_item(
         width_in_columns: 3,
         height_in_rows: helpers::height_in_rows(&player.arsenal, 3),
         enabled: true,
+        theme,
     };
 
     let arsenal_items = player
@@ -1163,7 +1304,7 @@ fn action_choosing_arsenal_display_without_used_item(
 
                 vec![
                     Component::Circle {
-                        fill_color: colors::arsenal_item_color(arsenal_item),
+                        fill_color: theme.arsenal_item_color(arsenal_item),
                         shape: dequeue_circle::background_at(side, row, column),
                         on_click: None,
                     },
@@ -1271,12 +1412,15 @@ struct ActionChoosingRenderArgs<'a> {
     pub player: &'a ActionlessPlayer,
     pub side: Side,
     pub actions: &'a Vec<NzscAction>,
+    pub theme: &'a Theme,
 }
 
 struct DequeueingRenderArgs<'a> {
     pub player: &'a DequeueingPlayer,
     pub side: Side,
+    pub seat: Side,
     pub dequeues: &'a Vec<DequeueChoice>,
+    pub theme: &'a Theme,
 }
 
 trait ArrowRenderArgs {
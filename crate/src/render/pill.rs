@@ -1,21 +1,23 @@
 use super::Render;
-use crate::colors::{self, Rgba};
+use crate::colors::Rgba;
 use crate::paint::Component;
 use crate::shapes::{
     dequeue_circle::{self, CirclePosition},
     Circle, Rect,
 };
 use crate::side::Side;
+use crate::theme::Theme;
 use crate::transform::Translate;
 
-pub struct Pill {
+pub struct Pill<'a> {
     pub position: CirclePosition,
     pub width_in_columns: usize,
     pub height_in_rows: usize,
     pub enabled: bool,
+    pub theme: &'a Theme,
 }
 
-impl Pill {
+impl<'a> Pill<'a> {
     fn unchecked_render(&self) -> Vec<Component> {
         let vertical_connector = Rect {
             x: self.leftmost_circle_x(),
@@ -87,9 +89,9 @@ impl Pill {
 
     fn color(&self) -> Rgba {
         if self.enabled {
-            colors::PILL_ENABLED_COLOR
+            self.theme.pill_enabled
         } else {
-            colors::PILL_DISABLED_COLOR
+            self.theme.pill_disabled
         }
     }
 
@@ -114,7 +116,7 @@ impl Pill {
     }
 }
 
-impl Render<()> for Pill {
+impl<'a> Render<()> for Pill<'a> {
     fn render(&self, _: ()) -> Vec<Component> {
         if self.width_in_columns == 0 || self.height_in_rows == 0 {
             vec![]
@@ -129,6 +131,7 @@ const RADIUS: f64 = 110.0;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::settings::ThemeName;
 
     #[test]
     fn pill_with_zero_width_renders_empty_vec() {
@@ -141,6 +144,7 @@ mod tests {
             width_in_columns: 0,
             height_in_rows: 1,
             enabled: true,
+            theme: Theme::named(ThemeName::Classic),
         };
         assert!(pill.render(()).is_empty());
     }
@@ -156,6 +160,7 @@ mod tests {
             width_in_columns: 3,
             height_in_rows: 0,
             enabled: true,
+            theme: Theme::named(ThemeName::Classic),
         };
         assert!(pill.render(()).is_empty());
     }
@@ -0,0 +1,226 @@
+use crate::canvas_dimensions;
+use crate::click::Action;
+use crate::description;
+use crate::forecast::QueueForecast;
+use crate::i18n::Messages;
+use crate::paint::{Component, ImageType, Path, Stroke};
+use crate::render::{self, Render};
+use crate::shapes::{Circle, Rect};
+use crate::side::Side;
+use crate::text::TextAlign;
+use crate::theme::Theme;
+
+use nzscq::choices::ArsenalItem;
+
+pub struct QueueForecastDisplay<'a> {
+    pub side: Side,
+    pub forecasts: &'a [QueueForecast],
+    pub theme: &'a Theme,
+    pub messages: Messages,
+}
+
+impl<'a> QueueForecastDisplay<'a> {
+    fn header(&self) -> Vec<Component> {
+        let tabs = vec![
+            (Side::Left, "player.you", LEFT_TAB_X),
+            (Side::Right, "player.computer", RIGHT_TAB_X),
+        ]
+        .into_iter()
+        .flat_map(|(side, key, x)| {
+            let shape = Rect {
+                x,
+                y: BUTTON_Y,
+                width: TAB_WIDTH,
+                height: BUTTON_HEIGHT,
+            };
+            let selection_ring = if side == self.side {
+                Some(Component::UnclickablePath {
+                    path: Path::rounded_rect(
+                        &Rect {
+                            x: shape.x - SELECTION_RING_WIDTH,
+                            y: shape.y - SELECTION_RING_WIDTH,
+                            width: shape.width + 2.0 * SELECTION_RING_WIDTH,
+                            height: shape.height + 2.0 * SELECTION_RING_WIDTH,
+                        },
+                        BUTTON_CORNER_RADIUS,
+                    ),
+                    fill_color: None,
+                    stroke: Some(Stroke {
+                        color: self.theme.screen_text,
+                        width: 0.5 * SELECTION_RING_WIDTH,
+                    }),
+                })
+            } else {
+                None
+            };
+
+            selection_ring.into_iter().chain(render::text_button(
+                self.messages.text(key),
+                shape,
+                Some(Action::ForecastQueue(side)),
+                self.theme,
+            ))
+        });
+
+        vec![
+            render::label(
+                self.messages.text("forecast.title"),
+                (MARGIN, BUTTON_Y + 0.5 * BUTTON_HEIGHT),
+                TITLE_FONT_SIZE,
+                self.theme.screen_text,
+            ),
+            render::label(
+                self.messages.text("forecast.dequeue"),
+                (MARGIN, COLUMN_HEADER_Y),
+                LABEL_FONT_SIZE,
+                self.theme.screen_text,
+            ),
+            render::label(
+                self.messages.text("forecast.arsenal"),
+                (ARSENAL_X, COLUMN_HEADER_Y),
+                LABEL_FONT_SIZE,
+                self.theme.screen_text,
+            ),
+            render::label(
+                self.messages.text("forecast.queue"),
+                (QUEUE_X, COLUMN_HEADER_Y),
+                LABEL_FONT_SIZE,
+                self.theme.screen_text,
+            ),
+        ]
+        .into_iter()
+        .chain(tabs)
+        .chain(render::text_button(
+            self.messages.text("button.stop_inspecting"),
+            Rect {
+                x: canvas_dimensions::WIDTH - MARGIN - STOP_BUTTON_WIDTH,
+                y: BUTTON_Y,
+                width: STOP_BUTTON_WIDTH,
+                height: BUTTON_HEIGHT,
+            },
+            Some(Action::StopInspectingMove),
+            self.theme,
+        ))
+        .collect()
+    }
+
+    fn rows(&self) -> Vec<Component> {
+        let row_height = ((canvas_dimensions::HEIGHT - ROWS_Y - MARGIN)
+            / self.forecasts.len().max(1) as f64)
+            .min(MAX_ROW_HEIGHT);
+        let radius = ITEM_RADIUS_FACTOR * row_height;
+        let spacing = ITEM_SPACING_FACTOR * row_height;
+
+        self.forecasts
+            .iter()
+            .enumerate()
+            .flat_map(|(i, forecast)| {
+                let center_y = ROWS_Y + row_height * (i as f64 + 0.5);
+                let item_at = |x: f64, item: Option<ArsenalItem>| {
+                    item_circle(item, x + radius, center_y, radius, self.theme)
+                };
+                let arsenal = forecast
+                    .arsenal
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(j, &item)| item_at(ARSENAL_X + spacing * j as f64, Some(item)));
+
+                let queue = &forecast.queue;
+                let pool_x = QUEUE_X + spacing + SLOT_GAP;
+                let exit_x = pool_x + spacing * queue.pool.len() as f64 + SLOT_GAP;
+                let pool = queue
+                    .pool
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(j, &item)| item_at(pool_x + spacing * j as f64, Some(item)));
+
+                vec![render::aligned_label(
+                    description::dequeue_name(forecast.dequeue, self.messages),
+                    (MARGIN, center_y),
+                    LABEL_FONT_SIZE,
+                    TextAlign::Left,
+                    self.theme.screen_text,
+                    Some(ARSENAL_X - MARGIN - SLOT_GAP),
+                )]
+                .into_iter()
+                .chain(arsenal)
+                .chain(item_at(QUEUE_X, queue.entrance))
+                .chain(pool)
+                .chain(item_at(exit_x, queue.exit))
+                .collect::<Vec<Component>>()
+            })
+            .collect()
+    }
+}
+
+impl<'a> Render<()> for QueueForecastDisplay<'a> {
+    fn render(&self, _: ()) -> Vec<Component> {
+        vec![
+            vec![Component::Background {
+                color: self.theme.background,
+            }],
+            self.header(),
+            self.rows(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+fn item_circle(
+    item: Option<ArsenalItem>,
+    x: f64,
+    y: f64,
+    radius: f64,
+    theme: &Theme,
+) -> Vec<Component> {
+    let shape = Circle { x, y, radius };
+
+    match item {
+        Some(item) => vec![
+            Component::Circle {
+                fill_color: theme.arsenal_item_color(item),
+                shape,
+                on_click: None,
+            },
+            Component::Image {
+                image_type: ImageType::from(item),
+                alpha: 1.0,
+                shape: Rect {
+                    x: x - radius,
+                    y: y - radius,
+                    width: 2.0 * radius,
+                    height: 2.0 * radius,
+                },
+                on_click: None,
+            },
+        ],
+        None => vec![Component::Circle {
+            fill_color: theme.stats_bar_track,
+            shape,
+            on_click: None,
+        }],
+    }
+}
+
+const MARGIN: f64 = 40.0;
+const BUTTON_Y: f64 = 30.0;
+const BUTTON_HEIGHT: f64 = 90.0;
+const BUTTON_CORNER_RADIUS: f64 = 40.0;
+const SELECTION_RING_WIDTH: f64 = 12.0;
+const LEFT_TAB_X: f64 = 620.0;
+const RIGHT_TAB_X: f64 = 940.0;
+const TAB_WIDTH: f64 = 290.0;
+const STOP_BUTTON_WIDTH: f64 = 400.0;
+const TITLE_FONT_SIZE: f64 = 40.0;
+
+const COLUMN_HEADER_Y: f64 = 170.0;
+const LABEL_FONT_SIZE: f64 = 26.0;
+const ARSENAL_X: f64 = 560.0;
+const QUEUE_X: f64 = 1180.0;
+const ROWS_Y: f64 = 200.0;
+const MAX_ROW_HEIGHT: f64 = 80.0;
+const ITEM_RADIUS_FACTOR: f64 = 0.42;
+const ITEM_SPACING_FACTOR: f64 = 0.9;
+const SLOT_GAP: f64 = 20.0;
@@ -0,0 +1,49 @@
+use crate::canvas_dimensions;
+use crate::click::Action;
+use crate::paint::{Component, Path, PathCommand};
+use crate::render::lerp::Lerper;
+use crate::shapes::Circle;
+use crate::theme::Theme;
+use crate::transform::Translate;
+
+pub fn replay_button(lerper: &Lerper, theme: &Theme) -> Vec<Component> {
+    vec![
+        Component::Circle {
+            fill_color: theme.home_button_background,
+            shape: Circle {
+                x: CENTER_X,
+                y: CENTER_Y,
+                radius: BACKGROUND_RADIUS,
+            },
+            on_click: Some(Action::StartReplay),
+        },
+        rewind_triangle(CENTER_X + 0.5 * TRIANGLE_WIDTH, theme),
+        rewind_triangle(CENTER_X - 0.5 * TRIANGLE_WIDTH, theme),
+    ]
+    .translate(
+        0.0,
+        lerper.lerp(
+            canvas_dimensions::HEIGHT - CENTER_Y + BACKGROUND_RADIUS,
+            0.0,
+        ),
+    )
+}
+
+fn rewind_triangle(tip_x: f64, theme: &Theme) -> Component {
+    Component::UnclickablePath {
+        path: Path {
+            start: (tip_x - 0.5 * TRIANGLE_WIDTH, CENTER_Y),
+            commands: vec![
+                PathCommand::LineTo(tip_x + 0.5 * TRIANGLE_WIDTH, CENTER_Y - TRIANGLE_WIDTH),
+                PathCommand::LineTo(tip_x + 0.5 * TRIANGLE_WIDTH, CENTER_Y + TRIANGLE_WIDTH),
+            ],
+        },
+        fill_color: Some(theme.home_button_foreground),
+        stroke: None,
+    }
+}
+
+const BACKGROUND_RADIUS: f64 = 80.0;
+const TRIANGLE_WIDTH: f64 = 36.0;
+const CENTER_X: f64 = 0.5 * canvas_dimensions::WIDTH + 320.0;
+const CENTER_Y: f64 = 0.5 * canvas_dimensions::HEIGHT;
@@ -0,0 +1,219 @@
+use super::Render;
+use crate::canvas_dimensions;
+use crate::click::Action;
+use crate::i18n::Messages;
+use crate::paint::{Component, Path, PathCommand};
+use crate::phase::Phase;
+use crate::render::{self, lerp::Lerper};
+use crate::shapes::{Circle, Rect};
+use crate::theme::Theme;
+use crate::transform::{Scale, Translate};
+
+pub struct ReplayControls<'a> {
+    pub phases: &'a [Phase],
+    pub index: usize,
+    pub is_playing: bool,
+    pub mistake_phases: &'a [usize],
+    pub theme: &'a Theme,
+    pub messages: Messages,
+}
+
+impl<'a> ReplayControls<'a> {
+    fn home_button(&self) -> Vec<Component> {
+        render::home_button(&Lerper::from_completion_factor(1.0), self.theme)
+            .translate(-canvas_dimensions::CENTER_X, -canvas_dimensions::CENTER_Y)
+            .scale(HOME_BUTTON_RADIUS / ORIGINAL_HOME_BUTTON_RADIUS)
+            .translate(MARGIN + HOME_BUTTON_RADIUS, MARGIN + HOME_BUTTON_RADIUS)
+    }
+
+    fn buttons(&self) -> Vec<Component> {
+        let play_pause_glyph = if self.is_playing {
+            pause_glyph(button_x(2), self.theme)
+        } else {
+            vec![triangle(button_x(2) + 0.25 * GLYPH_SIZE, 1.0, self.theme)]
+        };
+
+        vec![
+            button(0, Action::SkipToPreviousRound, self.theme),
+            vec![
+                triangle(button_x(0) - 0.25 * GLYPH_SIZE, -1.0, self.theme),
+                triangle(button_x(0) + 0.5 * GLYPH_SIZE, -1.0, self.theme),
+            ],
+            button(1, Action::StepReplayBackward, self.theme),
+            vec![
+                bar(button_x(1) - 0.5 * GLYPH_SIZE, self.theme),
+                triangle(button_x(1) - 0.25 * GLYPH_SIZE, -1.0, self.theme),
+            ],
+            button(2, Action::ToggleReplayPlayback, self.theme),
+            play_pause_glyph,
+            button(3, Action::StepReplayForward, self.theme),
+            vec![
+                triangle(button_x(3) + 0.25 * GLYPH_SIZE, 1.0, self.theme),
+                bar(button_x(3) + 0.5 * GLYPH_SIZE - BAR_WIDTH, self.theme),
+            ],
+            button(4, Action::SkipToNextRound, self.theme),
+            vec![
+                triangle(button_x(4) + 0.25 * GLYPH_SIZE, 1.0, self.theme),
+                triangle(button_x(4) - 0.5 * GLYPH_SIZE, 1.0, self.theme),
+            ],
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    fn timeline(&self) -> Vec<Component> {
+        let segment_width = TIMELINE_WIDTH / self.phases.len() as f64;
+
+        self.phases
+            .iter()
+            .enumerate()
+            .map(|(i, phase)| {
+                let height = if phase.starts_round() {
+                    ROUND_SEGMENT_HEIGHT
+                } else {
+                    SEGMENT_HEIGHT
+                };
+
+                Component::Rect {
+                    fill_color: if i <= self.index {
+                        self.theme.replay_timeline_played
+                    } else {
+                        self.theme.replay_timeline_unplayed
+                    },
+                    shape: Rect {
+                        x: TIMELINE_X + segment_width * i as f64,
+                        y: CONTROLS_CENTER_Y - 0.5 * height,
+                        width: segment_width - SEGMENT_GAP,
+                        height,
+                    },
+                    on_click: Some(Action::SeekReplay(i)),
+                }
+            })
+            .chain(self.mistake_phases.iter().map(|&i| Component::Circle {
+                fill_color: self.theme.stats_loss,
+                shape: Circle {
+                    x: TIMELINE_X + segment_width * (i as f64 + 0.5),
+                    y: CONTROLS_CENTER_Y - 0.5 * ROUND_SEGMENT_HEIGHT - MISTAKE_MARKER_RADIUS,
+                    radius: MISTAKE_MARKER_RADIUS,
+                },
+                on_click: Some(Action::SeekReplay(i)),
+            }))
+            .chain(vec![Component::Circle {
+                fill_color: self.theme.home_button_background,
+                shape: Circle {
+                    x: TIMELINE_X + segment_width * (self.index as f64 + 0.5),
+                    y: CONTROLS_CENTER_Y,
+                    radius: 0.5 * ROUND_SEGMENT_HEIGHT,
+                },
+                on_click: None,
+            }])
+            .collect()
+    }
+}
+
+impl<'a> Render<()> for ReplayControls<'a> {
+    fn render(&self, _: ()) -> Vec<Component> {
+        vec![
+            self.home_button(),
+            vec![Component::Rect {
+                fill_color: self.theme.replay_controls_background,
+                shape: Rect {
+                    x: 0.0,
+                    y: canvas_dimensions::HEIGHT - CONTROLS_HEIGHT,
+                    width: canvas_dimensions::WIDTH,
+                    height: CONTROLS_HEIGHT,
+                },
+                on_click: Some(Action::StopPropagation),
+            }],
+            self.buttons(),
+            self.timeline(),
+            render::text_button(
+                self.messages.text("button.branch"),
+                Rect {
+                    x: BRANCH_BUTTON_X,
+                    y: CONTROLS_CENTER_Y - BUTTON_RADIUS,
+                    width: BRANCH_BUTTON_WIDTH,
+                    height: 2.0 * BUTTON_RADIUS,
+                },
+                Some(Action::BranchReplay),
+                self.theme,
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+fn button(index: usize, on_click: Action, theme: &Theme) -> Vec<Component> {
+    vec![Component::Circle {
+        fill_color: theme.home_button_background,
+        shape: Circle {
+            x: button_x(index),
+            y: CONTROLS_CENTER_Y,
+            radius: BUTTON_RADIUS,
+        },
+        on_click: Some(on_click),
+    }]
+}
+
+fn button_x(index: usize) -> f64 {
+    MARGIN + BUTTON_RADIUS + (2.0 * BUTTON_RADIUS + BUTTON_GAP) * index as f64
+}
+
+fn triangle(tip_x: f64, direction: f64, theme: &Theme) -> Component {
+    let base_x = tip_x - direction * 0.75 * GLYPH_SIZE;
+
+    Component::UnclickablePath {
+        path: Path {
+            start: (tip_x, CONTROLS_CENTER_Y),
+            commands: vec![
+                PathCommand::LineTo(base_x, CONTROLS_CENTER_Y - 0.5 * GLYPH_SIZE),
+                PathCommand::LineTo(base_x, CONTROLS_CENTER_Y + 0.5 * GLYPH_SIZE),
+            ],
+        },
+        fill_color: Some(theme.home_button_foreground),
+        stroke: None,
+    }
+}
+
+fn bar(x: f64, theme: &Theme) -> Component {
+    Component::Rect {
+        fill_color: theme.home_button_foreground,
+        shape: Rect {
+            x,
+            y: CONTROLS_CENTER_Y - 0.5 * GLYPH_SIZE,
+            width: BAR_WIDTH,
+            height: GLYPH_SIZE,
+        },
+        on_click: None,
+    }
+}
+
+fn pause_glyph(center_x: f64, theme: &Theme) -> Vec<Component> {
+    vec![
+        bar(center_x - 0.5 * GLYPH_SIZE + BAR_WIDTH, theme),
+        bar(center_x + 0.5 * GLYPH_SIZE - 2.0 * BAR_WIDTH, theme),
+    ]
+}
+
+const ORIGINAL_HOME_BUTTON_RADIUS: f64 = 120.0;
+const HOME_BUTTON_RADIUS: f64 = 40.0;
+const MARGIN: f64 = 30.0;
+
+const CONTROLS_HEIGHT: f64 = 120.0;
+const CONTROLS_CENTER_Y: f64 = canvas_dimensions::HEIGHT - 0.5 * CONTROLS_HEIGHT;
+const BUTTON_RADIUS: f64 = 40.0;
+const BUTTON_GAP: f64 = 20.0;
+const GLYPH_SIZE: f64 = 36.0;
+const BAR_WIDTH: f64 = 8.0;
+
+const TIMELINE_X: f64 = MARGIN + 5.0 * (2.0 * BUTTON_RADIUS + BUTTON_GAP) + BUTTON_GAP;
+const TIMELINE_WIDTH: f64 = BRANCH_BUTTON_X - BUTTON_GAP - TIMELINE_X;
+const BRANCH_BUTTON_WIDTH: f64 = 200.0;
+const BRANCH_BUTTON_X: f64 = canvas_dimensions::WIDTH - MARGIN - BRANCH_BUTTON_WIDTH;
+const SEGMENT_HEIGHT: f64 = 24.0;
+const ROUND_SEGMENT_HEIGHT: f64 = 48.0;
+const SEGMENT_GAP: f64 = 2.0;
+const MISTAKE_MARKER_RADIUS: f64 = 8.0;
@@ -0,0 +1,347 @@
+use crate::canvas_dimensions;
+use crate::click::Action;
+use crate::colors::Rgba;
+use crate::i18n::Messages;
+use crate::paint::{Component, ImageType};
+use crate::render::{self, Render};
+use crate::round_log::{self, RoundLog, RoundLogEntry};
+use crate::shapes::{Circle, Rect};
+use crate::text::TextAlign;
+use crate::theme::Theme;
+
+use nzscq::{
+    choices::{Action as NzscAction, ArsenalItem, DequeueChoice},
+    outcomes::ActionPointsDestroyed,
+};
+
+pub fn is_over_round_log(canvas_coords: (f64, f64)) -> bool {
+    let (x, y) = canvas_coords;
+
+    PANEL_X <= x && (0.0..=canvas_dimensions::HEIGHT).contains(&y)
+}
+
+impl<'a> Render<(&'a Theme, Messages)> for RoundLog {
+    fn render(&self, (theme, messages): (&'a Theme, Messages)) -> Vec<Component> {
+        if !self.is_expanded {
+            return toggle_button(theme);
+        }
+
+        let rows = self
+            .entries
+            .iter()
+            .skip(self.first_visible_row())
+            .take(round_log::VISIBLE_ROWS)
+            .enumerate()
+            .flat_map(|(i, entry)| row(entry, LIST_Y + ROW_HEIGHT * i as f64, theme, messages));
+
+        vec![
+            Component::Rect {
+                fill_color: theme.settings_screen_background,
+                shape: Rect {
+                    x: PANEL_X,
+                    y: 0.0,
+                    width: canvas_dimensions::WIDTH - PANEL_X,
+                    height: canvas_dimensions::HEIGHT,
+                },
+                on_click: Some(Action::StopPropagation),
+            },
+            render::label(
+                messages.text("log.title"),
+                (PANEL_X + PADDING, HEADER_Y + 0.5 * SCROLL_BUTTON_SIZE),
+                TITLE_FONT_SIZE,
+                theme.screen_text,
+            ),
+        ]
+        .into_iter()
+        .chain(scroll_button(
+            "\u{2191}",
+            SCROLL_UP_X,
+            self.can_scroll_up(),
+            Action::ScrollRoundLogUp,
+            theme,
+        ))
+        .chain(scroll_button(
+            "\u{2193}",
+            SCROLL_DOWN_X,
+            self.can_scroll_down(),
+            Action::ScrollRoundLogDown,
+            theme,
+        ))
+        .chain(rows)
+        .chain(toggle_button(theme))
+        .collect()
+    }
+}
+
+fn toggle_button(theme: &Theme) -> Vec<Component> {
+    let background = Component::Circle {
+        fill_color: theme.home_button_background,
+        shape: Circle {
+            x: TOGGLE_CENTER_X,
+            y: TOGGLE_CENTER_Y,
+            radius: TOGGLE_RADIUS,
+        },
+        on_click: Some(Action::ToggleRoundLog),
+    };
+    let lines = (0..3).map(|i| Component::Rect {
+        fill_color: theme.home_button_foreground,
+        shape: Rect {
+            x: TOGGLE_CENTER_X - 0.5 * TOGGLE_GLYPH_SIZE,
+            y: TOGGLE_CENTER_Y - 0.5 * TOGGLE_GLYPH_SIZE
+                + 0.5 * (TOGGLE_GLYPH_SIZE - TOGGLE_LINE_WIDTH) * f64::from(i),
+            width: TOGGLE_GLYPH_SIZE,
+            height: TOGGLE_LINE_WIDTH,
+        },
+        on_click: None,
+    });
+
+    vec![background].into_iter().chain(lines).collect()
+}
+
+fn scroll_button(
+    label: &str,
+    x: f64,
+    enabled: bool,
+    on_click: Action,
+    theme: &Theme,
+) -> Vec<Component> {
+    let shape = Rect {
+        x,
+        y: HEADER_Y,
+        width: SCROLL_BUTTON_SIZE,
+        height: SCROLL_BUTTON_SIZE,
+    };
+
+    if enabled {
+        render::text_button(label.to_string(), shape, Some(on_click), theme)
+    } else {
+        render::text_button(label.to_string(), shape, None, theme)
+            .into_iter()
+            .map(|component| component.faded(DISABLED_OPACITY))
+            .collect()
+    }
+}
+
+fn row(entry: &RoundLogEntry, y: f64, theme: &Theme, messages: Messages) -> Vec<Component> {
+    let (label, cells) = match entry {
+        RoundLogEntry::Characters {
+            characters,
+            is_rechoose,
+        } => (
+            if *is_rechoose {
+                messages.text("log.rechoose")
+            } else {
+                messages.text("log.characters")
+            },
+            [0, 1]
+                .iter()
+                .flat_map(|&i| {
+                    square_icon(
+                        ImageType::Character(characters[i]),
+                        theme.character_color(characters[i]),
+                        1.0,
+                        side_x(i),
+                        y,
+                    )
+                })
+                .collect::<Vec<Component>>(),
+        ),
+        RoundLogEntry::Boosters(boosters) => (
+            messages.text("log.boosters"),
+            [0, 1]
+                .iter()
+                .flat_map(|&i| {
+                    square_icon(
+                        ImageType::Booster(boosters[i]),
+                        theme.booster_color(boosters[i]),
+                        1.0,
+                        side_x(i),
+                        y,
+                    )
+                })
+                .collect(),
+        ),
+        RoundLogEntry::Round {
+            number,
+            dequeues,
+            exits,
+            outcome,
+        } => (
+            messages.format("log.round", &[number]),
+            [0, 1]
+                .iter()
+                .flat_map(|&i| {
+                    dequeue_icon(dequeues[i], exits[i], side_x(i), y, theme)
+                        .into_iter()
+                        .chain(outcome.iter().flat_map(|outcome| {
+                            action_cells(&outcome[i], side_x(i), y, theme, messages)
+                        }))
+                        .collect::<Vec<Component>>()
+                })
+                .collect(),
+        ),
+    };
+
+    vec![render::aligned_label(
+        label,
+        (PANEL_X + PADDING, y + 0.5 * ICON_SIZE),
+        LABEL_FONT_SIZE,
+        TextAlign::Left,
+        theme.screen_text,
+        Some(HUMAN_X - PANEL_X - 2.0 * PADDING),
+    )]
+    .into_iter()
+    .chain(cells)
+    .collect()
+}
+
+fn dequeue_icon(
+    dequeue: DequeueChoice,
+    exit: Option<ArsenalItem>,
+    x: f64,
+    y: f64,
+    theme: &Theme,
+) -> Vec<Component> {
+    let (fill_color, image_type) = match (dequeue, exit) {
+        (DequeueChoice::DrainAndExit(item), _) | (DequeueChoice::JustExit, Some(item)) => {
+            (theme.arsenal_item_color(item), ImageType::from(item))
+        }
+        _ => (theme.decline_dequeue, ImageType::DeclineDequeue),
+    };
+    let radius = 0.5 * DEQUEUE_ICON_SIZE;
+    let center_y = y + 0.5 * ICON_SIZE;
+
+    vec![
+        Component::Circle {
+            fill_color,
+            shape: Circle {
+                x: x + radius,
+                y: center_y,
+                radius,
+            },
+            on_click: None,
+        },
+        Component::Image {
+            image_type,
+            alpha: 1.0,
+            shape: Rect {
+                x,
+                y: center_y - radius,
+                width: DEQUEUE_ICON_SIZE,
+                height: DEQUEUE_ICON_SIZE,
+            },
+            on_click: None,
+        },
+    ]
+}
+
+fn action_cells(
+    outcome: &ActionPointsDestroyed,
+    x: f64,
+    y: f64,
+    theme: &Theme,
+    messages: Messages,
+) -> Vec<Component> {
+    let ActionPointsDestroyed(action, points, is_destroyed) = *outcome;
+    let alpha = if is_destroyed { DISABLED_OPACITY } else { 1.0 };
+    let action_x = x + DEQUEUE_ICON_SIZE + CELL_GAP;
+    let icon = match action {
+        NzscAction::Move(m) => {
+            square_icon(ImageType::Move(m), theme.move_color(m), alpha, action_x, y)
+        }
+        NzscAction::Mirror(m) => {
+            let mut components = square_icon(
+                ImageType::Mirror,
+                theme.arsenal_item_color(ArsenalItem::Mirror),
+                alpha,
+                action_x,
+                y,
+            );
+            components.push(Component::Image {
+                image_type: ImageType::Move(m),
+                alpha,
+                shape: Rect {
+                    x: action_x + 0.5 * ICON_SIZE,
+                    y: y + 0.5 * ICON_SIZE,
+                    width: 0.5 * ICON_SIZE,
+                    height: 0.5 * ICON_SIZE,
+                },
+                on_click: None,
+            });
+            components
+        }
+        NzscAction::Concede => {
+            return vec![render::label(
+                messages.text("action.concede"),
+                (action_x, y + 0.5 * ICON_SIZE),
+                LABEL_FONT_SIZE,
+                theme.screen_text,
+            )];
+        }
+    };
+
+    icon.into_iter()
+        .chain(vec![render::label(
+            format!("+{}", points),
+            (action_x + ICON_SIZE + CELL_GAP, y + 0.5 * ICON_SIZE),
+            LABEL_FONT_SIZE,
+            theme.screen_text,
+        )])
+        .collect()
+}
+
+fn square_icon(image_type: ImageType, color: Rgba, alpha: f64, x: f64, y: f64) -> Vec<Component> {
+    let shape = Rect {
+        x,
+        y,
+        width: ICON_SIZE,
+        height: ICON_SIZE,
+    };
+
+    vec![
+        Component::Rect {
+            fill_color: color,
+            shape: shape.clone(),
+            on_click: None,
+        }
+        .faded(alpha),
+        Component::Image {
+            image_type,
+            alpha,
+            shape,
+            on_click: None,
+        },
+    ]
+}
+
+fn side_x(side: usize) -> f64 {
+    if side == 0 {
+        HUMAN_X
+    } else {
+        COMPUTER_X
+    }
+}
+
+const TOGGLE_CENTER_X: f64 = canvas_dimensions::CENTER_X;
+const TOGGLE_CENTER_Y: f64 = 50.0;
+const TOGGLE_RADIUS: f64 = 36.0;
+const TOGGLE_GLYPH_SIZE: f64 = 32.0;
+const TOGGLE_LINE_WIDTH: f64 = 5.0;
+
+const PANEL_X: f64 = 1240.0;
+const PADDING: f64 = 20.0;
+const HEADER_Y: f64 = 30.0;
+const TITLE_FONT_SIZE: f64 = 36.0;
+const SCROLL_BUTTON_SIZE: f64 = 80.0;
+const SCROLL_UP_X: f64 = 1600.0;
+const SCROLL_DOWN_X: f64 = 1700.0;
+const DISABLED_OPACITY: f64 = 0.4;
+
+const LIST_Y: f64 = 140.0;
+const ROW_HEIGHT: f64 = 68.0;
+const LABEL_FONT_SIZE: f64 = 22.0;
+const HUMAN_X: f64 = 1380.0;
+const COMPUTER_X: f64 = 1590.0;
+const ICON_SIZE: f64 = 52.0;
+const DEQUEUE_ICON_SIZE: f64 = 40.0;
+const CELL_GAP: f64 = 8.0;
@@ -0,0 +1,116 @@
+use super::Render;
+use crate::canvas_dimensions;
+use crate::click::Action;
+use crate::i18n::Messages;
+use crate::paint::Component;
+use crate::render::{self, lerp::Lerper};
+use crate::shapes::Rect;
+use crate::side::Side;
+use crate::text::TextAlign;
+use crate::theme::Theme;
+use crate::transform::{Scale, Translate};
+
+pub struct SandboxControls<'a> {
+    pub seat: Side,
+    pub can_undo: bool,
+    pub can_redo: bool,
+    pub theme: &'a Theme,
+    pub messages: Messages,
+}
+
+impl<'a> SandboxControls<'a> {
+    fn home_button(&self) -> Vec<Component> {
+        render::home_button(&Lerper::from_completion_factor(1.0), self.theme)
+            .translate(-canvas_dimensions::CENTER_X, -canvas_dimensions::CENTER_Y)
+            .scale(HOME_BUTTON_RADIUS / ORIGINAL_HOME_BUTTON_RADIUS)
+            .translate(MARGIN + HOME_BUTTON_RADIUS, MARGIN + HOME_BUTTON_RADIUS)
+    }
+
+    fn seat_label(&self) -> Component {
+        let key = match self.seat {
+            Side::Left => "sandbox.seat_left",
+            Side::Right => "sandbox.seat_right",
+        };
+
+        render::aligned_label(
+            self.messages.text(key),
+            (canvas_dimensions::CENTER_X, BUTTON_Y + 0.5 * BUTTON_HEIGHT),
+            LABEL_FONT_SIZE,
+            TextAlign::Center,
+            self.theme.screen_text,
+            Some(LABEL_MAX_WIDTH),
+        )
+    }
+}
+
+impl<'a> Render<()> for SandboxControls<'a> {
+    fn render(&self, _: ()) -> Vec<Component> {
+        vec![
+            self.home_button(),
+            undo_button(self.can_undo, self.theme, self.messages),
+            vec![self.seat_label()],
+            history_button(
+                self.messages.text("button.redo"),
+                REDO_X,
+                Action::RedoChoice,
+                self.can_redo,
+                self.theme,
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+pub fn undo_button(is_enabled: bool, theme: &Theme, messages: Messages) -> Vec<Component> {
+    history_button(
+        messages.text("button.undo"),
+        UNDO_X,
+        Action::UndoChoice,
+        is_enabled,
+        theme,
+    )
+}
+
+fn history_button(
+    text: String,
+    x: f64,
+    action: Action,
+    is_enabled: bool,
+    theme: &Theme,
+) -> Vec<Component> {
+    let button = render::text_button(
+        text,
+        Rect {
+            x,
+            y: BUTTON_Y,
+            width: BUTTON_WIDTH,
+            height: BUTTON_HEIGHT,
+        },
+        if is_enabled { Some(action) } else { None },
+        theme,
+    );
+
+    if is_enabled {
+        button
+    } else {
+        button
+            .into_iter()
+            .map(|component| component.faded(DISABLED_OPACITY))
+            .collect()
+    }
+}
+
+const ORIGINAL_HOME_BUTTON_RADIUS: f64 = 120.0;
+const HOME_BUTTON_RADIUS: f64 = 40.0;
+const MARGIN: f64 = 30.0;
+
+const BUTTON_Y: f64 = 15.0;
+const BUTTON_WIDTH: f64 = 200.0;
+const BUTTON_HEIGHT: f64 = 70.0;
+const UNDO_X: f64 = 500.0;
+const REDO_X: f64 = canvas_dimensions::WIDTH - UNDO_X - BUTTON_WIDTH;
+const DISABLED_OPACITY: f64 = 0.5;
+const LABEL_FONT_SIZE: f64 = 28.0;
+const LABEL_MAX_WIDTH: f64 = REDO_X - UNDO_X - BUTTON_WIDTH - 40.0;
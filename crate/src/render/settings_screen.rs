@@ -1,41 +1,46 @@
 use crate::canvas_dimensions;
 use crate::click::Action;
-use crate::colors;
 use crate::context::Context;
+use crate::i18n::{Language, Messages};
 use crate::opponent::Difficulty;
-use crate::paint::{Component, ImageType};
+use crate::paint::{Component, ImageType, Path, PathCommand, Stroke};
 use crate::render::{self, lerp::Lerper};
-use crate::shapes::Rect;
+use crate::settings::{AnimationSpeed, Settings, ThemeName};
+use crate::shapes::{Circle, Rect};
+use crate::text::TextAlign;
+use crate::theme::Theme;
 use crate::transform::{Scale, Translate};
 
 use std::convert::TryFrom;
 use std::f64;
 
 pub fn settings_screen(context: &Context) -> Vec<Component> {
-    let difficulty = context.computer_difficulty;
+    let theme = context.theme();
+    let messages = context.messages();
+    let difficulty = context.settings.computer_difficulty;
 
     vec![
         vec![Component::Background {
-            color: colors::SETTINGS_SCREEN_BACKGROUND,
+            color: theme.settings_screen_background,
         }],
-        render::home_button(&Lerper::from_completion_factor(1.0))
+        render::home_button(&Lerper::from_completion_factor(1.0), theme)
             .translate(
                 -0.5 * canvas_dimensions::WIDTH,
                 -0.5 * canvas_dimensions::HEIGHT,
             )
             .scale(TARGET_RADIUS / ORIGINAL_RADIUS)
             .translate(MARGIN + TARGET_RADIUS, MARGIN + TARGET_RADIUS),
-        vec![Component::Image {
-            image_type: ImageType::ComputerDifficulty(difficulty),
-            alpha: 1.0,
-            shape: Rect {
-                x: 160.0,
-                y: -50.0,
-                width: 776.0,
-                height: 240.0,
-            },
-            on_click: None,
-        }],
+        vec![render::aligned_label(
+            messages.format(
+                "settings.computer_difficulty",
+                &[&messages.difficulty_name(difficulty)],
+            ),
+            (170.0, 70.0),
+            DIFFICULTY_FONT_SIZE,
+            TextAlign::Left,
+            theme.screen_text,
+            Some(DIFFICULTY_MAX_WIDTH),
+        )],
         (0..3)
             .map(|i| Component::Image {
                 image_type: if i > difficulty as u8 {
@@ -56,47 +61,375 @@ pub fn settings_screen(context: &Context) -> Vec<Component> {
                 )),
             })
             .collect(),
-        vec![
-            Component::Image {
-                image_type: ImageType::TutorialButton,
-                alpha: 0.5,
-                shape: Rect {
-                    x: 160.0,
-                    y: 150.0,
-                    width: 388.0,
-                    height: 240.0,
+        render::text_button(
+            messages.text("button.tutorial"),
+            big_button_shape(0.0),
+            None,
+            theme,
+        )
+        .into_iter()
+        .chain(render::text_button(
+            messages.text("button.pass_and_play"),
+            big_button_shape(1.0),
+            None,
+            theme,
+        ))
+        .map(|component| component.faded(UNAVAILABLE_BUTTON_OPACITY))
+        .collect(),
+        render::text_button(
+            messages.text("button.custom_seed"),
+            big_button_shape(2.0),
+            Some(Action::PromptUserForCustomSeed),
+            theme,
+        ),
+        animation_speed_buttons(context.settings.animation_speed, theme),
+        reduce_motion_button(context.settings.reduce_motion, theme, messages),
+        theme_buttons(context.settings.theme, theme, messages),
+        language_buttons(context.settings.language, theme, messages),
+        hint_limit_buttons(context.settings.hints_per_game, theme, messages),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn big_button_shape(index: f64) -> Rect {
+    Rect {
+        x: 160.0 + index * (BIG_BUTTON_WIDTH + BIG_BUTTON_GAP),
+        y: 150.0,
+        width: BIG_BUTTON_WIDTH,
+        height: BIG_BUTTON_HEIGHT,
+    }
+}
+
+fn animation_speed_buttons(selected_speed: AnimationSpeed, theme: &Theme) -> Vec<Component> {
+    AnimationSpeed::all()
+        .into_iter()
+        .enumerate()
+        .flat_map(|(i, animation_speed)| {
+            let center_x = 160.0 + SPEED_BUTTON_RADIUS + SPEED_BUTTON_OFFSET * i as f64;
+            let background = Component::Circle {
+                fill_color: if animation_speed == selected_speed {
+                    theme.home_button_background
+                } else {
+                    theme
+                        .home_button_background
+                        .with_alpha(UNSELECTED_SPEED_ALPHA)
                 },
-                on_click: None,
+                shape: Circle {
+                    x: center_x,
+                    y: SPEED_BUTTON_CENTER_Y,
+                    radius: SPEED_BUTTON_RADIUS,
+                },
+                on_click: Some(Action::SetAnimationSpeed(animation_speed)),
+            };
+
+            vec![background].into_iter().chain(animation_speed_glyph(
+                animation_speed,
+                center_x,
+                theme,
+            ))
+        })
+        .collect()
+}
+
+fn reduce_motion_button(reduce_motion: bool, theme: &Theme, messages: Messages) -> Vec<Component> {
+    let center_x = 160.0
+        + SPEED_BUTTON_RADIUS
+        + SPEED_BUTTON_OFFSET * (AnimationSpeed::all().len() as f64 + 0.5);
+
+    vec![
+        Component::Circle {
+            fill_color: if reduce_motion {
+                theme.home_button_background
+            } else {
+                theme
+                    .home_button_background
+                    .with_alpha(UNSELECTED_SPEED_ALPHA)
             },
-            Component::Image {
-                image_type: ImageType::PassAndPlayButton,
-                alpha: 0.5,
-                shape: Rect {
-                    x: 160.0 + 1.0 * (388.0 + 40.0),
-                    y: 150.0,
-                    width: 388.0,
-                    height: 240.0,
+            shape: Circle {
+                x: center_x,
+                y: SPEED_BUTTON_CENTER_Y,
+                radius: SPEED_BUTTON_RADIUS,
+            },
+            on_click: Some(Action::ToggleReducedMotion),
+        },
+        Component::Rect {
+            fill_color: theme.home_button_foreground,
+            shape: Rect {
+                x: center_x - 0.5 * GLYPH_HEIGHT,
+                y: SPEED_BUTTON_CENTER_Y - 0.5 * GLYPH_HEIGHT,
+                width: GLYPH_HEIGHT,
+                height: GLYPH_HEIGHT,
+            },
+            on_click: None,
+        },
+        render::aligned_label(
+            messages.text("settings.reduce_motion"),
+            (
+                center_x + SPEED_BUTTON_RADIUS + LABEL_GAP,
+                SPEED_BUTTON_CENTER_Y,
+            ),
+            LABEL_FONT_SIZE,
+            TextAlign::Left,
+            theme.screen_text,
+            Some(LABEL_MAX_WIDTH),
+        ),
+    ]
+}
+
+fn theme_buttons(selected_theme: ThemeName, theme: &Theme, messages: Messages) -> Vec<Component> {
+    ThemeName::all()
+        .into_iter()
+        .enumerate()
+        .flat_map(|(i, theme_name)| {
+            let swatch_theme = Theme::named(theme_name);
+            let center_x = 160.0 + SPEED_BUTTON_RADIUS + SPEED_BUTTON_OFFSET * i as f64;
+            let selection_ring = if theme_name == selected_theme {
+                Some(Component::Circle {
+                    fill_color: theme.home_button_background,
+                    shape: Circle {
+                        x: center_x,
+                        y: THEME_BUTTON_CENTER_Y,
+                        radius: SPEED_BUTTON_RADIUS + SELECTED_THEME_RING_WIDTH,
+                    },
+                    on_click: None,
+                })
+            } else {
+                None
+            };
+            let swatch = Component::Circle {
+                fill_color: swatch_theme.background,
+                shape: Circle {
+                    x: center_x,
+                    y: THEME_BUTTON_CENTER_Y,
+                    radius: SPEED_BUTTON_RADIUS,
+                },
+                on_click: Some(Action::SetTheme(theme_name)),
+            };
+            let dots = vec![
+                (-1.0, swatch_theme.dark_move),
+                (1.0, swatch_theme.light_move),
+            ]
+            .into_iter()
+            .map(move |(direction, fill_color)| Component::Circle {
+                fill_color,
+                shape: Circle {
+                    x: center_x + direction * THEME_DOT_OFFSET,
+                    y: THEME_BUTTON_CENTER_Y,
+                    radius: THEME_DOT_RADIUS,
                 },
                 on_click: None,
-            },
-            Component::Image {
-                image_type: ImageType::CustomSeedButton,
-                alpha: 1.0,
-                shape: Rect {
-                    x: 160.0 + 2.0 * (388.0 + 40.0),
-                    y: 150.0,
-                    width: 388.0,
-                    height: 240.0,
+            });
+
+            let name = render::aligned_label(
+                messages.text(theme_label_key(theme_name)),
+                (
+                    center_x,
+                    THEME_BUTTON_CENTER_Y
+                        + SPEED_BUTTON_RADIUS
+                        + SELECTED_THEME_RING_WIDTH
+                        + LABEL_GAP,
+                ),
+                LABEL_FONT_SIZE,
+                TextAlign::Center,
+                theme.screen_text,
+                Some(LABEL_MAX_WIDTH),
+            );
+
+            selection_ring
+                .into_iter()
+                .chain(vec![swatch])
+                .chain(dots)
+                .chain(vec![name])
+        })
+        .collect()
+}
+
+fn theme_label_key(theme_name: ThemeName) -> &'static str {
+    match theme_name {
+        ThemeName::Classic => "settings.theme_classic",
+        ThemeName::Dark => "settings.theme_dark",
+        ThemeName::HighContrast => "settings.theme_high_contrast",
+        ThemeName::Deuteranopia => "settings.theme_deuteranopia",
+    }
+}
+
+fn language_buttons(
+    selected_language: Option<Language>,
+    theme: &Theme,
+    messages: Messages,
+) -> Vec<Component> {
+    vec![None]
+        .into_iter()
+        .chain(Language::all().into_iter().map(Some))
+        .enumerate()
+        .flat_map(|(i, language)| {
+            let shape = Rect {
+                x: 160.0 + (LANGUAGE_BUTTON_WIDTH + LANGUAGE_BUTTON_GAP) * i as f64,
+                y: LANGUAGE_BUTTON_Y,
+                width: LANGUAGE_BUTTON_WIDTH,
+                height: LANGUAGE_BUTTON_HEIGHT,
+            };
+            let text = match language {
+                Some(language) => messages.language_name(language),
+                None => messages.text("settings.detect_language"),
+            };
+            let selection_ring = if language == selected_language {
+                Some(Component::UnclickablePath {
+                    path: Path::rounded_rect(
+                        &Rect {
+                            x: shape.x - SELECTED_THEME_RING_WIDTH,
+                            y: shape.y - SELECTED_THEME_RING_WIDTH,
+                            width: shape.width + 2.0 * SELECTED_THEME_RING_WIDTH,
+                            height: shape.height + 2.0 * SELECTED_THEME_RING_WIDTH,
+                        },
+                        LANGUAGE_BUTTON_CORNER_RADIUS,
+                    ),
+                    fill_color: None,
+                    stroke: Some(Stroke {
+                        color: theme.screen_text,
+                        width: 0.5 * SELECTED_THEME_RING_WIDTH,
+                    }),
+                })
+            } else {
+                None
+            };
+
+            selection_ring.into_iter().chain(render::text_button(
+                text,
+                shape,
+                Some(Action::SetLanguage(language)),
+                theme,
+            ))
+        })
+        .collect()
+}
+
+fn hint_limit_buttons(selected_limit: u8, theme: &Theme, messages: Messages) -> Vec<Component> {
+    let title = render::aligned_label(
+        messages.text("settings.hints_per_game"),
+        (HINT_BUTTONS_X, SPEED_BUTTON_CENTER_Y),
+        LABEL_FONT_SIZE,
+        TextAlign::Left,
+        theme.screen_text,
+        Some(LABEL_MAX_WIDTH),
+    );
+    let buttons = Settings::HINT_LIMITS
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &limit)| {
+            let center_x = HINT_BUTTONS_X + SPEED_BUTTON_RADIUS + HINT_BUTTON_OFFSET * i as f64;
+
+            vec![
+                Component::Circle {
+                    fill_color: if limit == selected_limit {
+                        theme.home_button_background
+                    } else {
+                        theme
+                            .home_button_background
+                            .with_alpha(UNSELECTED_SPEED_ALPHA)
+                    },
+                    shape: Circle {
+                        x: center_x,
+                        y: HINT_BUTTON_CENTER_Y,
+                        radius: SPEED_BUTTON_RADIUS,
+                    },
+                    on_click: Some(Action::SetHintsPerGame(limit)),
                 },
-                on_click: Some(Action::PromptUserForCustomSeed),
+                render::aligned_label(
+                    limit.to_string(),
+                    (center_x, HINT_BUTTON_CENTER_Y),
+                    HINT_BUTTON_FONT_SIZE,
+                    TextAlign::Center,
+                    theme.home_button_foreground,
+                    None,
+                ),
+            ]
+        });
+
+    vec![title].into_iter().chain(buttons).collect()
+}
+
+fn animation_speed_glyph(
+    animation_speed: AnimationSpeed,
+    center_x: f64,
+    theme: &Theme,
+) -> Vec<Component> {
+    let triangle_count = match animation_speed {
+        AnimationSpeed::Half => 1,
+        AnimationSpeed::Normal | AnimationSpeed::Instant => 2,
+        AnimationSpeed::Double => 3,
+    };
+    let has_bar = animation_speed == AnimationSpeed::Instant;
+    let glyph_width = GLYPH_TRIANGLE_WIDTH * f64::from(triangle_count)
+        + if has_bar { GLYPH_BAR_WIDTH } else { 0.0 };
+    let left = center_x - 0.5 * glyph_width;
+
+    let triangles = (0..triangle_count).map(|i| {
+        let base_x = left + GLYPH_TRIANGLE_WIDTH * f64::from(i);
+
+        Component::UnclickablePath {
+            path: Path {
+                start: (base_x, SPEED_BUTTON_CENTER_Y - 0.5 * GLYPH_HEIGHT),
+                commands: vec![
+                    PathCommand::LineTo(base_x + GLYPH_TRIANGLE_WIDTH, SPEED_BUTTON_CENTER_Y),
+                    PathCommand::LineTo(base_x, SPEED_BUTTON_CENTER_Y + 0.5 * GLYPH_HEIGHT),
+                ],
             },
-        ],
-    ]
-    .into_iter()
-    .flatten()
-    .collect()
+            fill_color: Some(theme.home_button_foreground),
+            stroke: None,
+        }
+    });
+    let bar = if has_bar {
+        Some(Component::Rect {
+            fill_color: theme.home_button_foreground,
+            shape: Rect {
+                x: left + glyph_width - GLYPH_BAR_WIDTH,
+                y: SPEED_BUTTON_CENTER_Y - 0.5 * GLYPH_HEIGHT,
+                width: GLYPH_BAR_WIDTH,
+                height: GLYPH_HEIGHT,
+            },
+            on_click: None,
+        })
+    } else {
+        None
+    };
+
+    triangles.chain(bar).collect()
 }
 
 const ORIGINAL_RADIUS: f64 = 120.0;
 const MARGIN: f64 = 30.0;
 const TARGET_RADIUS: f64 = 40.0;
+
+const DIFFICULTY_FONT_SIZE: f64 = 56.0;
+const DIFFICULTY_MAX_WIDTH: f64 = 750.0;
+const BIG_BUTTON_WIDTH: f64 = 388.0;
+const BIG_BUTTON_HEIGHT: f64 = 240.0;
+const BIG_BUTTON_GAP: f64 = 40.0;
+const UNAVAILABLE_BUTTON_OPACITY: f64 = 0.5;
+
+const SPEED_BUTTON_RADIUS: f64 = 50.0;
+const SPEED_BUTTON_OFFSET: f64 = 140.0;
+const SPEED_BUTTON_CENTER_Y: f64 = 480.0;
+const THEME_BUTTON_CENTER_Y: f64 = 620.0;
+const SELECTED_THEME_RING_WIDTH: f64 = 8.0;
+const LABEL_GAP: f64 = 20.0;
+const LABEL_FONT_SIZE: f64 = 24.0;
+const LABEL_MAX_WIDTH: f64 = 300.0;
+const LANGUAGE_BUTTON_Y: f64 = 770.0;
+const LANGUAGE_BUTTON_WIDTH: f64 = 240.0;
+const LANGUAGE_BUTTON_HEIGHT: f64 = 90.0;
+const LANGUAGE_BUTTON_GAP: f64 = 40.0;
+const LANGUAGE_BUTTON_CORNER_RADIUS: f64 = 48.0;
+const HINT_BUTTONS_X: f64 = 1300.0;
+const HINT_BUTTON_OFFSET: f64 = 115.0;
+const HINT_BUTTON_CENTER_Y: f64 = THEME_BUTTON_CENTER_Y;
+const HINT_BUTTON_FONT_SIZE: f64 = 40.0;
+const THEME_DOT_OFFSET: f64 = 18.0;
+const THEME_DOT_RADIUS: f64 = 14.0;
+const UNSELECTED_SPEED_ALPHA: u8 = 0x60;
+const GLYPH_TRIANGLE_WIDTH: f64 = 20.0;
+const GLYPH_BAR_WIDTH: f64 = 8.0;
+const GLYPH_HEIGHT: f64 = 36.0;
@@ -0,0 +1,344 @@
+use crate::canvas_dimensions;
+use crate::click::Action;
+use crate::colors;
+use crate::opponent::Difficulty;
+use crate::paint::{Component, ImageType};
+use crate::render::{self, lerp::Lerper};
+use crate::shapes::{Circle, Rect};
+use crate::stats::{RecentMatch, Stats, Tally};
+use crate::text::TextAlign;
+use crate::theme::Theme;
+use crate::transform::{Scale, Translate};
+
+use nzscq::choices::{Booster, Character, Move};
+
+pub fn stats_screen(stats: &Stats, theme: &Theme) -> Vec<Component> {
+    vec![
+        vec![Component::Background {
+            color: theme.settings_screen_background,
+        }],
+        render::home_button(&Lerper::from_completion_factor(1.0), theme)
+            .translate(-canvas_dimensions::CENTER_X, -canvas_dimensions::CENTER_Y)
+            .scale(TARGET_RADIUS / ORIGINAL_RADIUS)
+            .translate(MARGIN + TARGET_RADIUS, MARGIN + TARGET_RADIUS),
+        win_rates(stats, theme),
+        move_success_rates(stats, theme),
+        streaks(stats, theme),
+        recent_matches(&stats.recent_matches, theme),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn win_rates(stats: &Stats, theme: &Theme) -> Vec<Component> {
+    let difficulties =
+        stats
+            .wins_by_difficulty
+            .iter()
+            .enumerate()
+            .map(|(row, &(difficulty, tally))| {
+                let (x, y) = (LEFT_COLUMN_X, row_y(row as f64));
+                vec![
+                    difficulty_icon(difficulty, x, y),
+                    rate_bar(tally, x, y, theme),
+                ]
+            });
+    let characters =
+        stats
+            .wins_by_character
+            .iter()
+            .enumerate()
+            .map(|(row, &(character, tally))| {
+                let (x, y) = (LEFT_COLUMN_X, row_y(row as f64 + 3.5));
+                vec![
+                    character_icon(character, x, y, theme),
+                    rate_bar(tally, x, y, theme),
+                ]
+            });
+    let boosters = stats
+        .wins_by_booster
+        .iter()
+        .enumerate()
+        .map(|(row, &(booster, tally))| {
+            let (x, y) = (MIDDLE_COLUMN_X, row_y(row as f64));
+            vec![
+                booster_icon(booster, x, y, theme),
+                rate_bar(tally, x, y, theme),
+            ]
+        });
+
+    difficulties
+        .chain(characters)
+        .chain(boosters)
+        .flatten()
+        .flatten()
+        .collect()
+}
+
+fn move_success_rates(stats: &Stats, theme: &Theme) -> Vec<Component> {
+    let most_successful = stats
+        .most_successful_moves(MOVES_SHOWN)
+        .into_iter()
+        .enumerate()
+        .map(|(row, (m, tally))| (row as f64, m, tally));
+    let least_successful = stats
+        .least_successful_moves(MOVES_SHOWN)
+        .into_iter()
+        .enumerate()
+        .map(|(row, (m, tally))| ((row + MOVES_SHOWN) as f64 + 0.5, m, tally));
+
+    most_successful
+        .chain(least_successful)
+        .flat_map(|(row, m, tally)| {
+            let (x, y) = (RIGHT_COLUMN_X, row_y(row));
+            vec![move_icon(m, x, y, theme), rate_bar(tally, x, y, theme)]
+        })
+        .flatten()
+        .collect()
+}
+
+fn streaks(stats: &Stats, theme: &Theme) -> Vec<Component> {
+    let current_y = row_y(7.0);
+    let best_y = row_y(8.0);
+
+    vec![
+        vec![
+            image(ImageType::Heart, RIGHT_COLUMN_X, current_y),
+            image(ImageType::Star, RIGHT_COLUMN_X, best_y),
+        ],
+        streak_pips(stats.current_streak, current_y, theme),
+        streak_pips(stats.best_streak, best_y, theme),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn streak_pips(streak: u32, y: f64, theme: &Theme) -> Vec<Component> {
+    (0..MAX_STREAK_PIPS)
+        .map(|i| Component::Circle {
+            fill_color: if i < streak {
+                theme.stats_bar_fill
+            } else {
+                theme.stats_bar_track
+            },
+            shape: Circle {
+                x: RIGHT_COLUMN_X + ICON_AREA_WIDTH + PIP_RADIUS + PIP_SPACING * f64::from(i),
+                y: y + 0.5 * ICON_SIZE,
+                radius: PIP_RADIUS,
+            },
+            on_click: None,
+        })
+        .collect()
+}
+
+fn recent_matches(recent_matches: &[RecentMatch], theme: &Theme) -> Vec<Component> {
+    recent_matches
+        .iter()
+        .enumerate()
+        .flat_map(|(i, recent_match)| {
+            let x = LEFT_COLUMN_X + (TILE_WIDTH + TILE_GAP) * i as f64;
+            let y = RECENT_MATCHES_Y;
+
+            vec![
+                vec![Component::Rect {
+                    fill_color: if recent_match.did_human_win {
+                        theme.stats_win
+                    } else {
+                        theme.stats_loss
+                    },
+                    shape: Rect {
+                        x,
+                        y,
+                        width: TILE_WIDTH,
+                        height: TILE_HEIGHT,
+                    },
+                    on_click: Some(Action::ReplayMatch(recent_match.index)),
+                }],
+                character_icon(
+                    recent_match.characters[HUMAN],
+                    x + TILE_PADDING,
+                    y + TILE_PADDING,
+                    theme,
+                ),
+                booster_icon(
+                    recent_match.boosters[HUMAN],
+                    x + TILE_WIDTH - TILE_PADDING - ICON_SIZE,
+                    y + TILE_PADDING,
+                    theme,
+                ),
+                character_icon(
+                    recent_match.characters[COMPUTER],
+                    x + TILE_PADDING,
+                    y + TILE_HEIGHT - TILE_PADDING - ICON_SIZE,
+                    theme,
+                ),
+                booster_icon(
+                    recent_match.boosters[COMPUTER],
+                    x + TILE_WIDTH - TILE_PADDING - ICON_SIZE,
+                    y + TILE_HEIGHT - TILE_PADDING - ICON_SIZE,
+                    theme,
+                ),
+            ]
+            .into_iter()
+            .flatten()
+        })
+        .collect()
+}
+
+fn rate_bar(tally: Tally, x: f64, y: f64, theme: &Theme) -> Vec<Component> {
+    let track = Rect {
+        x: x + ICON_AREA_WIDTH,
+        y: y + 0.5 * (ICON_SIZE - BAR_HEIGHT),
+        width: BAR_WIDTH,
+        height: BAR_HEIGHT,
+    };
+    let count = render::aligned_label(
+        format!("{}/{}", tally.successes, tally.attempts),
+        (track.x + BAR_WIDTH, track.y - 0.5 * TALLY_FONT_SIZE),
+        TALLY_FONT_SIZE,
+        TextAlign::Right,
+        theme.screen_text,
+        Some(BAR_WIDTH),
+    );
+
+    let bar = match tally.ratio() {
+        Some(ratio) => vec![
+            Component::Rect {
+                fill_color: theme.stats_bar_track,
+                shape: track.clone(),
+                on_click: None,
+            },
+            Component::Rect {
+                fill_color: theme.stats_bar_fill,
+                shape: Rect {
+                    width: BAR_WIDTH * ratio,
+                    ..track
+                },
+                on_click: None,
+            },
+        ],
+        None => vec![Component::Rect {
+            fill_color: theme
+                .stats_bar_track
+                .with_alpha(colors::DISABLED_DEQUEUE_ARSENAL_ITEM_ALPHA),
+            shape: track,
+            on_click: None,
+        }],
+    };
+
+    bar.into_iter().chain(vec![count]).collect()
+}
+
+fn difficulty_icon(difficulty: Difficulty, x: f64, y: f64) -> Vec<Component> {
+    (0..3)
+        .map(|i| Component::Image {
+            image_type: if i > difficulty as u8 {
+                ImageType::EmptyStar
+            } else {
+                ImageType::Star
+            },
+            alpha: 1.0,
+            shape: Rect {
+                x: x + STAR_SIZE * f64::from(i),
+                y: y + 0.5 * (ICON_SIZE - STAR_SIZE),
+                width: STAR_SIZE,
+                height: STAR_SIZE,
+            },
+            on_click: None,
+        })
+        .collect()
+}
+
+fn character_icon(character: Character, x: f64, y: f64, theme: &Theme) -> Vec<Component> {
+    icon_on_background(
+        ImageType::Character(character),
+        theme.character_color(character),
+        x,
+        y,
+    )
+}
+
+fn booster_icon(booster: Booster, x: f64, y: f64, theme: &Theme) -> Vec<Component> {
+    icon_on_background(
+        ImageType::Booster(booster),
+        theme.booster_color(booster),
+        x,
+        y,
+    )
+}
+
+fn move_icon(m: Move, x: f64, y: f64, theme: &Theme) -> Vec<Component> {
+    icon_on_background(ImageType::Move(m), theme.move_color(m), x, y)
+}
+
+fn icon_on_background(
+    image_type: ImageType,
+    color: colors::Rgba,
+    x: f64,
+    y: f64,
+) -> Vec<Component> {
+    vec![
+        Component::Rect {
+            fill_color: color,
+            shape: Rect {
+                x,
+                y,
+                width: ICON_SIZE,
+                height: ICON_SIZE,
+            },
+            on_click: None,
+        },
+        image(image_type, x, y),
+    ]
+}
+
+fn image(image_type: ImageType, x: f64, y: f64) -> Component {
+    Component::Image {
+        image_type,
+        alpha: 1.0,
+        shape: Rect {
+            x,
+            y,
+            width: ICON_SIZE,
+            height: ICON_SIZE,
+        },
+        on_click: None,
+    }
+}
+
+fn row_y(row: f64) -> f64 {
+    TOP + ROW_HEIGHT * row
+}
+
+const ORIGINAL_RADIUS: f64 = 120.0;
+const MARGIN: f64 = 30.0;
+const TARGET_RADIUS: f64 = 40.0;
+
+const TOP: f64 = 150.0;
+const ROW_HEIGHT: f64 = 75.0;
+const LEFT_COLUMN_X: f64 = 160.0;
+const MIDDLE_COLUMN_X: f64 = 640.0;
+const RIGHT_COLUMN_X: f64 = 1120.0;
+
+const ICON_SIZE: f64 = 60.0;
+const ICON_AREA_WIDTH: f64 = 100.0;
+const STAR_SIZE: f64 = 28.0;
+const BAR_WIDTH: f64 = 320.0;
+const BAR_HEIGHT: f64 = 24.0;
+const TALLY_FONT_SIZE: f64 = 16.0;
+
+const MOVES_SHOWN: usize = 3;
+const MAX_STREAK_PIPS: u32 = 10;
+const PIP_RADIUS: f64 = 12.0;
+const PIP_SPACING: f64 = 32.0;
+
+const RECENT_MATCHES_Y: f64 = 840.0;
+const TILE_WIDTH: f64 = 180.0;
+const TILE_HEIGHT: f64 = 140.0;
+const TILE_GAP: f64 = 20.0;
+const TILE_PADDING: f64 = 10.0;
+
+const HUMAN: usize = 0;
+const COMPUTER: usize = 1;
@@ -1,10 +1,70 @@
 use super::lerp::Lerper;
 use crate::paint::Component;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Motion {
+    Full,
+    Reduced,
+}
+
 pub trait Switch {
-    fn case(self, completion_factor: f64) -> Option<Vec<Component>>;
+    fn ranges(&self) -> Vec<(f64, f64)>;
+
+    fn render_case(&self, index: usize, lerper: Lerper) -> Vec<Component>;
+
+    fn case(self, completion_factor: f64, motion: Motion) -> Option<Vec<Component>>
+    where
+        Self: Sized,
+    {
+        let ranges = self.ranges();
+        let index = index_of_case(&ranges, completion_factor)?;
+        let lerper = Lerper::from_completion_factor(completion_factor);
+
+        match motion {
+            Motion::Full => {
+                Some(self.render_case(index, lerper.sub_lerper(ranges[index].0..ranges[index].1)))
+            }
+            Motion::Reduced => {
+                let (start, end) = ranges[index];
+                let after = self.render_case(index, Lerper::from_completion_factor(1.0));
+                if end <= start {
+                    return Some(after);
+                }
+
+                let crossfade_end = start + REDUCED_MOTION_CROSSFADE_PORTION * (end - start);
+                let opacity = 1.0 - lerper.sub_lerper(start..crossfade_end).lerp(0.0, 1.0);
+                if opacity <= 0.0 {
+                    return Some(after);
+                }
+
+                let before = if index == 0 {
+                    self.render_case(0, Lerper::from_completion_factor(0.0))
+                } else {
+                    self.render_case(index - 1, Lerper::from_completion_factor(1.0))
+                };
+
+                Some(
+                    after
+                        .into_iter()
+                        .chain(before.into_iter().map(|component| component.faded(opacity)))
+                        .collect(),
+                )
+            }
+        }
+    }
+}
+
+fn index_of_case(ranges: &[(f64, f64)], completion_factor: f64) -> Option<usize> {
+    let last_index = ranges.len() - 1;
+
+    ranges.iter().enumerate().position(|(i, &(start, end))| {
+        start <= completion_factor
+            && (completion_factor < end || (i == last_index && completion_factor <= end))
+    })
 }
 
+const REDUCED_MOTION_CROSSFADE_PORTION: f64 = 0.25;
+
 pub struct Switch4<A, B, C, D>(
     pub (std::ops::Range<f64>, A),
     pub (std::ops::Range<f64>, B),
@@ -12,35 +72,34 @@ pub struct Switch4<A, B, C, D>(
     pub (std::ops::RangeInclusive<f64>, D),
 )
 where
-    A: FnOnce(Lerper) -> Vec<Component>,
-    B: FnOnce(Lerper) -> Vec<Component>,
-    C: FnOnce(Lerper) -> Vec<Component>,
-    D: FnOnce(Lerper) -> Vec<Component>;
+    A: Fn(Lerper) -> Vec<Component>,
+    B: Fn(Lerper) -> Vec<Component>,
+    C: Fn(Lerper) -> Vec<Component>,
+    D: Fn(Lerper) -> Vec<Component>;
 
 impl<A, B, C, D> Switch for Switch4<A, B, C, D>
 where
-    A: FnOnce(Lerper) -> Vec<Component>,
-    B: FnOnce(Lerper) -> Vec<Component>,
-    C: FnOnce(Lerper) -> Vec<Component>,
-    D: FnOnce(Lerper) -> Vec<Component>,
+    A: Fn(Lerper) -> Vec<Component>,
+    B: Fn(Lerper) -> Vec<Component>,
+    C: Fn(Lerper) -> Vec<Component>,
+    D: Fn(Lerper) -> Vec<Component>,
 {
-    fn case(self, completion_factor: f64) -> Option<Vec<Component>> {
-        let lerper = Lerper::from_completion_factor(completion_factor);
+    fn ranges(&self) -> Vec<(f64, f64)> {
+        vec![
+            ((self.0).0.start, (self.0).0.end),
+            ((self.1).0.start, (self.1).0.end),
+            ((self.2).0.start, (self.2).0.end),
+            (*(self.3).0.start(), *(self.3).0.end()),
+        ]
+    }
 
-        if (self.0).0.contains(&completion_factor) {
-            let lerper = lerper.sub_lerper((self.0).0);
-            Some((self.0).1(lerper))
-        } else if (self.1).0.contains(&completion_factor) {
-            let lerper = lerper.sub_lerper((self.1).0);
-            Some((self.1).1(lerper))
-        } else if (self.2).0.contains(&completion_factor) {
-            let lerper = lerper.sub_lerper((self.2).0);
-            Some((self.2).1(lerper))
-        } else if (self.3).0.contains(&completion_factor) {
-            let lerper = lerper.sub_lerper((self.3).0);
-            Some((self.3).1(lerper))
-        } else {
-            None
+    fn render_case(&self, index: usize, lerper: Lerper) -> Vec<Component> {
+        match index {
+            0 => (self.0).1(lerper),
+            1 => (self.1).1(lerper),
+            2 => (self.2).1(lerper),
+            3 => (self.3).1(lerper),
+            _ => panic!("Switch4 should only have 4 cases"),
         }
     }
 }
@@ -53,40 +112,89 @@ pub struct Switch5<A, B, C, D, E>(
     pub (std::ops::RangeInclusive<f64>, E),
 )
 where
-    A: FnOnce(Lerper) -> Vec<Component>,
-    B: FnOnce(Lerper) -> Vec<Component>,
-    C: FnOnce(Lerper) -> Vec<Component>,
-    D: FnOnce(Lerper) -> Vec<Component>,
-    E: FnOnce(Lerper) -> Vec<Component>;
+    A: Fn(Lerper) -> Vec<Component>,
+    B: Fn(Lerper) -> Vec<Component>,
+    C: Fn(Lerper) -> Vec<Component>,
+    D: Fn(Lerper) -> Vec<Component>,
+    E: Fn(Lerper) -> Vec<Component>;
 
 impl<A, B, C, D, E> Switch for Switch5<A, B, C, D, E>
 where
-    A: FnOnce(Lerper) -> Vec<Component>,
-    B: FnOnce(Lerper) -> Vec<Component>,
-    C: FnOnce(Lerper) -> Vec<Component>,
-    D: FnOnce(Lerper) -> Vec<Component>,
-    E: FnOnce(Lerper) -> Vec<Component>,
+    A: Fn(Lerper) -> Vec<Component>,
+    B: Fn(Lerper) -> Vec<Component>,
+    C: Fn(Lerper) -> Vec<Component>,
+    D: Fn(Lerper) -> Vec<Component>,
+    E: Fn(Lerper) -> Vec<Component>,
 {
-    fn case(self, completion_factor: f64) -> Option<Vec<Component>> {
-        let lerper = Lerper::from_completion_factor(completion_factor);
+    fn ranges(&self) -> Vec<(f64, f64)> {
+        vec![
+            ((self.0).0.start, (self.0).0.end),
+            ((self.1).0.start, (self.1).0.end),
+            ((self.2).0.start, (self.2).0.end),
+            ((self.3).0.start, (self.3).0.end),
+            (*(self.4).0.start(), *(self.4).0.end()),
+        ]
+    }
 
-        if (self.0).0.contains(&completion_factor) {
-            let lerper = lerper.sub_lerper((self.0).0);
-            Some((self.0).1(lerper))
-        } else if (self.1).0.contains(&completion_factor) {
-            let lerper = lerper.sub_lerper((self.1).0);
-            Some((self.1).1(lerper))
-        } else if (self.2).0.contains(&completion_factor) {
-            let lerper = lerper.sub_lerper((self.2).0);
-            Some((self.2).1(lerper))
-        } else if (self.3).0.contains(&completion_factor) {
-            let lerper = lerper.sub_lerper((self.3).0);
-            Some((self.3).1(lerper))
-        } else if (self.4).0.contains(&completion_factor) {
-            let lerper = lerper.sub_lerper((self.4).0);
-            Some((self.4).1(lerper))
-        } else {
-            None
+    fn render_case(&self, index: usize, lerper: Lerper) -> Vec<Component> {
+        match index {
+            0 => (self.0).1(lerper),
+            1 => (self.1).1(lerper),
+            2 => (self.2).1(lerper),
+            3 => (self.3).1(lerper),
+            4 => (self.4).1(lerper),
+            _ => panic!("Switch5 should only have 5 cases"),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colors::Rgba;
+
+    fn background(lerper: Lerper) -> Vec<Component> {
+        vec![Component::Background {
+            color: Rgba(0, 0, 0, lerper.lerp(0u8, 0xFF)),
+        }]
+    }
+
+    fn alphas(components: Vec<Component>) -> Vec<u8> {
+        components
+            .into_iter()
+            .map(|component| match component {
+                Component::Background { color } => color.3,
+                _ => panic!("should only render backgrounds"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reduced_motion_crossfades_between_completed_cases() {
+        let switch = || {
+            Switch4(
+                (0.0..0.25, background),
+                (0.25..0.5, background),
+                (0.5..1.0, background),
+                (1.0..=1.0, background),
+            )
+        };
+
+        assert_eq!(
+            vec![0x7F],
+            alphas(switch().case(0.375, Motion::Full).unwrap())
+        );
+        assert_eq!(
+            vec![0xFF, 0xFF],
+            alphas(switch().case(0.25, Motion::Reduced).unwrap())
+        );
+        assert_eq!(
+            vec![0xFF],
+            alphas(switch().case(0.375, Motion::Reduced).unwrap())
+        );
+        assert_eq!(
+            vec![0xFF],
+            alphas(switch().case(1.0, Motion::Reduced).unwrap())
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use crate::click::Action;
+use crate::colors::Rgba;
+use crate::paint::{Component, Path};
+use crate::shapes::Rect;
+use crate::text::{Font, FontFamily, FontWeight, TextAlign};
+use crate::theme::Theme;
+
+pub fn text_button(
+    text: String,
+    shape: Rect,
+    on_click: Option<Action>,
+    theme: &Theme,
+) -> Vec<Component> {
+    let anchor = (shape.x + 0.5 * shape.width, shape.y + 0.5 * shape.height);
+    let max_width = shape.width - 2.0 * PADDING;
+
+    vec![
+        Component::Rect {
+            fill_color: Rgba::transparent(),
+            shape: shape.clone(),
+            on_click,
+        },
+        Component::UnclickablePath {
+            path: Path::rounded_rect(&shape, CORNER_RADIUS),
+            fill_color: Some(theme.home_button_background),
+            stroke: None,
+        },
+        Component::Text {
+            text,
+            font: Font {
+                family: FontFamily::SansSerif,
+                weight: FontWeight::Normal,
+                size: FONT_SIZE,
+            },
+            align: TextAlign::Center,
+            color: theme.home_button_foreground,
+            anchor,
+            max_width: Some(max_width),
+            measured_width: None,
+            on_click: None,
+        },
+    ]
+}
+
+const CORNER_RADIUS: f64 = 40.0;
+const PADDING: f64 = 24.0;
+const FONT_SIZE: f64 = 48.0;
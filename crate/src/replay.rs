@@ -0,0 +1,306 @@
+use crate::analysis::Analysis;
+use crate::context::Context;
+use crate::game_summary::GameSummary;
+use crate::notation::GameRecord;
+use crate::paint::Component;
+use crate::phase::Phase;
+use crate::render::{self, AnalysisPanel, Render, ReplayControls};
+use crate::settings::AnimationSpeed;
+use crate::side::Side;
+
+use nzscq::{choices::BatchChoice, game::BatchChoiceGame};
+
+use ordered_float::NotNan;
+
+use std::hash::{Hash, Hasher};
+
+#[derive(Debug)]
+pub struct ReplayState {
+    pub animation_start_time: f64,
+
+    pub record: GameRecord,
+    phases: Vec<Phase>,
+    summary: Option<GameSummary>,
+    analysis: Option<Analysis>,
+    index: usize,
+    is_playing: bool,
+    is_skipping_animation: bool,
+}
+
+impl ReplayState {
+    pub fn new(record: GameRecord, animation_start_time: f64) -> Result<ReplayState, ()> {
+        let mut game = BatchChoiceGame::default();
+        let mut phases = vec![Phase::initial(&game)];
+
+        for choices in &record.choices {
+            let phase = phases
+                .last()
+                .expect("should have initial phase")
+                .next(&mut game, choices.clone())?;
+            phases.push(phase);
+        }
+
+        let summary = GameSummary::from_choices(&record.choices).ok();
+
+        Ok(ReplayState {
+            animation_start_time,
+
+            record,
+            phases,
+            summary,
+            analysis: None,
+            index: 0,
+            is_playing: true,
+            is_skipping_animation: false,
+        })
+    }
+
+    pub fn phase(&self) -> &Phase {
+        &self.phases[self.index]
+    }
+
+    pub fn phase_mut(&mut self) -> &mut Phase {
+        &mut self.phases[self.index]
+    }
+
+    pub fn choices_so_far(&self) -> Vec<BatchChoice> {
+        self.record.choices[..self.index].to_vec()
+    }
+
+    pub fn analyze(&mut self) -> Result<(), ()> {
+        if self.analysis.is_none() {
+            self.analysis = Some(Analysis::from_choices(&self.record.choices)?);
+        }
+        self.is_playing = false;
+
+        Ok(())
+    }
+
+    pub fn analysis(&self) -> Option<&Analysis> {
+        self.analysis.as_ref()
+    }
+
+    pub fn restart(&mut self, current_time: f64) {
+        self.index = 0;
+        self.animation_start_time = current_time;
+        self.is_playing = true;
+        self.is_skipping_animation = false;
+    }
+
+    pub fn toggle_playback(&mut self, current_time: f64) {
+        if self.is_playing {
+            self.is_playing = false;
+        } else {
+            self.is_playing = true;
+
+            if self.index == self.last_index() {
+                self.restart(current_time);
+            }
+        }
+    }
+
+    pub fn step_forward(&mut self, current_time: f64) {
+        if self.index < self.last_index() {
+            self.index += 1;
+            self.animation_start_time = current_time;
+            self.is_skipping_animation = false;
+        }
+    }
+
+    pub fn step_backward(&mut self, current_time: f64) {
+        if self.index > 0 {
+            self.seek(self.index - 1, current_time);
+        }
+    }
+
+    pub fn skip_to_next_round(&mut self, current_time: f64) {
+        let next_round_start = (self.index + 1..self.phases.len())
+            .find(|&i| self.phases[i].starts_round())
+            .unwrap_or_else(|| self.last_index());
+
+        if next_round_start != self.index {
+            self.index = next_round_start;
+            self.animation_start_time = current_time;
+            self.is_skipping_animation = false;
+        }
+    }
+
+    pub fn skip_to_previous_round(&mut self, current_time: f64) {
+        let previous_round_start = (0..self.index)
+            .rev()
+            .find(|&i| self.phases[i].starts_round())
+            .unwrap_or(0);
+
+        self.seek(previous_round_start, current_time);
+    }
+
+    pub fn seek(&mut self, index: usize, current_time: f64) {
+        self.index = index.min(self.last_index());
+        self.animation_start_time = current_time;
+        self.is_skipping_animation = true;
+    }
+
+    pub fn update(&mut self, current_time: f64, animation_speed: AnimationSpeed) -> bool {
+        let elapsed_time = current_time - self.animation_start_time - PAUSE_BETWEEN_PHASES;
+        let animation_speed = self.effective_animation_speed(animation_speed);
+
+        if self.is_playing
+            && self
+                .phase()
+                .is_elapsed_time_past_completion(elapsed_time, animation_speed)
+        {
+            if self.index < self.last_index() {
+                self.step_forward(current_time);
+            } else {
+                self.is_playing = false;
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn is_current_time_past_completion(
+        &self,
+        current_time: f64,
+        animation_speed: AnimationSpeed,
+    ) -> bool {
+        let elapsed_time = current_time - self.animation_start_time;
+        let animation_speed = self.effective_animation_speed(animation_speed);
+
+        !self.is_playing
+            && self
+                .phase()
+                .is_elapsed_time_past_completion(elapsed_time, animation_speed)
+    }
+
+    fn effective_animation_speed(&self, animation_speed: AnimationSpeed) -> AnimationSpeed {
+        if self.is_skipping_animation {
+            AnimationSpeed::Instant
+        } else {
+            animation_speed
+        }
+    }
+
+    fn last_index(&self) -> usize {
+        self.phases.len() - 1
+    }
+}
+
+impl Render<&Context> for ReplayState {
+    fn render(&self, context: &Context) -> Vec<Component> {
+        let mut components = self.phase().render((
+            self.animation_start_time,
+            self.effective_animation_speed(context.settings.animation_speed),
+            Side::Left,
+            context,
+        ));
+
+        for component in &mut components {
+            if let Some(on_click) = component.on_click_mut() {
+                if on_click.as_ref().is_some_and(|action| action.is_choice()) {
+                    *on_click = None;
+                }
+            }
+        }
+
+        if let Some(summary) = &self.summary {
+            if self.phase().is_showing_results(
+                self.animation_start_time,
+                context.current_time,
+                self.effective_animation_speed(context.settings.animation_speed),
+            ) {
+                components.extend(summary.render((context.theme(), context.messages())));
+                if self.analysis.is_none() {
+                    components.extend(render::analyze_button(context.theme(), context.messages()));
+                }
+            }
+        }
+
+        if let Some(analysis) = &self.analysis {
+            components.extend(
+                AnalysisPanel {
+                    analysis,
+                    phase_index: self.index,
+                    is_expanded: !self.is_playing,
+                    theme: context.theme(),
+                    messages: context.messages(),
+                }
+                .render(()),
+            );
+        }
+
+        let mistake_phases = self
+            .analysis
+            .as_ref()
+            .map(Analysis::mistake_phases)
+            .unwrap_or_default();
+        components.extend(
+            ReplayControls {
+                phases: &self.phases,
+                index: self.index,
+                is_playing: self.is_playing,
+                mistake_phases: &mistake_phases,
+                theme: context.theme(),
+                messages: context.messages(),
+            }
+            .render(()),
+        );
+
+        components
+    }
+}
+
+impl Hash for ReplayState {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let animation_start_time =
+            NotNan::new(self.animation_start_time).expect("animation_start_time should not be NaN");
+        animation_start_time.hash(state);
+        self.record.hash(state);
+        self.phases.hash(state);
+        self.summary.hash(state);
+        self.analysis.hash(state);
+        self.index.hash(state);
+        self.is_playing.hash(state);
+        self.is_skipping_animation.hash(state);
+    }
+}
+
+const PAUSE_BETWEEN_PHASES: f64 = 1.0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replay() -> ReplayState {
+        let record = "seed=replay\ndifficulty=easy\nninja zombie\nshadow regenerative\n(decline decline)\nkick ram\n"
+            .parse()
+            .unwrap();
+
+        ReplayState::new(record, 0.0).unwrap()
+    }
+
+    #[test]
+    fn replay_has_one_phase_per_choice() {
+        let replay = replay();
+
+        assert_eq!(5, replay.phases.len());
+        assert!(matches!(
+            replay.phases[4],
+            Phase::ChooseSubsequentDequeue(_)
+        ));
+    }
+
+    #[test]
+    fn round_skipping_passes_over_action_phases() {
+        let mut replay = replay();
+
+        replay.seek(4, 0.0);
+        replay.skip_to_previous_round(0.0);
+        assert_eq!(2, replay.index);
+
+        replay.skip_to_next_round(0.0);
+        assert_eq!(4, replay.index);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::helpers;
+use crate::phase::Phase;
+
+use nzscq::{
+    choices::{ArsenalItem, BatchChoice, Booster, Character, DequeueChoice},
+    outcomes::ActionPointsDestroyed,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RoundLogEntry {
+    Characters {
+        characters: [Character; 2],
+        is_rechoose: bool,
+    },
+    Boosters([Booster; 2]),
+    Round {
+        number: usize,
+        dequeues: [DequeueChoice; 2],
+        exits: [Option<ArsenalItem>; 2],
+        outcome: Option<[ActionPointsDestroyed; 2]>,
+    },
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RoundLog {
+    pub entries: Vec<RoundLogEntry>,
+    pub is_expanded: bool,
+    first_visible_row: Option<usize>,
+}
+
+impl RoundLog {
+    pub fn record(&mut self, choices: &BatchChoice, phase: &Phase) {
+        let entry = match (choices, phase) {
+            (BatchChoice::Characters(characters), _) => RoundLogEntry::Characters {
+                characters: helpers::vec2_to_arr2(characters.clone()),
+                is_rechoose: matches!(phase, Phase::RechooseCharacter(_)),
+            },
+            (BatchChoice::Boosters(boosters), _) => {
+                RoundLogEntry::Boosters(helpers::vec2_to_arr2(boosters.clone()))
+            }
+            (BatchChoice::DequeueChoices(dequeues), Phase::ChooseAction(phase)) => {
+                RoundLogEntry::Round {
+                    number: self.round_count() + 1,
+                    dequeues: helpers::vec2_to_arr2(dequeues.clone()),
+                    exits: [
+                        phase.previous_scoreboard[0].queue.exit,
+                        phase.previous_scoreboard[1].queue.exit,
+                    ],
+                    outcome: None,
+                }
+            }
+            (BatchChoice::Actions(_), _) => {
+                let outcome = phase.previous_action_outcome();
+                if let Some(RoundLogEntry::Round {
+                    outcome: logged_outcome,
+                    ..
+                }) = self.entries.last_mut()
+                {
+                    *logged_outcome = outcome.cloned();
+                }
+                return;
+            }
+            _ => return,
+        };
+
+        self.entries.push(entry);
+    }
+
+    pub fn toggle(&mut self) {
+        self.is_expanded = !self.is_expanded;
+    }
+
+    pub fn first_visible_row(&self) -> usize {
+        let last_page_start = self.entries.len().saturating_sub(VISIBLE_ROWS);
+
+        self.first_visible_row
+            .unwrap_or(last_page_start)
+            .min(last_page_start)
+    }
+
+    pub fn can_scroll_up(&self) -> bool {
+        self.first_visible_row() > 0
+    }
+
+    pub fn can_scroll_down(&self) -> bool {
+        self.first_visible_row() + VISIBLE_ROWS < self.entries.len()
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.first_visible_row = Some(self.first_visible_row().saturating_sub(1));
+    }
+
+    pub fn scroll_down(&mut self) {
+        let row = self.first_visible_row() + 1;
+        self.first_visible_row = Some(row);
+
+        if !self.can_scroll_down() {
+            self.first_visible_row = None;
+        }
+    }
+
+    fn round_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| matches!(entry, RoundLogEntry::Round { .. }))
+            .count()
+    }
+}
+
+pub const VISIBLE_ROWS: usize = 12;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test_games;
+    use nzscq::choices::{Action, Move};
+    use nzscq::game::BatchChoiceGame;
+
+    #[test]
+    fn rounds_collect_dequeues_and_action_outcomes() {
+        let mut game = BatchChoiceGame::default();
+        let mut phase = Phase::initial(&game);
+        let mut log = RoundLog::default();
+        let mut choices = vec![BatchChoice::Characters(vec![
+            Character::Ninja,
+            Character::Ninja,
+        ])];
+        choices.extend(test_games::first_round_choices(Action::Move(Move::Kick)));
+
+        for choice in choices {
+            phase = phase
+                .next(&mut game, choice.clone())
+                .expect("choice should be legal");
+            log.record(&choice, &phase);
+        }
+
+        assert_eq!(4, log.entries.len());
+        assert_eq!(
+            RoundLogEntry::Characters {
+                characters: [Character::Ninja, Character::Ninja],
+                is_rechoose: true,
+            },
+            log.entries[0]
+        );
+        match &log.entries[3] {
+            RoundLogEntry::Round {
+                number, outcome, ..
+            } => {
+                assert_eq!(1, *number);
+                assert_eq!(
+                    Action::Move(Move::Kick),
+                    outcome.as_ref().expect("round should be finished")[0].0
+                );
+            }
+            entry => panic!("{:?} should be a round", entry),
+        }
+    }
+}
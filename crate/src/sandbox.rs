@@ -0,0 +1,248 @@
+use crate::context::Context;
+use crate::paint::Component;
+use crate::phase::{ChooseBoosterPhase, Phase};
+use crate::render::{Render, SandboxControls};
+use crate::settings::AnimationSpeed;
+use crate::side::Side;
+
+use nzscq::{
+    choices::{Action, BatchChoice, Booster, Character, DequeueChoice},
+    game::BatchChoiceGame,
+};
+
+use ordered_float::NotNan;
+
+use std::hash::{Hash, Hasher};
+
+#[derive(Debug)]
+pub struct SandboxState {
+    pub animation_start_time: f64,
+
+    game: BatchChoiceGame,
+    phase: Phase,
+    choices: Vec<BatchChoice>,
+    undone_choices: Vec<BatchChoice>,
+    pending_choice: Option<BatchChoice>,
+}
+
+impl SandboxState {
+    pub fn new(choices: Vec<BatchChoice>, animation_start_time: f64) -> Result<SandboxState, ()> {
+        let (game, phase) = replay_choices(&choices)?;
+
+        Ok(SandboxState {
+            animation_start_time,
+
+            game,
+            phase,
+            choices,
+            undone_choices: vec![],
+            pending_choice: None,
+        })
+    }
+
+    pub fn phase_mut(&mut self) -> &mut Phase {
+        &mut self.phase
+    }
+
+    pub fn seat(&self) -> Side {
+        if self.pending_choice.is_some() {
+            Side::Right
+        } else {
+            Side::Left
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.pending_choice.is_some() || !self.choices.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.pending_choice.is_none() && !self.undone_choices.is_empty()
+    }
+
+    pub fn handle_character_choice(&mut self, character: Character) -> bool {
+        self.handle_choice(BatchChoice::Characters(vec![character]))
+    }
+
+    pub fn handle_booster_choice(&mut self, booster: Booster) -> bool {
+        self.handle_choice(BatchChoice::Boosters(vec![booster]))
+    }
+
+    pub fn handle_dequeue_choice(&mut self, dequeue: DequeueChoice) -> bool {
+        self.handle_choice(BatchChoice::DequeueChoices(vec![dequeue]))
+    }
+
+    pub fn handle_action_choice(&mut self, action: Action) -> bool {
+        self.handle_choice(BatchChoice::Actions(vec![action]))
+    }
+
+    pub fn undo(&mut self) -> Result<(), ()> {
+        if self.pending_choice.take().is_some() {
+            return Ok(());
+        }
+
+        if let Some(choice) = self.choices.pop() {
+            let (game, phase) = replay_choices(&self.choices)?;
+            self.game = game;
+            self.phase = phase;
+            self.undone_choices.push(choice);
+            self.animation_start_time = f64::NEG_INFINITY;
+        }
+
+        Ok(())
+    }
+
+    pub fn redo(&mut self) -> Result<(), ()> {
+        if let Some(choice) = self.undone_choices.pop() {
+            self.pending_choice = None;
+            self.phase = self.phase.next(&mut self.game, choice.clone())?;
+            self.choices.push(choice);
+        }
+
+        Ok(())
+    }
+
+    pub fn is_current_time_past_completion(
+        &self,
+        current_time: f64,
+        animation_speed: AnimationSpeed,
+    ) -> bool {
+        let elapsed_time = current_time - self.animation_start_time;
+
+        self.phase
+            .is_elapsed_time_past_completion(elapsed_time, animation_speed)
+    }
+
+    fn handle_choice(&mut self, choice: BatchChoice) -> bool {
+        match self.pending_choice.take() {
+            None => {
+                self.pending_choice = Some(choice);
+                false
+            }
+            Some(left_choice) => {
+                let choices = both_sides(left_choice, choice).expect("sides should choose alike");
+                self.phase = self
+                    .phase
+                    .next(&mut self.game, choices.clone())
+                    .expect("should have outcome");
+                self.choices.push(choices);
+                self.undone_choices.clear();
+                true
+            }
+        }
+    }
+
+    pub fn seated_phase(&self) -> Phase {
+        match (&self.phase, self.seat()) {
+            (Phase::ChooseBooster(phase), Side::Right) => {
+                Phase::ChooseBooster(ChooseBoosterPhase {
+                    available_boosters: self
+                        .game
+                        .choices()
+                        .boosters()
+                        .expect("should be able to choose booster")
+                        .remove(Side::Right as usize),
+                    ..phase.clone()
+                })
+            }
+            (phase, _) => phase.clone(),
+        }
+    }
+}
+
+impl Render<&Context> for SandboxState {
+    fn render(&self, context: &Context) -> Vec<Component> {
+        let mut components = self.seated_phase().render((
+            self.animation_start_time,
+            context.settings.animation_speed,
+            self.seat(),
+            context,
+        ));
+        components.extend(
+            SandboxControls {
+                seat: self.seat(),
+                can_undo: self.can_undo(),
+                can_redo: self.can_redo(),
+                theme: context.theme(),
+                messages: context.messages(),
+            }
+            .render(()),
+        );
+
+        components
+    }
+}
+
+impl Hash for SandboxState {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let animation_start_time =
+            NotNan::new(self.animation_start_time).expect("animation_start_time should not be NaN");
+        animation_start_time.hash(state);
+        self.game.hash(state);
+        self.phase.hash(state);
+        self.choices.hash(state);
+        self.undone_choices.hash(state);
+        self.pending_choice.hash(state);
+    }
+}
+
+fn replay_choices(choices: &[BatchChoice]) -> Result<(BatchChoiceGame, Phase), ()> {
+    let mut game = BatchChoiceGame::default();
+    let mut phase = Phase::initial(&game);
+
+    for choice in choices {
+        phase = phase.next(&mut game, choice.clone())?;
+    }
+
+    Ok((game, phase))
+}
+
+fn both_sides(left: BatchChoice, right: BatchChoice) -> Result<BatchChoice, ()> {
+    match (left, right) {
+        (BatchChoice::Characters(mut left), BatchChoice::Characters(right)) => {
+            left.extend(right);
+            Ok(BatchChoice::Characters(left))
+        }
+        (BatchChoice::Boosters(mut left), BatchChoice::Boosters(right)) => {
+            left.extend(right);
+            Ok(BatchChoice::Boosters(left))
+        }
+        (BatchChoice::DequeueChoices(mut left), BatchChoice::DequeueChoices(right)) => {
+            left.extend(right);
+            Ok(BatchChoice::DequeueChoices(left))
+        }
+        (BatchChoice::Actions(mut left), BatchChoice::Actions(right)) => {
+            left.extend(right);
+            Ok(BatchChoice::Actions(left))
+        }
+        _ => Err(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test_games;
+    use nzscq::choices::Move;
+
+    #[test]
+    fn undo_and_redo_replay_the_choice_log() {
+        let mut choices = test_games::first_round_choices(Action::Move(Move::Kick));
+        choices.pop();
+        let mut sandbox = SandboxState::new(choices, 0.0).expect("choices should be legal");
+        let before_actions = sandbox.phase.clone();
+
+        assert!(!sandbox.handle_action_choice(Action::Move(Move::Kick)));
+        assert_eq!(Side::Right, sandbox.seat());
+        assert!(sandbox.handle_action_choice(Action::Move(Move::Rampage)));
+        let after_actions = sandbox.phase.clone();
+
+        sandbox.undo().unwrap();
+        assert_eq!(before_actions, sandbox.phase);
+        assert!(sandbox.can_redo());
+
+        sandbox.redo().unwrap();
+        assert_eq!(after_actions, sandbox.phase);
+        assert_eq!(4, sandbox.choices.len());
+    }
+}
@@ -0,0 +1,104 @@
+use crate::helpers;
+use crate::i18n::Messages;
+use crate::letterbox::Letterbox;
+use crate::opponent::Difficulty;
+
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Document, HtmlElement};
+
+pub struct SeedDisplay {
+    container: HtmlElement,
+    label: HtmlElement,
+    copy_link_button: HtmlElement,
+}
+
+impl SeedDisplay {
+    pub fn new(document: &Document) -> Result<SeedDisplay, JsValue> {
+        let container = document.create_element("div")?.dyn_into::<HtmlElement>()?;
+        let label = document.create_element("span")?.dyn_into::<HtmlElement>()?;
+        let copy_link_button = document
+            .create_element("button")?
+            .dyn_into::<HtmlElement>()?;
+
+        container.append_child(&label)?;
+        container.append_child(&copy_link_button)?;
+
+        let style = container.style();
+        style.set_property("position", "absolute")?;
+        style.set_property("display", "none")?;
+        style.set_property("align-items", "center")?;
+        style.set_property("font-family", "sans-serif")?;
+        style.set_property("color", "#EEEEEE")?;
+        style.set_property("background-color", "#333333AA")?;
+        style.set_property("border-radius", "0.5em")?;
+        style.set_property("padding", "0.25em 0.5em")?;
+        style.set_property("transform", "translateX(-50%)")?;
+        copy_link_button
+            .style()
+            .set_property("margin-left", "0.5em")?;
+
+        Ok(SeedDisplay {
+            container,
+            label,
+            copy_link_button,
+        })
+    }
+
+    pub fn container(&self) -> &HtmlElement {
+        &self.container
+    }
+
+    pub fn copy_link_button(&self) -> &HtmlElement {
+        &self.copy_link_button
+    }
+
+    pub fn show(
+        &self,
+        seed: &str,
+        difficulty: Difficulty,
+        messages: Messages,
+    ) -> Result<(), JsValue> {
+        self.label.set_text_content(Some(
+            &messages.format(
+                "seed.label",
+                &[&seed, &messages.difficulty_name(difficulty)],
+            )[..],
+        ));
+        self.copy_link_button
+            .set_text_content(Some(&messages.text("seed.copy_link")[..]));
+        self.container.style().set_property("display", "flex")
+    }
+
+    pub fn hide(&self) -> Result<(), JsValue> {
+        self.container.style().set_property("display", "none")
+    }
+
+    pub fn mark_link_copied(&self, messages: Messages) {
+        self.copy_link_button
+            .set_text_content(Some(&messages.text("seed.link_copied")[..]));
+    }
+
+    pub fn reposition(
+        &self,
+        letterbox: &Letterbox,
+        ideal_dimensions: (u32, u32),
+    ) -> Result<(), JsValue> {
+        let (ideal_width, ideal_height) = ideal_dimensions;
+        let style = self.container.style();
+
+        style.set_property(
+            "left",
+            &helpers::px(letterbox.left + 0.5 * f64::from(ideal_width) * letterbox.scale)[..],
+        )?;
+        style.set_property(
+            "top",
+            &helpers::px(
+                letterbox.top + (f64::from(ideal_height) - BOTTOM_OFFSET) * letterbox.scale,
+            )[..],
+        )?;
+        style.set_property("font-size", &helpers::px(FONT_SIZE * letterbox.scale)[..])
+    }
+}
+
+const BOTTOM_OFFSET: f64 = 70.0;
+const FONT_SIZE: f64 = 28.0;
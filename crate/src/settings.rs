@@ -0,0 +1,319 @@
+use crate::helpers;
+use crate::i18n::Language;
+use crate::opponent::Difficulty;
+
+use web_sys::Window;
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Settings {
+    pub computer_difficulty: Difficulty,
+    pub animation_speed: AnimationSpeed,
+    pub theme: ThemeName,
+    pub reduce_motion: bool,
+    pub language: Option<Language>,
+    pub hints_per_game: u8,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            computer_difficulty: Difficulty::Medium,
+            animation_speed: AnimationSpeed::Normal,
+            theme: ThemeName::Classic,
+            reduce_motion: false,
+            language: None,
+            hints_per_game: 3,
+        }
+    }
+}
+
+impl Settings {
+    pub const HINT_LIMITS: [u8; 4] = [0, 1, 3, 5];
+
+    pub fn load(window: &Window) -> Settings {
+        let stored = helpers::get_local_storage_item(window, STORAGE_KEY);
+        let legacy_difficulty = helpers::get_local_storage_item(window, LEGACY_DIFFICULTY_KEY);
+        let settings = Settings::from_legacy(stored.as_deref(), legacy_difficulty.as_deref());
+
+        if stored.is_none() {
+            settings.save(window);
+        }
+        if legacy_difficulty.is_some() {
+            helpers::remove_local_storage_item(window, LEGACY_DIFFICULTY_KEY);
+        }
+
+        settings
+    }
+
+    pub fn save(&self, window: &Window) {
+        helpers::set_local_storage_item(window, STORAGE_KEY, &self.to_string()[..]);
+    }
+
+    fn from_legacy(stored: Option<&str>, legacy_difficulty: Option<&str>) -> Settings {
+        match stored {
+            Some(settings) => settings.parse().unwrap_or_default(),
+            None => Settings {
+                computer_difficulty: legacy_difficulty
+                    .and_then(|difficulty| Difficulty::try_from(difficulty).ok())
+                    .unwrap_or(Difficulty::Medium),
+                ..Settings::default()
+            },
+        }
+    }
+}
+
+impl Display for Settings {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        writeln!(f, "{}={}", VERSION_KEY, CURRENT_VERSION)?;
+        writeln!(
+            f,
+            "{}={}",
+            DIFFICULTY_KEY,
+            self.computer_difficulty.to_string().to_lowercase()
+        )?;
+        writeln!(f, "{}={}", ANIMATION_SPEED_KEY, self.animation_speed)?;
+        writeln!(f, "{}={}", THEME_KEY, self.theme)?;
+        writeln!(
+            f,
+            "{}={}",
+            REDUCE_MOTION_KEY,
+            if self.reduce_motion { ON } else { OFF }
+        )?;
+        match self.language {
+            Some(language) => writeln!(f, "{}={}", LANGUAGE_KEY, language)?,
+            None => writeln!(f, "{}={}", LANGUAGE_KEY, AUTO)?,
+        }
+        writeln!(f, "{}={}", HINTS_KEY, self.hints_per_game)
+    }
+}
+
+impl FromStr for Settings {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Settings, ()> {
+        let values: HashMap<&str, &str> = s
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| (key.trim(), value.trim()))
+            .collect();
+        let version: u32 = values.get(VERSION_KEY).ok_or(())?.parse().map_err(|_| ())?;
+        if version == 0 || version > CURRENT_VERSION {
+            return Err(());
+        }
+
+        let defaults = Settings::default();
+
+        Ok(Settings {
+            computer_difficulty: values
+                .get(DIFFICULTY_KEY)
+                .and_then(|&value| Difficulty::try_from(value).ok())
+                .unwrap_or(defaults.computer_difficulty),
+            animation_speed: values
+                .get(ANIMATION_SPEED_KEY)
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(defaults.animation_speed),
+            theme: values
+                .get(THEME_KEY)
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(defaults.theme),
+            reduce_motion: match values.get(REDUCE_MOTION_KEY) {
+                Some(&ON) => true,
+                Some(&OFF) => false,
+                _ => defaults.reduce_motion,
+            },
+            language: values
+                .get(LANGUAGE_KEY)
+                .and_then(|value| value.parse().ok())
+                .or(defaults.language),
+            hints_per_game: values
+                .get(HINTS_KEY)
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(defaults.hints_per_game),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnimationSpeed {
+    Half,
+    Normal,
+    Double,
+    Instant,
+}
+
+impl AnimationSpeed {
+    pub fn all() -> Vec<AnimationSpeed> {
+        vec![
+            AnimationSpeed::Half,
+            AnimationSpeed::Normal,
+            AnimationSpeed::Double,
+            AnimationSpeed::Instant,
+        ]
+    }
+
+    pub fn multiplier(self) -> f64 {
+        match self {
+            AnimationSpeed::Half => 0.5,
+            AnimationSpeed::Normal => 1.0,
+            AnimationSpeed::Double => 2.0,
+            AnimationSpeed::Instant => f64::INFINITY,
+        }
+    }
+}
+
+impl Display for AnimationSpeed {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            AnimationSpeed::Half => write!(f, "0.5"),
+            AnimationSpeed::Normal => write!(f, "1"),
+            AnimationSpeed::Double => write!(f, "2"),
+            AnimationSpeed::Instant => write!(f, "instant"),
+        }
+    }
+}
+
+impl FromStr for AnimationSpeed {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<AnimationSpeed, ()> {
+        match s {
+            "0.5" => Ok(AnimationSpeed::Half),
+            "1" => Ok(AnimationSpeed::Normal),
+            "2" => Ok(AnimationSpeed::Double),
+            "instant" => Ok(AnimationSpeed::Instant),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThemeName {
+    Classic,
+    Dark,
+    HighContrast,
+    Deuteranopia,
+}
+
+impl ThemeName {
+    pub fn all() -> Vec<ThemeName> {
+        vec![
+            ThemeName::Classic,
+            ThemeName::Dark,
+            ThemeName::HighContrast,
+            ThemeName::Deuteranopia,
+        ]
+    }
+}
+
+impl Display for ThemeName {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ThemeName::Classic => write!(f, "classic"),
+            ThemeName::Dark => write!(f, "dark"),
+            ThemeName::HighContrast => write!(f, "high-contrast"),
+            ThemeName::Deuteranopia => write!(f, "deuteranopia"),
+        }
+    }
+}
+
+impl FromStr for ThemeName {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<ThemeName, ()> {
+        match s {
+            "classic" => Ok(ThemeName::Classic),
+            "dark" => Ok(ThemeName::Dark),
+            "high-contrast" => Ok(ThemeName::HighContrast),
+            "deuteranopia" => Ok(ThemeName::Deuteranopia),
+            _ => Err(()),
+        }
+    }
+}
+
+const STORAGE_KEY: &str = "nzscq_settings";
+const LEGACY_DIFFICULTY_KEY: &str = "nzscq_computer_difficulty";
+const CURRENT_VERSION: u32 = 1;
+
+const VERSION_KEY: &str = "version";
+const DIFFICULTY_KEY: &str = "difficulty";
+const ANIMATION_SPEED_KEY: &str = "animation_speed";
+const THEME_KEY: &str = "theme";
+const REDUCE_MOTION_KEY: &str = "reduce_motion";
+const LANGUAGE_KEY: &str = "language";
+const HINTS_KEY: &str = "hints";
+const ON: &str = "on";
+const OFF: &str = "off";
+const AUTO: &str = "auto";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settings_round_trip() {
+        let settings = Settings {
+            computer_difficulty: Difficulty::Stupid,
+            animation_speed: AnimationSpeed::Instant,
+            theme: ThemeName::Classic,
+            reduce_motion: true,
+            language: Some(Language::Chinese),
+            hints_per_game: 5,
+        };
+
+        assert_eq!(Ok(settings), settings.to_string().parse());
+    }
+
+    #[test]
+    fn theme_names_round_trip() {
+        for theme in ThemeName::all() {
+            assert_eq!(Ok(theme), theme.to_string().parse());
+        }
+    }
+
+    #[test]
+    fn corrupt_values_fall_back_to_defaults() {
+        let settings: Settings =
+            "version=1\ndifficulty=easy\nanimation_speed=fast\nreduce_motion=maybe\nsound=off\n"
+                .parse()
+                .unwrap();
+
+        assert_eq!(
+            Settings {
+                computer_difficulty: Difficulty::Easy,
+                ..Settings::default()
+            },
+            settings
+        );
+    }
+
+    #[test]
+    fn legacy_difficulty_is_migrated_only_without_stored_settings() {
+        assert_eq!(
+            Settings {
+                computer_difficulty: Difficulty::Stupid,
+                ..Settings::default()
+            },
+            Settings::from_legacy(None, Some("stupid"))
+        );
+
+        let stored = Settings {
+            computer_difficulty: Difficulty::Easy,
+            ..Settings::default()
+        };
+        assert_eq!(
+            stored,
+            Settings::from_legacy(Some(&stored.to_string()[..]), Some("stupid"))
+        );
+    }
+
+    #[test]
+    fn unversioned_settings_are_rejected() {
+        assert_eq!(Err(()), "difficulty=easy".parse::<Settings>());
+        assert_eq!(Err(()), "version=99\ndifficulty=easy".parse::<Settings>());
+    }
+}
@@ -1,6 +1,6 @@
 use crate::transform::{Scale, Translate};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Rect {
     pub x: f64,
     pub y: f64,
@@ -5,8 +5,8 @@ pub enum Side {
 }
 
 impl Side {
-    pub fn if_left<T>(self, item: T) -> Option<T> {
-        if self == Side::Left {
+    pub fn if_seated<T>(self, seat: Side, item: T) -> Option<T> {
+        if self == seat {
             Some(item)
         } else {
             None
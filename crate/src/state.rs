@@ -1,18 +1,22 @@
 use crate::context::Context;
-use crate::helpers;
-use crate::opponent::{Difficulty, Opponent};
+use crate::game_summary::GameSummary;
+use crate::hint::{self, Hint};
+use crate::matchup_matrix::MatrixView;
+use crate::notation::GameRecord;
+use crate::opponent::{Difficulty, Opponent, PrngState};
 use crate::paint::Component;
-use crate::phase::{
-    ChooseActionPhase, ChooseBoosterPhase, ChooseCharacterPhase, ChooseFirstDequeuePhase,
-    ChooseSubsequentDequeuePhase, GameOverPhase, MoveInspectorState, Phase, RechooseCharacterPhase,
-};
+use crate::phase::Phase;
 use crate::render::{self, Render};
+use crate::replay::ReplayState;
+use crate::round_log::RoundLog;
+use crate::sandbox::SandboxState;
+use crate::settings::AnimationSpeed;
+use crate::side::Side;
+use crate::stats::Stats;
 use crate::xorshift::Xorshift128Plus;
 
-use nzscq::choices::{Action as NzscAction, BatchChoice, Booster, Character, DequeueChoice};
+use nzscq::choices::{Action as NzscAction, BatchChoice, Booster, Character, DequeueChoice, Move};
 use nzscq::game::BatchChoiceGame;
-use nzscq::outcomes::Outcome;
-use nzscq::scoreboard::{ActionlessPlayer, DequeueingPlayer};
 
 use ordered_float::NotNan;
 
@@ -22,7 +26,12 @@ use std::hash::{Hash, Hasher};
 pub enum State {
     HomeScreen,
     SettingsScreen,
+    StatsScreen(Box<Stats>),
+    EncyclopediaScreen(Option<Move>),
+    MatrixScreen(Box<MatrixView>),
     SinglePlayer(Box<SinglePlayerState>),
+    Replay(Box<ReplayState>),
+    Sandbox(Box<SandboxState>),
 }
 
 impl State {
@@ -32,19 +41,42 @@ impl State {
         seed: &str,
         computer_difficulty: Difficulty,
     ) {
-        let game = BatchChoiceGame::default();
-        let computer = Opponent::new(computer_difficulty, Box::new(Xorshift128Plus::from(seed)));
-        let initial_human_choices = game.choices().characters().unwrap().remove(0);
-
-        *self = State::SinglePlayer(Box::new(SinglePlayerState {
+        *self = State::SinglePlayer(Box::new(SinglePlayerState::new(
             animation_start_time,
+            seed,
+            computer_difficulty,
+        )));
+    }
 
-            game,
-            computer,
-            phase: Phase::ChooseCharacter(ChooseCharacterPhase {
-                available_characters: initial_human_choices,
-            }),
-        }));
+    pub fn start_challenge_game(
+        &mut self,
+        animation_start_time: f64,
+        seed: &str,
+        computer_difficulty: Difficulty,
+    ) {
+        let mut state = SinglePlayerState::new(animation_start_time, seed, computer_difficulty);
+        state.is_challenge = true;
+
+        *self = State::SinglePlayer(Box::new(state));
+    }
+
+    pub fn resume_single_player_game(
+        &mut self,
+        animation_start_time: f64,
+        record: &GameRecord,
+    ) -> Result<(), ()> {
+        let mut resumed = State::HomeScreen;
+        resumed.start_single_player_game(animation_start_time, &record.seed[..], record.difficulty);
+
+        if let State::SinglePlayer(state) = &mut resumed {
+            for choice in &record.choices {
+                state.handle_recorded_choice(choice)?;
+            }
+        }
+
+        *self = resumed;
+
+        Ok(())
     }
 
     pub fn start_animation(&mut self, animation_start_time: f64) {
@@ -52,14 +84,115 @@ impl State {
             State::SinglePlayer(state) => {
                 state.animation_start_time = animation_start_time;
             }
+            State::Sandbox(state) => {
+                state.animation_start_time = animation_start_time;
+            }
+
+            _ => {}
+        }
+    }
+
+    pub fn start_replay(
+        &mut self,
+        animation_start_time: f64,
+        record: GameRecord,
+    ) -> Result<(), ()> {
+        *self = State::Replay(Box::new(ReplayState::new(record, animation_start_time)?));
+
+        Ok(())
+    }
+
+    pub fn start_sandbox(
+        &mut self,
+        animation_start_time: f64,
+        choices: Vec<BatchChoice>,
+    ) -> Result<(), ()> {
+        *self = State::Sandbox(Box::new(SandboxState::new(choices, animation_start_time)?));
+
+        Ok(())
+    }
+
+    pub fn start_analysis(
+        &mut self,
+        animation_start_time: f64,
+        record: GameRecord,
+    ) -> Result<(), ()> {
+        let mut replay = ReplayState::new(record, animation_start_time)?;
+        replay.analyze()?;
+        *self = State::Replay(Box::new(replay));
+
+        Ok(())
+    }
+
+    pub fn is_animating_phase(
+        &mut self,
+        current_time: f64,
+        animation_speed: AnimationSpeed,
+    ) -> bool {
+        match self {
+            State::SinglePlayer(state) => {
+                !state.is_current_time_past_completion(current_time, animation_speed)
+            }
+            State::Sandbox(state) => {
+                !state.is_current_time_past_completion(current_time, animation_speed)
+            }
+            _ => false,
+        }
+    }
 
+    pub fn skip_animation(&mut self) {
+        match self {
+            State::SinglePlayer(state) => state.animation_start_time = f64::NEG_INFINITY,
+            State::Sandbox(state) => state.animation_start_time = f64::NEG_INFINITY,
             _ => {}
         }
     }
 
-    pub fn is_current_time_past_completion(&mut self, current_time: f64) -> bool {
+    pub fn phase_mut(&mut self) -> Option<&mut Phase> {
+        match self {
+            State::SinglePlayer(state) => Some(&mut state.phase),
+            State::Replay(state) => Some(state.phase_mut()),
+            State::Sandbox(state) => Some(state.phase_mut()),
+            _ => None,
+        }
+    }
+
+    pub fn round_log_mut(&mut self) -> Option<&mut RoundLog> {
+        match self {
+            State::SinglePlayer(state) => Some(&mut state.round_log),
+            _ => None,
+        }
+    }
+
+    pub fn matrix_view_mut(&mut self) -> Option<&mut MatrixView> {
+        match self {
+            State::MatrixScreen(view) => Some(view),
+            _ => None,
+        }
+    }
+
+    pub fn update(&mut self, current_time: f64, animation_speed: AnimationSpeed) -> bool {
         match self {
-            State::SinglePlayer(state) => state.is_current_time_past_completion(current_time),
+            State::Replay(state) => state.update(current_time, animation_speed),
+            _ => false,
+        }
+    }
+
+    pub fn is_current_time_past_completion(
+        &mut self,
+        current_time: f64,
+        animation_speed: AnimationSpeed,
+    ) -> bool {
+        match self {
+            State::SinglePlayer(state) => {
+                state.is_current_time_past_completion(current_time, animation_speed)
+            }
+            State::Replay(state) => {
+                state.is_current_time_past_completion(current_time, animation_speed)
+            }
+            State::Sandbox(state) => {
+                state.is_current_time_past_completion(current_time, animation_speed)
+            }
             _ => true,
         }
     }
@@ -68,11 +201,60 @@ impl State {
 impl Render<&Context> for State {
     fn render(&self, context: &Context) -> Vec<Component> {
         match &self {
-            State::HomeScreen => render::home_screen(),
+            State::HomeScreen => render::home_screen(context.theme()),
             State::SettingsScreen => render::settings_screen(context),
-            State::SinglePlayer(state) => state
-                .phase
-                .render((state.animation_start_time, context.current_time)),
+            State::StatsScreen(stats) => render::stats_screen(stats, context.theme()),
+            State::EncyclopediaScreen(selected_move) => {
+                render::encyclopedia_screen(*selected_move, context)
+            }
+            State::MatrixScreen(view) => render::matchup_matrix_screen(view, context),
+            State::SinglePlayer(state) => {
+                let mut components = state.phase.render((
+                    state.animation_start_time,
+                    context.settings.animation_speed,
+                    Side::Left,
+                    context,
+                ));
+                let hints_per_game = context.settings.hints_per_game;
+                if hints_per_game > 0 && render::HintOverlay::is_choosing(&components) {
+                    let hint_overlay = render::HintOverlay {
+                        hint: state.hint.as_ref(),
+                        remaining: hints_per_game.saturating_sub(state.hints_used),
+                        theme: context.theme(),
+                        messages: context.messages(),
+                    };
+                    let hint_components = hint_overlay.render(&components[..]);
+                    components.extend(hint_components);
+                }
+                if state.can_undo() {
+                    components.extend(render::undo_button(
+                        true,
+                        context.theme(),
+                        context.messages(),
+                    ));
+                }
+                components.extend(
+                    state
+                        .round_log
+                        .render((context.theme(), context.messages())),
+                );
+
+                if let Some(summary) = &state.summary {
+                    if state.phase.is_showing_results(
+                        state.animation_start_time,
+                        context.current_time,
+                        context.settings.animation_speed,
+                    ) {
+                        components.extend(summary.render((context.theme(), context.messages())));
+                        components
+                            .extend(render::analyze_button(context.theme(), context.messages()));
+                    }
+                }
+
+                components
+            }
+            State::Replay(state) => state.render(context),
+            State::Sandbox(state) => state.render(context),
         }
     }
 }
@@ -83,205 +265,171 @@ pub struct SinglePlayerState {
 
     pub game: BatchChoiceGame,
     pub computer: Opponent,
+    pub record: GameRecord,
     pub phase: Phase,
+    pub round_log: RoundLog,
+    pub summary: Option<GameSummary>,
+    pub hint: Option<Hint>,
+    pub hints_used: u8,
+    pub is_challenge: bool,
+    computer_snapshots: Vec<Option<PrngState>>,
 }
 
 impl SinglePlayerState {
+    fn new(
+        animation_start_time: f64,
+        seed: &str,
+        computer_difficulty: Difficulty,
+    ) -> SinglePlayerState {
+        let game = BatchChoiceGame::default();
+        let computer = Opponent::new(computer_difficulty, Box::new(Xorshift128Plus::from(seed)));
+        let phase = Phase::initial(&game);
+        let computer_snapshots = vec![computer.snapshot()];
+
+        SinglePlayerState {
+            animation_start_time,
+
+            game,
+            computer,
+            record: GameRecord::new(seed, computer_difficulty),
+            phase,
+            round_log: RoundLog::default(),
+            summary: None,
+            hint: None,
+            hints_used: 0,
+            is_challenge: false,
+            computer_snapshots,
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.is_challenge
+            && !matches!(self.phase, Phase::GameOver(_))
+            && self.snapshot_before_last_choice().is_some()
+    }
+
+    fn snapshot_before_last_choice(&self) -> Option<PrngState> {
+        let index = self.record.choices.len().checked_sub(1)?;
+
+        self.computer_snapshots.get(index).copied().flatten()
+    }
+
+    pub fn undo(&mut self) -> Result<(), ()> {
+        if !self.can_undo() {
+            return Err(());
+        }
+
+        let snapshot = self.snapshot_before_last_choice().ok_or(())?;
+        let mut choices = self.record.choices.clone();
+        choices.pop();
+        let mut rewound =
+            SinglePlayerState::new(f64::NEG_INFINITY, &self.record.seed, self.record.difficulty);
+        for choice in choices {
+            rewound.handle_choices(choice);
+        }
+        rewound.computer_snapshots = self.computer_snapshots[..self.record.choices.len()].to_vec();
+        rewound.computer.restore(snapshot)?;
+        rewound.round_log.is_expanded = self.round_log.is_expanded;
+        rewound.hints_used = self.hints_used;
+
+        *self = rewound;
+
+        Ok(())
+    }
+
+    pub fn show_hint(&mut self, hints_per_game: u8) {
+        if self.hint.is_some() || self.hints_used >= hints_per_game {
+            return;
+        }
+
+        self.hint = hint::hint(&self.game);
+        if self.hint.is_some() {
+            self.hints_used += 1;
+        }
+    }
+
+    fn handle_recorded_choice(&mut self, choice: &BatchChoice) -> Result<(), ()> {
+        match choice {
+            BatchChoice::Characters(characters) => self.handle_character_choice(characters[HUMAN]),
+            BatchChoice::Boosters(boosters) => self.handle_booster_choice(boosters[HUMAN]),
+            BatchChoice::DequeueChoices(dequeues) => self.handle_dequeue_choice(dequeues[HUMAN]),
+            BatchChoice::Actions(actions) => self.handle_action_choice(actions[HUMAN]),
+        }
+
+        if self.record.choices.last() == Some(choice) {
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+
     pub fn handle_character_choice(&mut self, human_character: Character) {
-        let previously_available_characters: Vec<Character> = self
-            .game
-            .choices()
-            .characters()
-            .expect("should be able to choose character")
-            .remove(HUMAN);
         let computer_character = self
             .computer
             .choose_character(&self.game)
             .expect("should choose character");
-        let choices = BatchChoice::Characters(vec![human_character, computer_character]);
-
-        let outcome = self.game.choose(choices).expect("should have outcome");
-
-        match outcome {
-            Outcome::CharacterPhaseDone(character_headstarts) => {
-                self.phase = Phase::ChooseBooster(ChooseBoosterPhase {
-                    previously_available_characters,
-                    previous_outcome: character_headstarts,
-                    available_boosters: self
-                        .game
-                        .choices()
-                        .boosters()
-                        .expect("should be able to choose booster")
-                        .remove(HUMAN),
-                });
-            }
-            Outcome::CharacterPhaseRechoose(characters) => {
-                self.phase = Phase::RechooseCharacter(RechooseCharacterPhase {
-                    previously_available_characters,
-                    previously_mutually_chosen_character: characters[0],
-                    available_characters: self
-                        .game
-                        .choices()
-                        .characters()
-                        .expect("should be able to choose character")
-                        .remove(HUMAN),
-                });
-            }
-            _ => panic!("outcome should be character outcome"),
-        }
+
+        self.handle_choices(BatchChoice::Characters(vec![
+            human_character,
+            computer_character,
+        ]));
     }
 
     pub fn handle_booster_choice(&mut self, human_booster: Booster) {
-        let previously_available_boosters: Vec<Booster> = self
-            .game
-            .choices()
-            .boosters()
-            .expect("should be able to choose booster")
-            .remove(HUMAN);
         let computer_booster = self
             .computer
             .choose_booster(&self.game)
             .expect("should choose booster");
-        let choices = BatchChoice::Boosters(vec![human_booster, computer_booster]);
-
-        let outcome = self.game.choose(choices).expect("should have outcome");
-
-        match outcome {
-            Outcome::BoosterPhaseDone(_) => {
-                self.phase = Phase::ChooseFirstDequeue(ChooseFirstDequeuePhase {
-                    previously_available_boosters,
-                    scoreboard: helpers::vec2_to_arr2(
-                        self.game
-                            .scoreboard()
-                            .dequeueing()
-                            .expect("should be dequeueing"),
-                    ),
-                    available_dequeues: helpers::vec2_to_arr2(
-                        self.game
-                            .choices()
-                            .dequeue_choices()
-                            .expect("should be able to choose dequeue"),
-                    ),
-                    inspector_state: MoveInspectorState::NotInspecting,
-                })
-            }
-            _ => panic!("outcome should be booster outcome"),
-        }
+
+        self.handle_choices(BatchChoice::Boosters(vec![human_booster, computer_booster]));
     }
 
     pub fn handle_dequeue_choice(&mut self, human_dequeue: DequeueChoice) {
-        let previous_scoreboard: [DequeueingPlayer; 2] = match &self.phase {
-            Phase::ChooseFirstDequeue(ChooseFirstDequeuePhase { scoreboard, .. }) => {
-                scoreboard.clone()
-            }
-            Phase::ChooseSubsequentDequeue(ChooseSubsequentDequeuePhase { scoreboard, .. }) => {
-                scoreboard.clone()
-            }
-            _ => panic!("should be on a dequeueing phase"),
-        };
-        let previously_available_dequeues = self
-            .game
-            .choices()
-            .dequeue_choices()
-            .expect("should be on a dequeuing phase");
-
         let computer_dequeue = self
             .computer
             .choose_dequeue(&self.game)
             .expect("should choose dequeue");
-        let choices = BatchChoice::DequeueChoices(vec![human_dequeue, computer_dequeue]);
-        let outcome = self.game.choose(choices).expect("should have outcome");
-
-        match outcome {
-            Outcome::DequeuePhaseDone(dequeues) => {
-                self.phase = Phase::ChooseAction(ChooseActionPhase {
-                    previous_scoreboard,
-                    previously_available_dequeues: helpers::vec2_to_arr2(
-                        previously_available_dequeues,
-                    ),
-                    previous_outcome: helpers::vec2_to_arr2(dequeues),
-                    scoreboard: helpers::vec2_to_arr2(
-                        self.game
-                            .scoreboard()
-                            .actionless()
-                            .expect("should be choosing actions"),
-                    ),
-                    available_actions: helpers::vec2_to_arr2(
-                        self.game
-                            .choices()
-                            .actions()
-                            .expect("should be able to choose action"),
-                    ),
-                    inspector_state: MoveInspectorState::NotInspecting,
-                })
-            }
-            _ => panic!("outcome should be dequeue outcome"),
-        }
+
+        self.handle_choices(BatchChoice::DequeueChoices(vec![
+            human_dequeue,
+            computer_dequeue,
+        ]));
     }
 
     pub fn handle_action_choice(&mut self, human_action: NzscAction) {
-        let previous_scoreboard: [ActionlessPlayer; 2] = match &self.phase {
-            Phase::ChooseAction(ChooseActionPhase { scoreboard, .. }) => scoreboard.clone(),
-            _ => panic!("should be on action-choosing phase"),
-        };
-        let previously_available_actions = helpers::vec2_to_arr2(
-            self.game
-                .choices()
-                .actions()
-                .expect("should be on action-choosing phase"),
-        );
-
         let computer_action = self
             .computer
             .choose_action(&self.game)
             .expect("should choose action");
-        let choices = BatchChoice::Actions(vec![human_action, computer_action]);
-        let outcome = self.game.choose(choices).expect("should have outcome");
-
-        match outcome {
-            Outcome::ActionPhaseDone(action_points_destroyed) => {
-                self.phase = Phase::ChooseSubsequentDequeue(ChooseSubsequentDequeuePhase {
-                    previous_scoreboard,
-                    previously_available_actions,
-                    previous_outcome: helpers::vec2_to_arr2(action_points_destroyed),
-                    scoreboard: helpers::vec2_to_arr2(
-                        self.game
-                            .scoreboard()
-                            .dequeueing()
-                            .expect("should be dequeueing"),
-                    ),
-                    available_dequeues: helpers::vec2_to_arr2(
-                        self.game
-                            .choices()
-                            .dequeue_choices()
-                            .expect("should be able to choose dequeue"),
-                    ),
-                    inspector_state: MoveInspectorState::NotInspecting,
-                })
-            }
 
-            Outcome::GameOver(action_points_destroyed) => {
-                self.phase = Phase::GameOver(GameOverPhase {
-                    previous_scoreboard,
-                    previously_available_actions,
-                    previous_outcome: helpers::vec2_to_arr2(action_points_destroyed),
-                    scoreboard: helpers::vec2_to_arr2(
-                        self.game
-                            .scoreboard()
-                            .final_()
-                            .expect("game should be over"),
-                    ),
-                })
-            }
+        self.handle_choices(BatchChoice::Actions(vec![human_action, computer_action]));
+    }
 
-            _ => panic!("outcome should be action outcome"),
+    fn handle_choices(&mut self, choices: BatchChoice) {
+        self.hint = None;
+        self.record.choices.push(choices.clone());
+        self.phase = self
+            .phase
+            .next(&mut self.game, choices)
+            .expect("should have outcome");
+        let choices = self.record.choices.last().expect("should have just pushed");
+        self.round_log.record(choices, &self.phase);
+        self.computer_snapshots.push(self.computer.snapshot());
+
+        if let Phase::GameOver(_) = self.phase {
+            self.summary = GameSummary::from_choices(&self.record.choices).ok();
         }
     }
 
-    fn is_current_time_past_completion(&self, current_time: f64) -> bool {
+    fn is_current_time_past_completion(
+        &self,
+        current_time: f64,
+        animation_speed: AnimationSpeed,
+    ) -> bool {
         let elapsed_time = current_time - self.animation_start_time;
 
-        self.phase.is_elapsed_time_past_completion(elapsed_time)
+        self.phase
+            .is_elapsed_time_past_completion(elapsed_time, animation_speed)
     }
 }
 
@@ -292,8 +440,76 @@ impl Hash for SinglePlayerState {
         animation_start_time.hash(state);
         self.game.hash(state);
         self.computer.hash(state);
+        self.record.hash(state);
         self.phase.hash(state);
+        self.round_log.hash(state);
+        self.summary.hash(state);
+        self.hint.hash(state);
+        self.hints_used.hash(state);
+        self.is_challenge.hash(state);
     }
 }
 
 const HUMAN: usize = 0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opponent::Random;
+
+    #[test]
+    fn undoing_a_choice_lets_the_computer_choose_the_same_way_again() {
+        let mut state = SinglePlayerState::new(0.0, "undo", Difficulty::Medium);
+        state.handle_character_choice(Character::Ninja);
+        state.handle_booster_choice(Booster::Shadow);
+        let before_undo = state.record.choices.clone();
+
+        state.undo().unwrap();
+        assert_eq!(before_undo[..1], state.record.choices[..]);
+
+        state.handle_booster_choice(Booster::Shadow);
+        assert_eq!(before_undo, state.record.choices);
+    }
+
+    #[derive(Debug)]
+    struct HiddenPrng;
+
+    impl Random for HiddenPrng {
+        fn random(&mut self) -> f64 {
+            0.5
+        }
+
+        fn snapshot(&self) -> Option<PrngState> {
+            None
+        }
+
+        fn restore(&mut self, _: PrngState) -> Result<(), ()> {
+            Err(())
+        }
+    }
+
+    #[test]
+    fn games_against_unsnapshotted_computers_cannot_be_undone() {
+        let mut state = SinglePlayerState::new(0.0, "undo", Difficulty::Medium);
+        state.computer = Opponent::new(Difficulty::Medium, Box::new(HiddenPrng));
+        state.computer_snapshots = vec![state.computer.snapshot()];
+        state.handle_character_choice(Character::Ninja);
+
+        assert!(!state.can_undo());
+        assert_eq!(Err(()), state.undo());
+    }
+
+    #[test]
+    fn challenge_games_cannot_be_undone() {
+        let mut state = State::HomeScreen;
+        state.start_challenge_game(0.0, "undo", Difficulty::Medium);
+
+        if let State::SinglePlayer(state) = &mut state {
+            state.handle_character_choice(Character::Ninja);
+            assert!(!state.can_undo());
+            assert_eq!(Err(()), state.undo());
+        } else {
+            panic!("should have started a single player game");
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use crate::history::MatchHistory;
+use crate::opponent::Difficulty;
+
+use nzscq::choices::{Action, Booster, Character, Move};
+use nzscq::game::BatchChoiceGame;
+use nzscq::outcomes::Outcome;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Tally {
+    pub successes: u32,
+    pub attempts: u32,
+}
+
+impl Tally {
+    fn add(&mut self, was_successful: bool) {
+        self.attempts += 1;
+        if was_successful {
+            self.successes += 1;
+        }
+    }
+
+    pub fn ratio(self) -> Option<f64> {
+        if self.attempts == 0 {
+            None
+        } else {
+            Some(f64::from(self.successes) / f64::from(self.attempts))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Stats {
+    pub wins_by_difficulty: Vec<(Difficulty, Tally)>,
+    pub wins_by_character: Vec<(Character, Tally)>,
+    pub wins_by_booster: Vec<(Booster, Tally)>,
+    pub move_successes: Vec<(Move, Tally)>,
+    pub current_streak: u32,
+    pub best_streak: u32,
+    pub recent_matches: Vec<RecentMatch>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecentMatch {
+    pub index: usize,
+    pub did_human_win: bool,
+    pub characters: [Character; 2],
+    pub boosters: [Booster; 2],
+}
+
+impl Stats {
+    pub fn most_successful_moves(&self, count: usize) -> Vec<(Move, Tally)> {
+        self.used_moves().into_iter().take(count).collect()
+    }
+
+    pub fn least_successful_moves(&self, count: usize) -> Vec<(Move, Tally)> {
+        self.used_moves()
+            .into_iter()
+            .skip(count)
+            .rev()
+            .take(count)
+            .collect()
+    }
+
+    fn used_moves(&self) -> Vec<(Move, Tally)> {
+        let mut used_moves: Vec<(Move, Tally)> = self
+            .move_successes
+            .iter()
+            .cloned()
+            .filter(|(_, tally)| tally.attempts > 0)
+            .collect();
+        used_moves.sort_by(|(_, a), (_, b)| {
+            let a = a.ratio().expect("used move should have a ratio");
+            let b = b.ratio().expect("used move should have a ratio");
+
+            b.partial_cmp(&a).expect("ratios should not be NaN")
+        });
+
+        used_moves
+    }
+}
+
+impl From<&MatchHistory> for Stats {
+    fn from(history: &MatchHistory) -> Stats {
+        let mut wins_by_difficulty: Vec<(Difficulty, Tally)> = Difficulty::all()
+            .into_iter()
+            .map(|difficulty| (difficulty, Tally::default()))
+            .collect();
+        let mut wins_by_character: Vec<(Character, Tally)> = Character::all()
+            .into_iter()
+            .map(|character| (character, Tally::default()))
+            .collect();
+        let mut wins_by_booster: Vec<(Booster, Tally)> = Booster::all()
+            .into_iter()
+            .map(|booster| (booster, Tally::default()))
+            .collect();
+        let mut move_successes: Vec<(Move, Tally)> = Move::all()
+            .into_iter()
+            .map(|m| (m, Tally::default()))
+            .collect();
+        let mut current_streak = 0;
+        let mut best_streak = 0;
+
+        for summary in &history.matches {
+            let did_win = summary.did_human_win;
+
+            tally(&mut wins_by_difficulty, summary.difficulty(), did_win);
+            if let Some(characters) = summary.characters() {
+                tally(&mut wins_by_character, characters[HUMAN], did_win);
+            }
+            if let Some(boosters) = summary.boosters() {
+                tally(&mut wins_by_booster, boosters[HUMAN], did_win);
+            }
+
+            let mut game = BatchChoiceGame::default();
+            for choice in &summary.record.choices {
+                match game.choose(choice.clone()) {
+                    Ok(Outcome::ActionPhaseDone(points_destroyed))
+                    | Ok(Outcome::GameOver(points_destroyed)) => {
+                        let human = &points_destroyed[HUMAN];
+                        if let Action::Move(m) | Action::Mirror(m) = human.0 {
+                            tally(&mut move_successes, m, human.1 > 0);
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(_) => break,
+                }
+            }
+
+            if did_win {
+                current_streak += 1;
+                best_streak = best_streak.max(current_streak);
+            } else {
+                current_streak = 0;
+            }
+        }
+
+        let recent_matches = history
+            .matches
+            .iter()
+            .enumerate()
+            .rev()
+            .filter_map(|(index, summary)| {
+                Some(RecentMatch {
+                    index,
+                    did_human_win: summary.did_human_win,
+                    characters: summary.characters()?,
+                    boosters: summary.boosters()?,
+                })
+            })
+            .take(RECENT_MATCH_COUNT)
+            .collect();
+
+        Stats {
+            wins_by_difficulty,
+            wins_by_character,
+            wins_by_booster,
+            move_successes,
+            current_streak,
+            best_streak,
+            recent_matches,
+        }
+    }
+}
+
+fn tally<T: PartialEq>(tallies: &mut [(T, Tally)], key: T, was_successful: bool) {
+    if let Some((_, tally)) = tallies.iter_mut().find(|(k, _)| *k == key) {
+        tally.add(was_successful);
+    }
+}
+
+const HUMAN: usize = 0;
+const RECENT_MATCH_COUNT: usize = 8;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::MatchSummary;
+
+    fn history(results: &[bool]) -> MatchHistory {
+        MatchHistory {
+            matches: results
+                .iter()
+                .enumerate()
+                .map(|(i, &did_human_win)| MatchSummary {
+                    date: i as u64,
+                    did_human_win,
+                    health: [1, 1],
+                    hints_used: 0,
+                    record: "seed=stats\ndifficulty=easy\nninja zombie\nshadow regenerative\n(decline decline)\nkick ram\n"
+                        .parse()
+                        .unwrap(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn streaks_count_consecutive_wins() {
+        let stats = Stats::from(&history(&[true, true, true, false, true, true]));
+
+        assert_eq!(2, stats.current_streak);
+        assert_eq!(3, stats.best_streak);
+    }
+
+    #[test]
+    fn win_rates_are_tallied_by_human_character() {
+        let stats = Stats::from(&history(&[true, false, false, false]));
+        let (_, ninja) = stats.wins_by_character[0];
+
+        assert_eq!(Character::Ninja, stats.wins_by_character[0].0);
+        assert_eq!(Some(0.25), ninja.ratio());
+        assert_eq!(Move::Kick, stats.most_successful_moves(1)[0].0);
+    }
+
+    #[test]
+    fn least_successful_moves_skip_the_most_successful_ones() {
+        let stats = Stats::from(&history(&[true]));
+
+        assert_eq!(1, stats.most_successful_moves(3).len());
+        assert!(stats.least_successful_moves(3).is_empty());
+    }
+}
@@ -0,0 +1,161 @@
+use crate::paint::Component;
+use crate::shapes::Rect;
+
+use web_sys::CanvasRenderingContext2d;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Font {
+    pub family: FontFamily,
+    pub weight: FontWeight,
+    pub size: f64,
+}
+
+impl Font {
+    pub fn to_css(self) -> String {
+        let weight = match self.weight {
+            FontWeight::Normal => "normal",
+            FontWeight::Bold => "bold",
+        };
+        let family = match self.family {
+            FontFamily::SansSerif => "sans-serif",
+        };
+
+        format!("{} {}px {}", weight, self.size, family)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FontFamily {
+    SansSerif,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FontWeight {
+    Normal,
+    Bold,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextAlign {
+    Left,
+    Center,
+    Right,
+}
+
+impl TextAlign {
+    pub fn to_css(self) -> &'static str {
+        match self {
+            TextAlign::Left => "left",
+            TextAlign::Center => "center",
+            TextAlign::Right => "right",
+        }
+    }
+}
+
+pub trait TextMeasurer {
+    fn measure_width(&self, text: &str, font: Font) -> f64;
+}
+
+impl TextMeasurer for CanvasRenderingContext2d {
+    fn measure_width(&self, text: &str, font: Font) -> f64 {
+        self.set_font(&font.to_css()[..]);
+
+        self.measure_text(text)
+            .map(|metrics| metrics.width())
+            .unwrap_or(0.0)
+    }
+}
+
+pub fn measure(components: &mut [Component], measurer: &impl TextMeasurer) {
+    for component in components {
+        if let Component::Text {
+            text,
+            font,
+            max_width,
+            measured_width,
+            ..
+        } = component
+        {
+            let width = measurer.measure_width(&text[..], *font);
+            *measured_width = Some(max_width.map_or(width, |max_width| width.min(max_width)));
+        }
+    }
+}
+
+pub fn bounds(component: &Component) -> Option<Rect> {
+    if let Component::Text {
+        font,
+        align,
+        anchor: (x, y),
+        measured_width: Some(width),
+        ..
+    } = component
+    {
+        let left = match align {
+            TextAlign::Left => *x,
+            TextAlign::Center => x - 0.5 * width,
+            TextAlign::Right => x - width,
+        };
+
+        Some(Rect {
+            x: left,
+            y: y - 0.5 * font.size,
+            width: *width,
+            height: font.size,
+        })
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::click::{self, Action};
+    use crate::colors::Rgba;
+
+    struct FixedAdvance(f64);
+
+    impl TextMeasurer for FixedAdvance {
+        fn measure_width(&self, text: &str, font: Font) -> f64 {
+            self.0 * font.size * text.chars().count() as f64
+        }
+    }
+
+    #[test]
+    fn measured_text_is_clickable_within_its_bounds() {
+        let mut components = vec![Component::Text {
+            text: "Go home".to_string(),
+            font: Font {
+                family: FontFamily::SansSerif,
+                weight: FontWeight::Bold,
+                size: 40.0,
+            },
+            align: TextAlign::Center,
+            color: Rgba(0, 0, 0, 0xFF),
+            anchor: (500.0, 300.0),
+            max_width: Some(100.0),
+            measured_width: None,
+            on_click: Some(Action::NavigateHome),
+        }];
+        measure(&mut components, &FixedAdvance(0.5));
+
+        assert_eq!(
+            Some(Rect {
+                x: 450.0,
+                y: 280.0,
+                width: 100.0,
+                height: 40.0,
+            }),
+            bounds(&components[0])
+        );
+        assert_eq!(
+            Some(Action::NavigateHome),
+            click::action_triggered_by_click_at((545.0, 310.0), &components)
+        );
+        assert_eq!(
+            None,
+            click::action_triggered_by_click_at((555.0, 310.0), &components)
+        );
+    }
+}
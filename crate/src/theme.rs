@@ -0,0 +1,217 @@
+use crate::colors::Rgba;
+use crate::helpers;
+use crate::settings::ThemeName;
+
+use nzscq::choices::{ArsenalItem, Booster, Character, Move};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    pub background: Rgba,
+    pub overlay: Rgba,
+    pub decline_dequeue: Rgba,
+    pub pill_enabled: Rgba,
+    pub pill_disabled: Rgba,
+    pub arrow: Rgba,
+    pub home_button_background: Rgba,
+    pub home_button_foreground: Rgba,
+    pub home_screen_background: Rgba,
+    pub settings_screen_background: Rgba,
+    pub screen_text: Rgba,
+    pub replay_controls_background: Rgba,
+    pub replay_timeline_played: Rgba,
+    pub replay_timeline_unplayed: Rgba,
+    pub hover_highlight: Rgba,
+    pub focus_ring: Rgba,
+    pub stats_bar_track: Rgba,
+    pub stats_bar_fill: Rgba,
+    pub stats_win: Rgba,
+    pub stats_loss: Rgba,
+    pub trapezoid_outcome_screen_border: Rgba,
+    pub trapezoid_fill: Rgba,
+    pub no_booster_background: Rgba,
+    pub mirror: Rgba,
+    pub dark_move: Rgba,
+    pub light_move: Rgba,
+    pub neutral_move: Rgba,
+    pub inspector_victory: Rgba,
+    pub inspector_defeat: Rgba,
+    pub inspector_special_tie: Rgba,
+    pub inspected_move_highlight: Rgba,
+}
+
+impl Theme {
+    pub fn named(name: ThemeName) -> &'static Theme {
+        match name {
+            ThemeName::Classic => &CLASSIC,
+            ThemeName::Dark => &DARK,
+            ThemeName::HighContrast => &HIGH_CONTRAST,
+            ThemeName::Deuteranopia => &DEUTERANOPIA,
+        }
+    }
+
+    pub fn character_color(&self, c: Character) -> Rgba {
+        self.move_color(helpers::character_logo_move(c))
+    }
+
+    pub fn booster_color(&self, b: Booster) -> Rgba {
+        if b == Booster::None {
+            self.no_booster_background
+        } else {
+            self.move_color(helpers::booster_logo_move(b).unwrap())
+        }
+    }
+
+    pub fn move_color(&self, m: Move) -> Rgba {
+        match m {
+            Move::Kick
+            | Move::Nunchucks
+            | Move::ShadowFireball
+            | Move::RunInCircles
+            | Move::LightningFastKarateChop
+            | Move::Rampage
+            | Move::Muscle
+            | Move::Zap
+            | Move::Gravedigger
+            | Move::ZombieCorps
+            | Move::Apocalypse
+            | Move::Helmet
+            | Move::Smash
+            | Move::StrongSmash
+            | Move::Lightning
+            | Move::Earthquake
+            | Move::Nose
+            | Move::NoseOfTheTaunted => self.dark_move,
+
+            Move::NinjaSword
+            | Move::ShadowSlip
+            | Move::Regenerate
+            | Move::SamuraiSword
+            | Move::Twist
+            | Move::Bend
+            | Move::AcidSpray
+            | Move::MustacheMash
+            | Move::BigHairyDeal => self.light_move,
+
+            Move::BackwardsMoustachio | Move::JugglingKnives => self.neutral_move,
+        }
+    }
+
+    pub fn arsenal_item_color(&self, arsenal_item: ArsenalItem) -> Rgba {
+        match arsenal_item {
+            ArsenalItem::Move(move_) => self.move_color(move_),
+            ArsenalItem::Mirror => self.mirror,
+        }
+    }
+
+    pub fn move_inspector_highlighter_color(
+        &self,
+        own_points: u8,
+        opponent_points: u8,
+    ) -> Option<Rgba> {
+        match (own_points, opponent_points) {
+            (0, 0) => None,
+            (1, 0) => Some(self.inspector_victory),
+            (0, 1) => Some(self.inspector_defeat),
+            (1, 1) => Some(self.inspector_special_tie),
+            _ => panic!(
+                "Human should never score {} while computer scores {}",
+                own_points, opponent_points
+            ),
+        }
+    }
+}
+
+const CLASSIC: Theme = Theme {
+    background: Rgba(0xF1, 0xF1, 0xF1, 0xFF),
+    overlay: Rgba(0x33, 0x33, 0x33, 0xAA),
+    decline_dequeue: Rgba(0x11, 0x11, 0x11, 0xFF),
+    pill_enabled: Rgba(0x72, 0x72, 0x72, 0xFF),
+    pill_disabled: Rgba(0x72, 0x42, 0x42, 0xFF),
+    arrow: Rgba(0x11, 0x11, 0x11, 0xFF),
+    home_button_background: Rgba(0x00, 0x88, 0xBB, 0xFF),
+    home_button_foreground: Rgba(0xEE, 0xEE, 0xEE, 0xFF),
+    home_screen_background: Rgba(0x23, 0x12, 0x01, 0xFF),
+    settings_screen_background: Rgba(0x23, 0x12, 0x01, 0xFF),
+    screen_text: Rgba(0xEE, 0xEE, 0xEE, 0xFF),
+    replay_controls_background: Rgba(0x33, 0x33, 0x33, 0xDD),
+    replay_timeline_played: Rgba(0xEE, 0xEE, 0xEE, 0xFF),
+    replay_timeline_unplayed: Rgba(0x72, 0x72, 0x72, 0xFF),
+    hover_highlight: Rgba(0xFF, 0xFF, 0xFF, 0x40),
+    focus_ring: Rgba(0xFF, 0xB3, 0x00, 0xFF),
+    stats_bar_track: Rgba(0x72, 0x72, 0x72, 0xFF),
+    stats_bar_fill: Rgba(0x00, 0x88, 0xBB, 0xFF),
+    stats_win: Rgba(0x2E, 0x7D, 0x32, 0xFF),
+    stats_loss: Rgba(0xB7, 0x1C, 0x1C, 0xFF),
+    trapezoid_outcome_screen_border: Rgba(0x49, 0x49, 0x49, 0xFF),
+    trapezoid_fill: Rgba(0xF1, 0xF1, 0xF1, 0xFF),
+    no_booster_background: Rgba(0x11, 0x11, 0x11, 0xFF),
+    mirror: Rgba(0x88, 0x88, 0x88, 0xFF),
+    dark_move: Rgba(0x11, 0x11, 0x11, 0xFF),
+    light_move: Rgba(0xDD, 0xDD, 0xDD, 0xFF),
+    neutral_move: Rgba(0x88, 0x88, 0x88, 0xFF),
+    inspector_victory: Rgba(0x44, 0xCC, 0x44, 0xCC),
+    inspector_defeat: Rgba(0x99, 0x42, 0x42, 0xCC),
+    inspector_special_tie: Rgba(0xAA, 0x99, 0x42, 0xCC),
+    inspected_move_highlight: Rgba(0x00, 0x88, 0xBB, 0xCC),
+};
+
+const DARK: Theme = Theme {
+    background: Rgba(0x21, 0x21, 0x24, 0xFF),
+    overlay: Rgba(0x00, 0x00, 0x00, 0xAA),
+    decline_dequeue: Rgba(0x60, 0x60, 0x68, 0xFF),
+    arrow: Rgba(0xDD, 0xDD, 0xDD, 0xFF),
+    home_screen_background: Rgba(0x12, 0x12, 0x14, 0xFF),
+    settings_screen_background: Rgba(0x12, 0x12, 0x14, 0xFF),
+    trapezoid_outcome_screen_border: Rgba(0x99, 0x99, 0x99, 0xFF),
+    trapezoid_fill: Rgba(0x21, 0x21, 0x24, 0xFF),
+    dark_move: Rgba(0x70, 0x70, 0x78, 0xFF),
+    neutral_move: Rgba(0xA4, 0xA4, 0xA8, 0xFF),
+    ..CLASSIC
+};
+
+const HIGH_CONTRAST: Theme = Theme {
+    background: Rgba(0xFF, 0xFF, 0xFF, 0xFF),
+    overlay: Rgba(0x00, 0x00, 0x00, 0xCC),
+    decline_dequeue: Rgba(0x00, 0x00, 0x00, 0xFF),
+    pill_enabled: Rgba(0x00, 0x00, 0x00, 0xFF),
+    pill_disabled: Rgba(0x99, 0x00, 0x00, 0xFF),
+    arrow: Rgba(0x00, 0x00, 0x00, 0xFF),
+    home_button_background: Rgba(0x00, 0x00, 0xCC, 0xFF),
+    home_button_foreground: Rgba(0xFF, 0xFF, 0xFF, 0xFF),
+    home_screen_background: Rgba(0x00, 0x00, 0x00, 0xFF),
+    settings_screen_background: Rgba(0x00, 0x00, 0x00, 0xFF),
+    screen_text: Rgba(0xFF, 0xFF, 0xFF, 0xFF),
+    replay_controls_background: Rgba(0x00, 0x00, 0x00, 0xFF),
+    replay_timeline_played: Rgba(0xFF, 0xFF, 0xFF, 0xFF),
+    replay_timeline_unplayed: Rgba(0x66, 0x66, 0x66, 0xFF),
+    hover_highlight: Rgba(0xFF, 0xFF, 0x00, 0x60),
+    focus_ring: Rgba(0xFF, 0x00, 0xFF, 0xFF),
+    stats_bar_track: Rgba(0x66, 0x66, 0x66, 0xFF),
+    stats_bar_fill: Rgba(0xFF, 0xFF, 0xFF, 0xFF),
+    stats_win: Rgba(0x00, 0x99, 0x00, 0xFF),
+    stats_loss: Rgba(0xCC, 0x00, 0x00, 0xFF),
+    trapezoid_outcome_screen_border: Rgba(0x00, 0x00, 0x00, 0xFF),
+    trapezoid_fill: Rgba(0xFF, 0xFF, 0xFF, 0xFF),
+    no_booster_background: Rgba(0x00, 0x00, 0x00, 0xFF),
+    mirror: Rgba(0x80, 0x80, 0x80, 0xFF),
+    dark_move: Rgba(0x00, 0x00, 0x00, 0xFF),
+    light_move: Rgba(0xFF, 0xFF, 0xFF, 0xFF),
+    neutral_move: Rgba(0x80, 0x80, 0x80, 0xFF),
+    inspector_victory: Rgba(0x00, 0xCC, 0x00, 0xE6),
+    inspector_defeat: Rgba(0xE0, 0x00, 0x00, 0xE6),
+    inspector_special_tie: Rgba(0xFF, 0xD7, 0x00, 0xE6),
+    inspected_move_highlight: Rgba(0x00, 0x00, 0xFF, 0xE6),
+};
+
+const DEUTERANOPIA: Theme = Theme {
+    pill_disabled: Rgba(0xA0, 0x5A, 0x00, 0xFF),
+    home_button_background: Rgba(0x00, 0x72, 0xB2, 0xFF),
+    stats_bar_fill: Rgba(0x00, 0x72, 0xB2, 0xFF),
+    stats_win: Rgba(0x00, 0x72, 0xB2, 0xFF),
+    stats_loss: Rgba(0xD5, 0x5E, 0x00, 0xFF),
+    inspector_victory: Rgba(0x00, 0x72, 0xB2, 0xCC),
+    inspector_defeat: Rgba(0xE6, 0x9F, 0x00, 0xCC),
+    inspector_special_tie: Rgba(0xF0, 0xE4, 0x42, 0xCC),
+    inspected_move_highlight: Rgba(0xCC, 0x79, 0xA7, 0xCC),
+    ..CLASSIC
+};
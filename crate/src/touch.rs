@@ -0,0 +1,70 @@
+use nzscq::choices::Move;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Touch {
+    start_coords: (f64, f64),
+    start_time: f64,
+    pub state: TouchState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TouchState {
+    Pressing,
+    Inspecting(Move),
+    Cancelled,
+}
+
+impl Touch {
+    pub fn new(start_coords: (f64, f64), start_time: f64) -> Touch {
+        Touch {
+            start_coords,
+            start_time,
+            state: TouchState::Pressing,
+        }
+    }
+
+    pub fn start_coords(&self) -> (f64, f64) {
+        self.start_coords
+    }
+
+    pub fn is_long_press(&self, current_time: f64) -> bool {
+        self.state == TouchState::Pressing && current_time - self.start_time >= LONG_PRESS_DURATION
+    }
+
+    pub fn has_moved_to(&self, canvas_coords: (f64, f64)) -> bool {
+        let (dx, dy) = (
+            canvas_coords.0 - self.start_coords.0,
+            canvas_coords.1 - self.start_coords.1,
+        );
+
+        dx * dx + dy * dy > MOVEMENT_TOLERANCE * MOVEMENT_TOLERANCE
+    }
+
+    pub fn is_inspecting(&self) -> bool {
+        matches!(self.state, TouchState::Inspecting(_))
+    }
+}
+
+const LONG_PRESS_DURATION: f64 = 0.5;
+const MOVEMENT_TOLERANCE: f64 = 30.0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_stationary_presses_become_long_presses() {
+        let touch = Touch::new((100.0, 100.0), 10.0);
+
+        assert!(!touch.is_long_press(10.2));
+        assert!(touch.is_long_press(10.5));
+        assert!(!touch.has_moved_to((110.0, 120.0)));
+        assert!(touch.has_moved_to((100.0, 140.0)));
+
+        let cancelled = Touch {
+            state: TouchState::Cancelled,
+            ..touch
+        };
+        assert!(!cancelled.is_long_press(11.0));
+    }
+}
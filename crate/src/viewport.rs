@@ -0,0 +1,162 @@
+use crate::shapes::Rect;
+
+use ordered_float::NotNan;
+
+use std::hash::{Hash, Hasher};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Viewport {
+    pub frame: Rect,
+    pub zoom: f64,
+    pub pan: (f64, f64),
+}
+
+impl Viewport {
+    pub fn new(frame: Rect) -> Viewport {
+        Viewport {
+            frame,
+            zoom: 1.0,
+            pan: (0.0, 0.0),
+        }
+    }
+
+    pub fn to_canvas(&self, content_coords: (f64, f64)) -> (f64, f64) {
+        (
+            self.frame.x + content_coords.0 * self.zoom - self.pan.0,
+            self.frame.y + content_coords.1 * self.zoom - self.pan.1,
+        )
+    }
+
+    pub fn contains(&self, canvas_coords: (f64, f64)) -> bool {
+        let (x, y) = canvas_coords;
+        let frame = &self.frame;
+
+        frame.x <= x && x <= frame.x + frame.width && frame.y <= y && y <= frame.y + frame.height
+    }
+
+    pub fn pan_by(&mut self, delta: (f64, f64), content_size: (f64, f64)) {
+        self.pan = (self.pan.0 - delta.0, self.pan.1 - delta.1);
+        self.clamp(content_size);
+    }
+
+    pub fn zoom_at(&mut self, canvas_coords: (f64, f64), factor: f64, content_size: (f64, f64)) {
+        let zoom = (self.zoom * factor).clamp(MIN_ZOOM, MAX_ZOOM);
+        let factor = zoom / self.zoom;
+        let (x, y) = (
+            canvas_coords.0 - self.frame.x,
+            canvas_coords.1 - self.frame.y,
+        );
+
+        self.zoom = zoom;
+        self.pan = ((self.pan.0 + x) * factor - x, (self.pan.1 + y) * factor - y);
+        self.clamp(content_size);
+    }
+
+    pub fn zoom_at_center(&mut self, factor: f64, content_size: (f64, f64)) {
+        let center = (
+            self.frame.x + 0.5 * self.frame.width,
+            self.frame.y + 0.5 * self.frame.height,
+        );
+
+        self.zoom_at(center, factor, content_size);
+    }
+
+    pub fn reset(&mut self) {
+        self.zoom = 1.0;
+        self.pan = (0.0, 0.0);
+    }
+
+    fn clamp(&mut self, content_size: (f64, f64)) {
+        let max_pan_x = (content_size.0 * self.zoom - self.frame.width).max(0.0);
+        let max_pan_y = (content_size.1 * self.zoom - self.frame.height).max(0.0);
+
+        self.pan = (
+            self.pan.0.max(0.0).min(max_pan_x),
+            self.pan.1.max(0.0).min(max_pan_y),
+        );
+    }
+}
+
+impl Hash for Viewport {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let Rect {
+            x,
+            y,
+            width,
+            height,
+        } = self.frame;
+
+        for value in &[x, y, width, height, self.zoom, self.pan.0, self.pan.1] {
+            NotNan::new(*value)
+                .expect("viewport should not contain NaN")
+                .hash(state);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Drag {
+    pub last_coords: (f64, f64),
+    pub distance: f64,
+    pub is_pressed: bool,
+}
+
+impl Drag {
+    pub fn new(start_coords: (f64, f64)) -> Drag {
+        Drag {
+            last_coords: start_coords,
+            distance: 0.0,
+            is_pressed: true,
+        }
+    }
+
+    pub fn move_to(&mut self, canvas_coords: (f64, f64)) -> (f64, f64) {
+        let delta = (
+            canvas_coords.0 - self.last_coords.0,
+            canvas_coords.1 - self.last_coords.1,
+        );
+        self.last_coords = canvas_coords;
+        self.distance += delta.0.hypot(delta.1);
+
+        delta
+    }
+
+    pub fn has_panned(&self) -> bool {
+        self.distance > PAN_TOLERANCE
+    }
+}
+
+pub const ZOOM_STEP: f64 = 1.5;
+const MIN_ZOOM: f64 = 1.0;
+const MAX_ZOOM: f64 = 8.0;
+const PAN_TOLERANCE: f64 = 10.0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zooming_keeps_the_point_under_the_cursor_fixed() {
+        let mut viewport = Viewport::new(Rect {
+            x: 100.0,
+            y: 100.0,
+            width: 400.0,
+            height: 400.0,
+        });
+        let content_size = (400.0, 400.0);
+        let cursor = (300.0, 200.0);
+
+        viewport.zoom_at(cursor, 2.0, content_size);
+
+        assert_eq!(cursor, viewport.to_canvas((200.0, 100.0)));
+        assert_eq!(2.0, viewport.zoom);
+
+        viewport.pan_by((1000.0, -1000.0), content_size);
+
+        assert_eq!((0.0, 400.0), viewport.pan);
+
+        viewport.zoom_at(cursor, 100.0, content_size);
+
+        assert_eq!(MAX_ZOOM, viewport.zoom);
+    }
+}
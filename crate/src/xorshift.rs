@@ -1,5 +1,5 @@
 // https://en.wikipedia.org/wiki/Xorshift#xorshift+
-use crate::opponent::Random;
+use crate::opponent::{PrngState, Random};
 
 use murmur3::murmur3_32::MurmurHasher;
 
@@ -47,6 +47,20 @@ impl Random for Xorshift128Plus {
     fn random(&mut self) -> f64 {
         f64::from(self.random_u32()) / f64::from(u32::MAX)
     }
+
+    fn snapshot(&self) -> Option<PrngState> {
+        Some((self.0, self.1))
+    }
+
+    fn restore(&mut self, (a, b): PrngState) -> Result<(), ()> {
+        if a == 0 && b == 0 {
+            return Err(());
+        }
+
+        *self = Xorshift128Plus(a, b);
+
+        Ok(())
+    }
 }
 
 impl From<&str> for Xorshift128Plus {
@@ -66,3 +80,23 @@ impl From<String> for Xorshift128Plus {
         Xorshift128Plus::from(&seed[..])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restoring_a_snapshot_repeats_the_same_numbers() {
+        let mut prng = Xorshift128Plus::from("snapshot");
+        prng.random();
+        let snapshot = prng.snapshot().expect("should have snapshot");
+        let expected: Vec<f64> = (0..10).map(|_| prng.random()).collect();
+
+        let mut restored = Xorshift128Plus::from("another seed");
+        restored.restore(snapshot).unwrap();
+        let actual: Vec<f64> = (0..10).map(|_| restored.random()).collect();
+        assert_eq!(expected, actual);
+
+        assert_eq!(Err(()), restored.restore((0, 0)));
+    }
+}
@@ -11,10 +11,101 @@ Promise.all([import("../crate/pkg"), loadImageGetter()]).then(
     window.addEventListener("orientationchange", () => {
       app.resize();
     });
+    window.addEventListener("hashchange", () => {
+      app.on_hash_change();
+    });
+    window.addEventListener("keydown", event => {
+      if (event.ctrlKey || event.metaKey || event.altKey) {
+        return;
+      }
+      if (event.target instanceof HTMLButtonElement) {
+        return;
+      }
+      if (app.on_key_down(event.key)) {
+        event.preventDefault();
+      }
+    });
     app.call_with_canvas(canvas => {
       canvas.addEventListener("click", ({ clientX, clientY }) => {
         app.on_click(clientX, clientY);
       });
+      canvas.addEventListener("mousemove", ({ clientX, clientY }) => {
+        app.on_mouse_move(clientX, clientY);
+      });
+      canvas.addEventListener("mouseleave", () => {
+        app.on_mouse_leave();
+      });
+      canvas.addEventListener("mousedown", ({ clientX, clientY }) => {
+        app.on_mouse_down(clientX, clientY);
+      });
+      canvas.addEventListener("mouseup", () => {
+        app.on_mouse_up();
+      });
+      canvas.addEventListener(
+        "wheel",
+        event => {
+          const { clientX, clientY, deltaY } = event;
+          if (app.on_wheel(clientX, clientY, deltaY)) {
+            event.preventDefault();
+          }
+        },
+        { passive: false }
+      );
+      canvas.addEventListener(
+        "touchstart",
+        ({ changedTouches }) => {
+          const { clientX, clientY } = changedTouches[0];
+          app.on_touch_start(clientX, clientY);
+        },
+        { passive: true }
+      );
+      canvas.addEventListener(
+        "touchmove",
+        ({ changedTouches, touches }) => {
+          if (touches.length === 2) {
+            app.on_pinch(
+              touches[0].clientX,
+              touches[0].clientY,
+              touches[1].clientX,
+              touches[1].clientY
+            );
+            return;
+          }
+          const { clientX, clientY } = changedTouches[0];
+          app.on_touch_move(clientX, clientY);
+        },
+        { passive: true }
+      );
+      canvas.addEventListener("touchend", event => {
+        if (app.on_touch_end()) {
+          event.preventDefault();
+        }
+      });
+      canvas.addEventListener("touchcancel", () => {
+        app.on_touch_end();
+      });
+      canvas.addEventListener("contextmenu", event => {
+        event.preventDefault();
+      });
+    });
+    app.call_with_accessible_button_list(list => {
+      list.addEventListener("click", ({ target }) => {
+        const index = target.getAttribute("data-action-index");
+        if (index !== null) {
+          app.on_accessible_button(Number(index));
+        }
+      });
+    });
+    app.call_with_copy_challenge_link_button(button => {
+      button.addEventListener("click", () => {
+        const copying = app.copy_challenge_link();
+        if (copying) {
+          copying.then(
+            () => app.on_challenge_link_copied(),
+            () => app.on_challenge_link_copy_failed()
+          );
+        }
+      });
     });
 
     drawLoop();
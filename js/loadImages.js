@@ -37,14 +37,6 @@ import MultiPlayerButton from "./images/MultiPlayerButton.png";
 import SettingsButton from "./images/SettingsButton.png";
 import Star from "./images/Star.png";
 import EmptyStar from "./images/EmptyStar.png";
-import ComputerDifficultyStupid from "./images/ComputerDifficultyStupid.png";
-import ComputerDifficultyEasy from "./images/ComputerDifficultyEasy.png";
-import ComputerDifficultyMedium from "./images/ComputerDifficultyMedium.png";
-import TutorialButton from "./images/TutorialButton.png";
-import PassAndPlayButton from "./images/PassAndPlayButton.png";
-import CustomSeedButton from "./images/CustomSeedButton.png";
-import InspectMoveButton from "./images/InspectMoveButton.png";
-import StopInspectingButton from "./images/StopInspectingButton.png";
 
 import "./shims";
 
@@ -87,15 +79,7 @@ const srcs = {
   MultiPlayerButton,
   SettingsButton,
   Star,
-  EmptyStar,
-  ComputerDifficultyStupid,
-  ComputerDifficultyEasy,
-  ComputerDifficultyMedium,
-  TutorialButton,
-  PassAndPlayButton,
-  CustomSeedButton,
-  InspectMoveButton,
-  StopInspectingButton
+  EmptyStar
 };
 
 function loadImages() {